@@ -0,0 +1,57 @@
+//! Measures dispatching to keys with a single listener against keys with
+//! two listeners, the latter taking the regular parallel path.
+//!
+//! Run via `cargo bench --bench single_listener`.
+
+use hey_listen::sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener};
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+const DISPATCHES: u32 = 100_000;
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+enum Event {
+    Single,
+    Pair,
+}
+
+#[derive(Default)]
+struct Counter(AtomicUsize);
+
+impl ParallelListener<Event> for Counter {
+    fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> {
+        self.0.fetch_add(1, Ordering::Relaxed);
+
+        None
+    }
+}
+
+/// Returns the average duration of dispatching `event`.
+fn measure(dispatcher: &mut ParallelDispatcher<Event>, event: &Event) -> Duration {
+    let start = Instant::now();
+
+    for _ in 0..DISPATCHES {
+        dispatcher.dispatch_event(event);
+    }
+
+    start.elapsed() / DISPATCHES
+}
+
+fn main() {
+    let mut dispatcher = ParallelDispatcher::new(4).expect("Failed to build threadpool");
+
+    dispatcher.add_listener(Event::Single, Counter::default());
+    dispatcher.add_listener(Event::Pair, Counter::default());
+    dispatcher.add_listener(Event::Pair, Counter::default());
+
+    println!(
+        "single listener: {:?} per dispatch",
+        measure(&mut dispatcher, &Event::Single)
+    );
+    println!(
+        "two listeners:   {:?} per dispatch",
+        measure(&mut dispatcher, &Event::Pair)
+    );
+}
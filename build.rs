@@ -1,3 +0,0 @@
-fn main() {
-    skeptic::generate_doc_tests(&["README.md"]);
-}
@@ -59,14 +59,6 @@ impl ParallelListener<Event> for Weak<RwLock<ListenerStruct>> {
     }
 }
 
-impl ParallelListener<Event>
-    for Box<dyn Fn(&Event) -> Option<ParallelDispatchResult> + Send + Sync>
-{
-    fn on_event(&self, event: &Event) -> Option<ParallelDispatchResult> {
-        (&self)(&event)
-    }
-}
-
 fn main() {
     // Create our dispatcher.
     // The number of threads here is exemplary, one should figure out what's best.
@@ -77,18 +69,17 @@ fn main() {
     let listener_2 = Arc::new(RwLock::new(ListenerStruct { number: 1 }));
 
     // Our closure gets its unique number as well.
-    let listener_3: Box<dyn Fn(&Event) -> Option<ParallelDispatchResult> + Send + Sync> =
-        Box::new(move |_event| {
-            println!("3");
+    let listener_3 = move |_event: &Event| {
+        println!("3");
 
-            // As we did in the `ParallelListener`-implementation:
-            None
-        });
+        // As we did in the `ParallelListener`-implementation:
+        None
+    };
 
     // We add some listeners for our only variant.
     dispatcher.add_listener(Event::EventVariant, Arc::downgrade(&listener_1));
     dispatcher.add_listener(Event::EventVariant, Arc::clone(&listener_2));
-    dispatcher.add_listener(Event::EventVariant, listener_3);
+    dispatcher.add_fn(Event::EventVariant, listener_3);
 
     // Let's remember that we gave every listener their own unique number
     // and added in order of their number.
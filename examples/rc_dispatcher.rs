@@ -48,12 +48,6 @@ impl Listener<EventEnum> for ListenerStruct {
     }
 }
 
-impl Listener<EventEnum> for Box<dyn Fn(&EventEnum) -> Option<DispatcherRequest>> {
-    fn on_event(&self, event: &EventEnum) -> Option<DispatcherRequest> {
-        (self)(&event)
-    }
-}
-
 fn main() {
     // Create your listener.
     let listener = ListenerStruct {};
@@ -71,25 +65,25 @@ fn main() {
     dispatcher.dispatch_event(&EventEnum::EventVariantB);
 
     // If you want to work with a closure, you can do the following:
-    let listening_closure: Box<dyn Fn(&EventEnum) -> Option<DispatcherRequest>> =
-        Box::new(move |event: &EventEnum| {
-            // Be aware, since enum's variants are no types,
-            // whenever you want to work with the enum,
-            // you need to pattern-match it of if-let-bind in order to find its variant,
-            // even if you listen to only one variant.
-            let event_name = match *event {
-                EventEnum::EventVariantA => "A".to_string(), // we won't listen to this ...
-                EventEnum::EventVariantB => "B".to_string(), // ... nor to this.
-                EventEnum::EventVariantC => "C, as in closure".to_string(),
-            };
+    let listening_closure = move |event: &EventEnum| {
+        // Be aware, since enum's variants are no types,
+        // whenever you want to work with the enum,
+        // you need to pattern-match it of if-let-bind in order to find its variant,
+        // even if you listen to only one variant.
+        let event_name = match *event {
+            EventEnum::EventVariantA => "A".to_string(), // we won't listen to this ...
+            EventEnum::EventVariantB => "B".to_string(), // ... nor to this.
+            EventEnum::EventVariantC => "C, as in closure".to_string(),
+        };
 
-            println!("Received event-variant: {}!", event_name);
+        println!("Received event-variant: {}!", event_name);
 
-            // As we did in the `Listener`-implementation:
-            None
-        });
+        // As we did in the `Listener`-implementation:
+        None
+    };
 
-    dispatcher.add_listener(EventEnum::EventVariantB, listening_closure);
+    // Closures require the `add_fn`-method instead of `add_listener`.
+    dispatcher.add_fn(EventEnum::EventVariantB, listening_closure);
 
     dispatcher.dispatch_event(&EventEnum::EventVariantC);
 }
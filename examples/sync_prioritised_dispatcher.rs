@@ -70,14 +70,6 @@ impl PriorityListener<EventEnum> for Arc<RwLock<ListenerStruct>> {
     }
 }
 
-impl PriorityListener<EventEnum>
-    for Box<dyn Fn(&EventEnum) -> Option<PriorityDispatcherResult> + Send + Sync>
-{
-    fn on_event(&self, event: &EventEnum) -> Option<PriorityDispatcherResult> {
-        (self)(event)
-    }
-}
-
 fn main() {
     // Create our listener.
     let listener = Arc::new(RwLock::new(ListenerStruct {}));
@@ -93,9 +85,7 @@ fn main() {
     dispatcher.add_listener(EventEnum::EventVariant(0), Arc::clone(&listener), 1);
 
     // If we want to work with a closure, we can do the following:
-    let listening_closure: Box<
-        dyn Fn(&EventEnum) -> Option<PriorityDispatcherResult> + Send + Sync,
-    > = Box::new(move |event| {
+    let listening_closure = move |event: &EventEnum| {
         // We have to be aware that an enum's variants are no types,
         // whenever we want to work with the enum we need to
         // pattern-match the enum in order to use its variant.
@@ -107,10 +97,10 @@ fn main() {
 
         // As we did in the `Listener`-implementation:
         None
-    });
+    };
 
-    // Closures require the `add_fn`-method instead `add_listener`.
-    dispatcher.add_listener(EventEnum::EventVariant(0), listening_closure, 3);
+    // Closures require the `add_fn`-method instead of `add_listener`.
+    dispatcher.add_fn(EventEnum::EventVariant(0), listening_closure, 3);
 
     // Dispatches our events to all listeners.
     dispatcher.dispatch_event(&EventEnum::EventVariant(1));
@@ -0,0 +1,1466 @@
+//! Adapters wrap an existing listener to alter which events it receives.
+//!
+//! Every adapter implements the listener-trait of each enabled dispatcher,
+//! as long as the wrapped listener does.
+//! Adapters are created via [`ListenerExt`](crate::adapters::ListenerExt) or their own constructor.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "parallel")] {
+//! use hey_listen::{
+//!     adapters::ListenerExt,
+//!     sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener},
+//! };
+//!
+//! #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+//! enum Event {
+//!     Damage(u32),
+//! }
+//!
+//! struct HealthBar;
+//!
+//! impl ParallelListener<Event> for HealthBar {
+//!     fn on_event(&self, event: &Event) -> Option<ParallelDispatchResult> {
+//!         None
+//!     }
+//! }
+//!
+//! let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+//!     .expect("Failed to build threadpool");
+//!
+//! let listener = HealthBar
+//!     .filter(|Event::Damage(amount): &Event| *amount > 0)
+//!     .inspect(|event: &Event| println!("Received {:?}", event));
+//!
+//! dispatcher.add_listener(Event::Damage(0), listener);
+//! # }
+//! ```
+#[cfg(feature = "blocking")]
+use crate::rc::DispatcherRequest;
+#[cfg(feature = "async")]
+use crate::sync::AsyncDispatchResult;
+#[cfg(feature = "parallel")]
+use crate::sync::ParallelDispatchResult;
+#[cfg(feature = "priority")]
+use crate::sync::PriorityDispatcherResult;
+use crate::{
+    audit::{DropHook, DropReason},
+    clock::{Clock, SystemClock},
+    key::{Identity, KeyExtract},
+};
+use std::{
+    borrow::Borrow,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, PoisonError, RwLock,
+    },
+    time::{Duration, Instant},
+};
+
+/// Implements every enabled listener-trait for an adapter.
+///
+/// `$forward` decides on the event the wrapped listener receives: `None`
+/// skips the wrapped listener, `Some` forwards anything borrowing
+/// `$inner_event`.
+/// `$listener` evaluates to the wrapped listener, defaulting to the
+/// adapter's `listener`-field.
+/// `$inner` evaluates to the listener whose name, liveness, and
+/// registration the adapter reports, adapters returning `None` report the
+/// trait's defaults.
+/// It defaults to the adapter's `listener`-field as well, or to `None` if
+/// `$listener` is given, as such adapters forward to a listener chosen per
+/// event.
+macro_rules! impl_listeners {
+    (
+        impl<$($param:ident),*> for $adapter:ty
+        where [$($bounds:tt)*]
+        forwarding $inner_event:ty: |$this:ident, $event:ident| $forward:expr
+    ) => {
+        impl_listeners! {
+            impl<$($param),*> for $adapter
+            where [$($bounds)*]
+            forwarding $inner_event: |$this, $event| $forward,
+            to |adapter| adapter.listener,
+            inner |adapter| Some(&adapter.listener)
+        }
+    };
+    (
+        impl<$($param:ident),*> for $adapter:ty
+        where [$($bounds:tt)*]
+        forwarding $inner_event:ty: |$this:ident, $event:ident| $forward:expr,
+        to |$wrapper:ident| $listener:expr
+    ) => {
+        impl_listeners! {
+            impl<$($param),*> for $adapter
+            where [$($bounds)*]
+            forwarding $inner_event: |$this, $event| $forward,
+            to |$wrapper| $listener,
+            inner |_adapter| None::<&L>
+        }
+    };
+    (
+        impl<$($param:ident),*> for $adapter:ty
+        where [$($bounds:tt)*]
+        forwarding $inner_event:ty: |$this:ident, $event:ident| $forward:expr,
+        to |$wrapper:ident| $listener:expr,
+        inner |$inner_wrapper:ident| $inner:expr
+    ) => {
+        #[cfg(feature = "blocking")]
+        impl<T, $($param),*> crate::rc::Listener<T> for $adapter
+        where
+            T: Clone + 'static,
+            $inner_event: Clone + 'static,
+            L: crate::rc::Listener<$inner_event>,
+            $($bounds)*
+        {
+            fn on_event(&self, event: &T) -> Option<DispatcherRequest> {
+                let ($this, $event, $wrapper) = (self, event, self);
+
+                $forward.and_then(|forwarded| ($listener).on_event(forwarded.borrow()))
+            }
+
+            fn name(&self) -> &str {
+                let $inner_wrapper = self;
+
+                match $inner {
+                    Some(listener) => listener.name(),
+                    None => std::any::type_name::<Self>(),
+                }
+            }
+
+            fn is_alive(&self) -> bool {
+                let $inner_wrapper = self;
+
+                match $inner {
+                    Some(listener) => listener.is_alive(),
+                    None => true,
+                }
+            }
+
+            fn listener_id(&self) -> Option<crate::handle::ListenerId> {
+                let $inner_wrapper = self;
+
+                match $inner {
+                    Some(listener) => listener.listener_id(),
+                    None => None,
+                }
+            }
+        }
+
+        #[cfg(feature = "parallel")]
+        impl<T, $($param),*> crate::sync::ParallelListener<T> for $adapter
+        where
+            T: Clone + Send + Sync + 'static,
+            $inner_event: Clone + Send + Sync + 'static,
+            L: crate::sync::ParallelListener<$inner_event>,
+            $($bounds)*
+        {
+            fn on_event(&self, event: &T) -> Option<ParallelDispatchResult> {
+                let ($this, $event, $wrapper) = (self, event, self);
+
+                $forward.and_then(|forwarded| ($listener).on_event(forwarded.borrow()))
+            }
+
+            fn name(&self) -> &str {
+                let $inner_wrapper = self;
+
+                match $inner {
+                    Some(listener) => listener.name(),
+                    None => std::any::type_name::<Self>(),
+                }
+            }
+
+            fn listener_id(&self) -> Option<crate::handle::ListenerId> {
+                let $inner_wrapper = self;
+
+                match $inner {
+                    Some(listener) => listener.listener_id(),
+                    None => None,
+                }
+            }
+
+            fn is_alive(&self) -> bool {
+                let $inner_wrapper = self;
+
+                match $inner {
+                    Some(listener) => listener.is_alive(),
+                    None => true,
+                }
+            }
+        }
+
+        #[cfg(feature = "priority")]
+        impl<T, $($param),*> crate::sync::PriorityListener<T> for $adapter
+        where
+            T: Clone + Send + Sync + 'static,
+            $inner_event: Clone + Send + Sync + 'static,
+            L: crate::sync::PriorityListener<$inner_event>,
+            $($bounds)*
+        {
+            fn on_event(&self, event: &T) -> Option<PriorityDispatcherResult> {
+                let ($this, $event, $wrapper) = (self, event, self);
+
+                $forward.and_then(|forwarded| ($listener).on_event(forwarded.borrow()))
+            }
+
+            fn name(&self) -> &str {
+                let $inner_wrapper = self;
+
+                match $inner {
+                    Some(listener) => listener.name(),
+                    None => std::any::type_name::<Self>(),
+                }
+            }
+
+            fn requires_order(&self) -> bool {
+                let $inner_wrapper = self;
+
+                match $inner {
+                    Some(listener) => listener.requires_order(),
+                    None => false,
+                }
+            }
+
+            fn owner(&self) -> Option<crate::sync::Owner> {
+                let $inner_wrapper = self;
+
+                match $inner {
+                    Some(listener) => listener.owner(),
+                    None => None,
+                }
+            }
+
+            fn listener_id(&self) -> Option<crate::handle::ListenerId> {
+                let $inner_wrapper = self;
+
+                match $inner {
+                    Some(listener) => listener.listener_id(),
+                    None => None,
+                }
+            }
+
+            fn is_alive(&self) -> bool {
+                let $inner_wrapper = self;
+
+                match $inner {
+                    Some(listener) => listener.is_alive(),
+                    None => true,
+                }
+            }
+        }
+
+        #[cfg(feature = "async")]
+        #[async_trait::async_trait]
+        impl<T, $($param),*> crate::sync::AsyncListener<T> for $adapter
+        where
+            T: Clone + Send + Sync + 'static,
+            $inner_event: Clone + Send + Sync + 'static,
+            L: crate::sync::AsyncListener<$inner_event> + Send + Sync,
+            Self: Send + Sync,
+            $($bounds)*
+        {
+            async fn on_event(&self, event: &T) -> Option<AsyncDispatchResult> {
+                let ($this, $event, $wrapper) = (self, event, self);
+
+                match $forward {
+                    Some(forwarded) => ($listener).on_event(forwarded.borrow()).await,
+                    None => None,
+                }
+            }
+
+            fn name(&self) -> &str {
+                let $inner_wrapper = self;
+
+                match $inner {
+                    Some(listener) => listener.name(),
+                    None => std::any::type_name::<Self>(),
+                }
+            }
+
+            fn listener_id(&self) -> Option<crate::handle::ListenerId> {
+                let $inner_wrapper = self;
+
+                match $inner {
+                    Some(listener) => listener.listener_id(),
+                    None => None,
+                }
+            }
+
+            fn is_alive(&self) -> bool {
+                let $inner_wrapper = self;
+
+                match $inner {
+                    Some(listener) => listener.is_alive(),
+                    None => true,
+                }
+            }
+        }
+    };
+}
+
+/// Provides adapters on every type, meant to be used on listeners.
+pub trait ListenerExt: Sized {
+    /// Wraps `self` in a [`Filter`], only forwarding events
+    /// for which `predicate` returns `true`.
+    fn filter<T, P>(self, predicate: P) -> Filter<Self, P>
+    where
+        P: Fn(&T) -> bool,
+    {
+        Filter::new(self, predicate)
+    }
+
+    /// Wraps `self` in a [`MapEvent`], forwarding the event
+    /// returned by `map` instead of the dispatched one.
+    fn map_event<T, U, F>(self, map: F) -> MapEvent<Self, F>
+    where
+        F: Fn(&T) -> U,
+    {
+        MapEvent::new(self, map)
+    }
+
+    /// Wraps `self` in an [`Inspect`], calling `inspect` with every event
+    /// before forwarding it.
+    fn inspect<T, F>(self, inspect: F) -> Inspect<Self, F>
+    where
+        F: Fn(&T),
+    {
+        Inspect::new(self, inspect)
+    }
+
+    /// Wraps `self` in a [`Throttled`], dropping events arriving sooner
+    /// than `min_interval` after the last forwarded one.
+    fn throttled(self, min_interval: Duration) -> Throttled<Self> {
+        Throttled::new(self, min_interval)
+    }
+
+    /// Wraps `self` in a [`Sampled`], forwarding only every `n`-th event.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    fn sampled(self, n: usize) -> Sampled<Self> {
+        Sampled::every_n(self, n)
+    }
+
+    /// Wraps `self` in a [`Shared`], whose clones can be added for multiple
+    /// event-keys.
+    fn shared(self) -> Shared<Self> {
+        Shared::new(self)
+    }
+
+    /// Wraps `self` in a [`Keyed`], forwarding each event together with
+    /// the event-key it has been dispatched for.
+    fn keyed(self) -> Keyed<Self> {
+        Keyed::new(self)
+    }
+}
+
+impl<L> ListenerExt for L {}
+
+/// Forwards only events accepted by a predicate to the wrapped listener.
+pub struct Filter<L, P> {
+    listener: L,
+    predicate: P,
+}
+
+impl<L, P> Filter<L, P> {
+    /// Wraps `listener`, forwarding only events for which `predicate`
+    /// returns `true`.
+    pub const fn new(listener: L, predicate: P) -> Self {
+        Self {
+            listener,
+            predicate,
+        }
+    }
+}
+
+impl_listeners! {
+    impl<L, P> for Filter<L, P>
+    where [P: Fn(&T) -> bool,]
+    forwarding T: |this, event| Some(event).filter(|event| (this.predicate)(event))
+}
+
+/// Forwards a mapped event to the wrapped listener.
+///
+/// The wrapped listener may listen to a different event-type than the
+/// dispatcher dispatches.
+pub struct MapEvent<L, F> {
+    listener: L,
+    map: F,
+}
+
+impl<L, F> MapEvent<L, F> {
+    /// Wraps `listener`, forwarding the event returned by `map`.
+    pub const fn new(listener: L, map: F) -> Self {
+        Self { listener, map }
+    }
+}
+
+impl_listeners! {
+    impl<U, L, F> for MapEvent<L, F>
+    where [F: Fn(&T) -> U,]
+    forwarding U: |this, event| Some((this.map)(event))
+}
+
+/// Calls a closure with every event before forwarding it to the wrapped
+/// listener.
+pub struct Inspect<L, F> {
+    listener: L,
+    inspect: F,
+}
+
+impl<L, F> Inspect<L, F> {
+    /// Wraps `listener`, calling `inspect` with every event first.
+    pub const fn new(listener: L, inspect: F) -> Self {
+        Self { listener, inspect }
+    }
+}
+
+impl_listeners! {
+    impl<L, F> for Inspect<L, F>
+    where [F: Fn(&T),]
+    forwarding T: |this, event| {
+        (this.inspect)(event);
+
+        Some(event)
+    }
+}
+
+/// Drops events arriving sooner than a minimum interval after the last
+/// event forwarded to the wrapped listener.
+///
+/// Every `Throttled` keeps track of its own last forwarded event,
+/// regardless of the event-key it has been added for.
+/// Dropped events are passed to a [`DropHook`], if set via
+/// [`on_dropped`](Self::on_dropped).
+pub struct Throttled<L, C = SystemClock, H = ()> {
+    listener: L,
+    min_interval: Duration,
+    last_forwarded: Mutex<Option<Instant>>,
+    clock: C,
+    drop_hook: H,
+}
+
+impl<L> Throttled<L> {
+    /// Wraps `listener`, forwarding at most one event per `min_interval`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parallel")] {
+    /// use hey_listen::{
+    ///     adapters::Throttled,
+    ///     sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener},
+    /// };
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     MouseMoved,
+    /// }
+    ///
+    /// struct Tooltip;
+    ///
+    /// impl ParallelListener<Event> for Tooltip {
+    ///     fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> { None }
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// dispatcher.add_listener(
+    ///     Event::MouseMoved,
+    ///     Throttled::new(Tooltip, Duration::from_millis(100)),
+    /// );
+    /// # }
+    /// ```
+    pub fn new(listener: L, min_interval: Duration) -> Self {
+        Self {
+            listener,
+            min_interval,
+            last_forwarded: Mutex::new(None),
+            clock: SystemClock,
+            drop_hook: (),
+        }
+    }
+}
+
+impl<L, C: Clock, H> Throttled<L, C, H> {
+    /// Reads the time from `clock` instead.
+    pub fn clock<D: Clock>(self, clock: D) -> Throttled<L, D, H> {
+        Throttled {
+            listener: self.listener,
+            min_interval: self.min_interval,
+            last_forwarded: self.last_forwarded,
+            clock,
+            drop_hook: self.drop_hook,
+        }
+    }
+
+    /// Passes each dropped event to `drop_hook`, with
+    /// [`DropReason::Throttled`].
+    pub fn on_dropped<G>(self, drop_hook: G) -> Throttled<L, C, G> {
+        Throttled {
+            listener: self.listener,
+            min_interval: self.min_interval,
+            last_forwarded: self.last_forwarded,
+            clock: self.clock,
+            drop_hook,
+        }
+    }
+
+    /// Returns whether an event arriving now shall be forwarded and if so,
+    /// remembers now as last forwarding.
+    fn admit(&self) -> bool {
+        let now = self.clock.now();
+        let mut last_forwarded = self
+            .last_forwarded
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let admitted =
+            last_forwarded.map_or(true, |last| now.duration_since(last) >= self.min_interval);
+
+        if admitted {
+            *last_forwarded = Some(now);
+        }
+
+        admitted
+    }
+}
+
+impl_listeners! {
+    impl<L, C, H> for Throttled<L, C, H>
+    where [C: Clock, H: DropHook<T>,]
+    forwarding T: |this, event| {
+        if this.admit() {
+            Some(event)
+        } else {
+            this.drop_hook.on_dropped(event, DropReason::Throttled);
+
+            None
+        }
+    }
+}
+
+/// Forwards only every n-th event to the wrapped listener.
+///
+/// Every `Sampled` counts events on its own, regardless of the event-key it
+/// has been added for.
+/// Skipped events are passed to a [`DropHook`], if set via
+/// [`on_dropped`](Self::on_dropped).
+pub struct Sampled<L, H = ()> {
+    listener: L,
+    n: usize,
+    count: AtomicUsize,
+    drop_hook: H,
+}
+
+impl<L> Sampled<L> {
+    /// Wraps `listener`, forwarding the `n`-th, `2n`-th, and so on event.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parallel")] {
+    /// use hey_listen::{
+    ///     adapters::Sampled,
+    ///     sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener},
+    /// };
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Frame,
+    /// }
+    ///
+    /// struct DebugOverlay;
+    ///
+    /// impl ParallelListener<Event> for DebugOverlay {
+    ///     fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> { None }
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// // Updates the overlay once every sixty frames.
+    /// dispatcher.add_listener(Event::Frame, Sampled::every_n(DebugOverlay, 60));
+    /// # }
+    /// ```
+    pub fn every_n(listener: L, n: usize) -> Self {
+        assert!(n > 0, "Sampling every zeroth event is impossible");
+
+        Self {
+            listener,
+            n,
+            count: AtomicUsize::new(0),
+            drop_hook: (),
+        }
+    }
+}
+
+impl<L, H> Sampled<L, H> {
+    /// Passes each skipped event to `drop_hook`, with
+    /// [`DropReason::Sampled`].
+    pub fn on_dropped<G>(self, drop_hook: G) -> Sampled<L, G> {
+        Sampled {
+            listener: self.listener,
+            n: self.n,
+            count: self.count,
+            drop_hook,
+        }
+    }
+
+    /// Counts an arriving event and returns whether it shall be forwarded.
+    fn admit(&self) -> bool {
+        let previous_count = self
+            .count
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |count| {
+                Some((count + 1) % self.n)
+            })
+            .unwrap_or_default();
+
+        previous_count + 1 == self.n
+    }
+}
+
+impl_listeners! {
+    impl<L, H> for Sampled<L, H>
+    where [H: DropHook<T>,]
+    forwarding T: |this, event| {
+        if this.admit() {
+            Some(event)
+        } else {
+            this.drop_hook.on_dropped(event, DropReason::Sampled);
+
+            None
+        }
+    }
+}
+
+/// Buffers events and forwards them as one batch to the wrapped listener.
+///
+/// The wrapped listener listens to `Vec<T>`, receiving buffered events in
+/// order of their arrival.
+/// A batch is forwarded once the buffer holds a given amount of events or,
+/// if set, the oldest buffered event exceeds a maximum age.
+///
+/// **Note**: Dispatchers have no timer, the age is only checked when an
+/// event arrives.
+/// Wrap the listener in a [`MapEvent`] to fold the batch into a
+/// different type.
+pub struct Aggregator<L, T, C = SystemClock> {
+    listener: L,
+    max_count: usize,
+    max_age: Option<Duration>,
+    buffer: Mutex<Batch<T>>,
+    clock: C,
+}
+
+/// Events buffered by an [`Aggregator`].
+struct Batch<T> {
+    events: Vec<T>,
+    oldest: Option<Instant>,
+}
+
+impl<L, T: Clone> Aggregator<L, T> {
+    /// Wraps `listener`, forwarding events in batches of `max_count`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parallel")] {
+    /// use hey_listen::{
+    ///     adapters::Aggregator,
+    ///     sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener},
+    /// };
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Log,
+    /// }
+    ///
+    /// struct LogWriter;
+    ///
+    /// impl ParallelListener<Vec<Event>> for LogWriter {
+    ///     fn on_event(&self, events: &Vec<Event>) -> Option<ParallelDispatchResult> {
+    ///         println!("Writing {} entries.", events.len());
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// let listener = Aggregator::new(LogWriter, 64).max_age(Duration::from_secs(1));
+    ///
+    /// dispatcher.add_listener(Event::Log, listener);
+    /// # }
+    /// ```
+    pub fn new(listener: L, max_count: usize) -> Self {
+        Self {
+            listener,
+            max_count,
+            max_age: None,
+            buffer: Mutex::new(Batch {
+                events: Vec::new(),
+                oldest: None,
+            }),
+            clock: SystemClock,
+        }
+    }
+}
+
+impl<L, T: Clone, C: Clock> Aggregator<L, T, C> {
+    /// Reads the time from `clock` instead.
+    pub fn clock<D: Clock>(self, clock: D) -> Aggregator<L, T, D> {
+        Aggregator {
+            listener: self.listener,
+            max_count: self.max_count,
+            max_age: self.max_age,
+            buffer: self.buffer,
+            clock,
+        }
+    }
+
+    /// Forwards the batch once its oldest event is older than `max_age`,
+    /// even if fewer than `max_count` events are buffered.
+    #[must_use]
+    pub const fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+
+        self
+    }
+
+    /// Buffers `event` and returns the batch if a threshold has been reached.
+    fn buffer(&self, event: &T) -> Option<Vec<T>> {
+        let now = self.clock.now();
+        let mut buffer = self.buffer.lock().unwrap_or_else(PoisonError::into_inner);
+
+        buffer.events.push(event.clone());
+        let oldest = *buffer.oldest.get_or_insert(now);
+
+        let is_full = buffer.events.len() >= self.max_count;
+        let is_old = self
+            .max_age
+            .map_or(false, |max_age| now.duration_since(oldest) >= max_age);
+
+        if is_full || is_old {
+            buffer.oldest = None;
+
+            Some(std::mem::take(&mut buffer.events))
+        } else {
+            None
+        }
+    }
+}
+
+impl_listeners! {
+    impl<L, C> for Aggregator<L, T, C>
+    where [C: Clock,]
+    forwarding Vec<T>: |this, event| this.buffer(event)
+}
+
+/// Shares the wrapped listener between all of its clones.
+///
+/// Adding clones for multiple event-keys subscribes the wrapped listener to
+/// any of them, it tells them apart by the event it receives.
+///
+/// Wrap the listener in a [`Keyed`] to receive the matched event-key along
+/// with each event.
+///
+/// **Note**: Requesting to stop listening only removes the clone that
+/// received the event, clones added for other event-keys remain.
+pub struct Shared<L> {
+    listener: Arc<L>,
+}
+
+impl<L> Shared<L> {
+    /// Wraps `listener` to be shared between clones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parallel")] {
+    /// use hey_listen::{
+    ///     adapters::Shared,
+    ///     sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener},
+    /// };
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Saved,
+    ///     Loaded,
+    /// }
+    ///
+    /// struct StatusBar;
+    ///
+    /// impl ParallelListener<Event> for StatusBar {
+    ///     fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> { None }
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// let listener = Shared::new(StatusBar);
+    ///
+    /// // The status bar receives saved as well as loaded events.
+    /// dispatcher.add_listener(Event::Saved, listener.clone());
+    /// dispatcher.add_listener(Event::Loaded, listener);
+    /// # }
+    /// ```
+    pub fn new(listener: L) -> Self {
+        Self {
+            listener: Arc::new(listener),
+        }
+    }
+}
+
+impl<L> Clone for Shared<L> {
+    fn clone(&self) -> Self {
+        Self {
+            listener: Arc::clone(&self.listener),
+        }
+    }
+}
+
+impl_listeners! {
+    impl<L> for Shared<L>
+    where []
+    forwarding T: |_this, event| Some(event)
+}
+
+/// Forwards each event together with the event-key it matched to the
+/// wrapped listener.
+///
+/// The wrapped listener listens to `(X::Key, T)`, telling apart which of
+/// its keys fired when subscribed to several of them via [`Shared`].
+///
+/// `X` is the [`KeyExtract`]-strategy deriving the key, it should match the
+/// dispatcher's.
+pub struct Keyed<L, X = Identity> {
+    listener: L,
+    key_extract: PhantomData<X>,
+}
+
+impl<L> Keyed<L> {
+    /// Wraps `listener`, forwarding each event together with itself as
+    /// event-key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parallel")] {
+    /// use hey_listen::{
+    ///     adapters::{Keyed, Shared},
+    ///     sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener},
+    /// };
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Saved(String),
+    ///     Loaded(String),
+    /// }
+    ///
+    /// struct StatusBar;
+    ///
+    /// impl ParallelListener<(Event, Event)> for StatusBar {
+    ///     fn on_event(&self, (key, _event): &(Event, Event)) -> Option<ParallelDispatchResult> {
+    ///         match key {
+    ///             Event::Saved(file) => println!("Saved {}", file),
+    ///             Event::Loaded(file) => println!("Loaded {}", file),
+    ///         }
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// let listener = Shared::new(Keyed::new(StatusBar));
+    ///
+    /// dispatcher.add_listener(Event::Saved("save.dat".into()), listener.clone());
+    /// dispatcher.add_listener(Event::Loaded("save.dat".into()), listener);
+    /// # }
+    /// ```
+    pub const fn new(listener: L) -> Self {
+        Self::with_key_extract(listener)
+    }
+}
+
+impl<L, X> Keyed<L, X> {
+    /// Wraps `listener`, forwarding each event together with the event-key
+    /// `X` derives from it.
+    pub const fn with_key_extract(listener: L) -> Self {
+        Self {
+            listener,
+            key_extract: PhantomData,
+        }
+    }
+}
+
+impl_listeners! {
+    impl<L, X> for Keyed<L, X>
+    where [X: KeyExtract<T>,]
+    forwarding (X::Key, T): |_this, event| Some((X::extract(event), event.clone()))
+}
+
+/// Forwards events once an event for every one of a set of keys has
+/// arrived.
+///
+/// Like [`Shared`], all clones share their state and are meant to be added
+/// for each of the keys.
+/// The wrapped listener listens to `Vec<T>`, receiving the latest event per
+/// key, ordered like the keys.
+/// Afterwards, every key needs to be seen again for the next forwarding.
+///
+/// `X` is the [`KeyExtract`]-strategy telling which key an event belongs
+/// to, it should match the dispatcher's.
+pub struct Join<L, T, X = Identity>
+where
+    X: KeyExtract<T>,
+{
+    listener: Arc<L>,
+    keys: Arc<[X::Key]>,
+    latest: Arc<Mutex<Vec<Option<T>>>>,
+}
+
+impl<L, T> Join<L, T>
+where
+    T: Eq + Hash + Clone,
+{
+    /// Wraps `listener`, forwarding once an event for each of `keys` has
+    /// arrived.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parallel")] {
+    /// use hey_listen::{
+    ///     adapters::Join,
+    ///     sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener},
+    /// };
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     TexturesLoaded,
+    ///     SoundsLoaded,
+    /// }
+    ///
+    /// struct LoadingScreen;
+    ///
+    /// impl ParallelListener<Vec<Event>> for LoadingScreen {
+    ///     fn on_event(&self, _events: &Vec<Event>) -> Option<ParallelDispatchResult> {
+    ///         println!("Everything has been loaded.");
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// let keys = vec![Event::TexturesLoaded, Event::SoundsLoaded];
+    /// let listener = Join::new(LoadingScreen, keys.clone());
+    ///
+    /// for key in keys {
+    ///     dispatcher.add_listener(key, listener.clone());
+    /// }
+    /// # }
+    /// ```
+    pub fn new(listener: L, keys: impl IntoIterator<Item = T>) -> Self {
+        Self::with_key_extract(listener, keys)
+    }
+}
+
+impl<L, T, X> Join<L, T, X>
+where
+    T: Clone,
+    X: KeyExtract<T>,
+{
+    /// Wraps `listener`, forwarding once an event for each of `keys` has
+    /// arrived, telling keys of events apart via `X`.
+    pub fn with_key_extract(listener: L, keys: impl IntoIterator<Item = T>) -> Self {
+        let keys: Arc<[X::Key]> = keys.into_iter().map(|key| X::extract(&key)).collect();
+
+        Self {
+            listener: Arc::new(listener),
+            latest: Arc::new(Mutex::new(vec![None; keys.len()])),
+            keys,
+        }
+    }
+
+    /// Remembers `event` as latest of its key and returns the latest events
+    /// of all keys, if each has been seen.
+    fn join(&self, event: &T) -> Option<Vec<T>> {
+        let key = X::extract(event);
+        let index = self.keys.iter().position(|joined| *joined == key)?;
+        let mut latest = self.latest.lock().unwrap_or_else(PoisonError::into_inner);
+
+        latest[index] = Some(event.clone());
+
+        if latest.iter().all(Option::is_some) {
+            Some(latest.iter_mut().filter_map(Option::take).collect())
+        } else {
+            None
+        }
+    }
+}
+
+impl<L, T, X> Clone for Join<L, T, X>
+where
+    X: KeyExtract<T>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            listener: Arc::clone(&self.listener),
+            keys: Arc::clone(&self.keys),
+            latest: Arc::clone(&self.latest),
+        }
+    }
+}
+
+impl_listeners! {
+    impl<L, X> for Join<L, T, X>
+    where [X: KeyExtract<T>,]
+    forwarding Vec<T>: |this, event| this.join(event)
+}
+
+/// Pairs related events arriving within a time window and forwards an
+/// event synthesised from each pair to the wrapped listener.
+///
+/// Every arriving event is offered to the correlating closure together
+/// with each pending event, oldest first.
+/// The first pair the closure synthesises an event for is forwarded and
+/// both events are consumed, otherwise the arriving event becomes pending.
+///
+/// **Note**: Dispatchers have no timer, pending events exceeding the window
+/// are only discarded when an event arrives.
+/// Wrap the correlation in a [`Shared`] to add it for the event-keys of
+/// both related events.
+pub struct Correlate<L, T, F, C = SystemClock> {
+    listener: L,
+    window: Duration,
+    synthesise: F,
+    pending: Mutex<Vec<(Instant, T)>>,
+    clock: C,
+}
+
+impl<L, T: Clone, F> Correlate<L, T, F> {
+    /// Wraps `listener`, forwarding the events `correlate` synthesises from
+    /// a pending and an arriving event, at most `window` apart.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parallel")] {
+    /// use hey_listen::{
+    ///     adapters::{Correlate, ListenerExt},
+    ///     sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener},
+    /// };
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     ButtonDown(u32),
+    ///     ButtonUp(u32),
+    /// }
+    ///
+    /// #[derive(Clone)]
+    /// struct Click(u32);
+    ///
+    /// struct Button;
+    ///
+    /// impl ParallelListener<Click> for Button {
+    ///     fn on_event(&self, Click(id): &Click) -> Option<ParallelDispatchResult> {
+    ///         println!("Button {} has been clicked.", id);
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// let listener = Correlate::new(Button, Duration::from_millis(500), |earlier: &Event, later: &Event| {
+    ///     match (earlier, later) {
+    ///         (Event::ButtonDown(down), Event::ButtonUp(up)) if down == up => Some(Click(*up)),
+    ///         _ => None,
+    ///     }
+    /// })
+    /// .shared();
+    ///
+    /// dispatcher.add_listener(Event::ButtonDown(1), listener.clone());
+    /// dispatcher.add_listener(Event::ButtonUp(1), listener);
+    ///
+    /// dispatcher.dispatch_event(&Event::ButtonDown(1));
+    /// dispatcher.dispatch_event(&Event::ButtonUp(1));
+    /// # }
+    /// ```
+    pub fn new(listener: L, window: Duration, correlate: F) -> Self {
+        Self {
+            listener,
+            window,
+            synthesise: correlate,
+            pending: Mutex::new(Vec::new()),
+            clock: SystemClock,
+        }
+    }
+}
+
+impl<L, T: Clone, F, C: Clock> Correlate<L, T, F, C> {
+    /// Reads the time from `clock` instead.
+    pub fn clock<D: Clock>(self, clock: D) -> Correlate<L, T, F, D> {
+        Correlate {
+            listener: self.listener,
+            window: self.window,
+            synthesise: self.synthesise,
+            pending: self.pending,
+            clock,
+        }
+    }
+
+    /// Discards expired pending events and pairs `event` with the oldest
+    /// pending event it correlates with, keeping `event` pending otherwise.
+    fn pair<U>(&self, event: &T) -> Option<U>
+    where
+        F: Fn(&T, &T) -> Option<U>,
+    {
+        let now = self.clock.now();
+        let mut pending = self.pending.lock().unwrap_or_else(PoisonError::into_inner);
+
+        pending.retain(|(arrival, _)| now.duration_since(*arrival) <= self.window);
+
+        let correlated = pending
+            .iter()
+            .enumerate()
+            .find_map(|(index, (_, earlier))| {
+                (self.synthesise)(earlier, event).map(|correlated| (index, correlated))
+            });
+
+        if let Some((index, correlated)) = correlated {
+            pending.remove(index);
+
+            Some(correlated)
+        } else {
+            pending.push((now, event.clone()));
+
+            None
+        }
+    }
+}
+
+impl_listeners! {
+    impl<U, L, F, C> for Correlate<L, T, F, C>
+    where [F: Fn(&T, &T) -> Option<U>, C: Clock,]
+    forwarding U: |this, event| this.pair(event)
+}
+
+/// Creates the wrapped listener once the first event is forwarded to it.
+///
+/// Useful for listeners expensive to create, which might never receive an
+/// event.
+/// Afterwards, the created listener receives every event.
+pub struct Lazy<L, F> {
+    factory: F,
+    listener: RwLock<Option<Arc<L>>>,
+}
+
+impl<L, F> Lazy<L, F>
+where
+    F: Fn() -> L,
+{
+    /// Wraps `factory`, calling it to create the listener on the first
+    /// event.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parallel")] {
+    /// use hey_listen::{
+    ///     adapters::Lazy,
+    ///     sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener},
+    /// };
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Crash,
+    /// }
+    ///
+    /// struct CrashReporter {
+    ///     symbols: Vec<String>,
+    /// }
+    ///
+    /// impl ParallelListener<Event> for CrashReporter {
+    ///     fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> { None }
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// // Symbols are only loaded once a crash occurs.
+    /// dispatcher.add_listener(
+    ///     Event::Crash,
+    ///     Lazy::new(|| CrashReporter {
+    ///         symbols: vec!["main".to_string()],
+    ///     }),
+    /// );
+    /// # }
+    /// ```
+    pub fn new(factory: F) -> Self {
+        Self {
+            factory,
+            listener: RwLock::new(None),
+        }
+    }
+
+    /// Returns the wrapped listener, creating it if necessary.
+    fn instance(&self) -> Arc<L> {
+        if let Some(listener) = &*self.listener.read().unwrap_or_else(PoisonError::into_inner) {
+            return Arc::clone(listener);
+        }
+
+        let mut listener = self
+            .listener
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        Arc::clone(listener.get_or_insert_with(|| Arc::new((self.factory)())))
+    }
+}
+
+impl_listeners! {
+    impl<L, F> for Lazy<L, F>
+    where [F: Fn() -> L,]
+    forwarding T: |_this, event| Some(event),
+    to |this| this.instance()
+}
+
+/// Forwards every event to exactly one of its listeners, like a pool of
+/// workers sharing jobs.
+///
+/// Listeners take turns in order, each receiving as many consecutive
+/// events as its weight.
+///
+/// **Note**: A listener requesting to stop listening removes the whole
+/// pool.
+pub struct Pool<L> {
+    listeners: Vec<L>,
+    /// The sum of the weights of every listener up to and including the
+    /// listener at the same index.
+    cumulative_weights: Vec<usize>,
+    count: AtomicUsize,
+}
+
+impl<L> Pool<L> {
+    /// Creates a pool forwarding to `listeners` in turns.
+    ///
+    /// # Panics
+    /// Panics if `listeners` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parallel")] {
+    /// use hey_listen::{
+    ///     adapters::Pool,
+    ///     sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener},
+    /// };
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Job,
+    /// }
+    ///
+    /// struct Worker {
+    ///     id: usize,
+    /// }
+    ///
+    /// impl ParallelListener<Event> for Worker {
+    ///     fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> {
+    ///         println!("Worker {} takes the job.", self.id);
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// let workers = (0..4).map(|id| Worker { id });
+    ///
+    /// dispatcher.add_listener(Event::Job, Pool::round_robin(workers));
+    /// # }
+    /// ```
+    pub fn round_robin(listeners: impl IntoIterator<Item = L>) -> Self {
+        Self::weighted(listeners.into_iter().map(|listener| (listener, 1)))
+    }
+
+    /// Creates a pool forwarding to `listeners` in turns, each receiving as
+    /// many consecutive events as its paired weight.
+    ///
+    /// # Panics
+    /// Panics if the weights sum up to zero.
+    pub fn weighted(listeners: impl IntoIterator<Item = (L, usize)>) -> Self {
+        let mut total_weight = 0;
+        let (listeners, cumulative_weights): (Vec<_>, Vec<_>) = listeners
+            .into_iter()
+            .map(|(listener, weight)| {
+                total_weight += weight;
+
+                (listener, total_weight)
+            })
+            .unzip();
+
+        assert!(
+            total_weight > 0,
+            "A pool needs at least one weighted listener"
+        );
+
+        Self {
+            listeners,
+            cumulative_weights,
+            count: AtomicUsize::new(0),
+        }
+    }
+
+    /// Counts an arriving event and returns the listener whose turn it is.
+    fn next(&self) -> &L {
+        let total_weight = self.cumulative_weights[self.cumulative_weights.len() - 1];
+        let turn = self
+            .count
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |count| {
+                Some((count + 1) % total_weight)
+            })
+            .unwrap_or_default();
+        let index = self
+            .cumulative_weights
+            .iter()
+            .position(|cumulative_weight| turn < *cumulative_weight)
+            .unwrap_or_default();
+
+        &self.listeners[index]
+    }
+}
+
+impl_listeners! {
+    impl<L> for Pool<L>
+    where []
+    forwarding T: |_this, event| Some(event),
+    to |this| this.next()
+}
+
+/// Forwards every event to one of its listeners, chosen by the event's
+/// hash.
+///
+/// Equal events always reach the same listener, preserving their order,
+/// while different events spread across all listeners.
+/// Listeners are chosen by rendezvous hashing, a sharded adapter with one
+/// listener more or less only moves the events of that listener.
+///
+/// **Note**: The dispatcher needs a [`KeyExtract`]-strategy keying events
+/// by less than their hash, e.g. [`ByDiscriminant`].
+/// With the default [`Identity`]-strategy, every event reaching the adapter
+/// equals the event-key it has been added for and thus hashes to the same
+/// listener.
+///
+/// **Note**: A listener requesting to stop listening removes the whole
+/// adapter.
+///
+/// [`ByDiscriminant`]: crate::key::ByDiscriminant
+pub struct Sharded<L> {
+    listeners: Vec<L>,
+}
+
+impl<L> Sharded<L> {
+    /// Creates an adapter distributing events across `listeners`.
+    ///
+    /// # Panics
+    /// Panics if `listeners` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parallel")] {
+    /// use hey_listen::{
+    ///     adapters::Sharded,
+    ///     key::ByDiscriminant,
+    ///     sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener},
+    /// };
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// #[derive(Clone)]
+    /// enum Event {
+    ///     Moved { entity: u32, x: i32 },
+    /// }
+    ///
+    /// // Events of the same entity reach the same worker.
+    /// impl Hash for Event {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         let Event::Moved { entity, .. } = self;
+    ///
+    ///         entity.hash(state);
+    ///     }
+    /// }
+    ///
+    /// struct Worker;
+    ///
+    /// impl ParallelListener<Event> for Worker {
+    ///     fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> { None }
+    /// }
+    ///
+    /// // Keyed by variant, all moves reach the workers.
+    /// let mut dispatcher: ParallelDispatcher<Event, ByDiscriminant> = ParallelDispatcher::new(1)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// let workers = Sharded::new(vec![Worker, Worker, Worker]);
+    ///
+    /// dispatcher.add_listener(Event::Moved { entity: 0, x: 0 }, workers);
+    /// dispatcher.dispatch_event(&Event::Moved { entity: 7, x: 3 });
+    /// # }
+    /// ```
+    pub fn new(listeners: impl IntoIterator<Item = L>) -> Self {
+        let listeners: Vec<_> = listeners.into_iter().collect();
+
+        assert!(
+            !listeners.is_empty(),
+            "Sharding needs at least one listener"
+        );
+
+        Self { listeners }
+    }
+
+    /// Returns the listener `event` belongs to.
+    fn shard<T: Hash>(&self, event: &T) -> &L {
+        let mut hasher = DefaultHasher::new();
+        event.hash(&mut hasher);
+        let event_hash = hasher.finish();
+
+        let index = (0..self.listeners.len())
+            .max_by_key(|index| {
+                let mut hasher = DefaultHasher::new();
+                event_hash.hash(&mut hasher);
+                index.hash(&mut hasher);
+
+                hasher.finish()
+            })
+            .unwrap_or_default();
+
+        &self.listeners[index]
+    }
+}
+
+impl_listeners! {
+    impl<L> for Sharded<L>
+    where [T: Hash,]
+    forwarding T: |_this, event| Some(event),
+    to |this| this.shard(event)
+}
@@ -0,0 +1,169 @@
+//! Dispatchers keeping their listeners in a bump arena.
+//!
+//! An [`ArenaDispatcher`](crate::arena::ArenaDispatcher) allocates every
+//! listener and closure added to it in a [`Bump`](bumpalo::Bump)-arena it borrows, instead
+//! of boxing each one on the heap.
+//! Listeners added one after another, e.g. at startup, end up next to each
+//! other in memory, dispatching iterates them without chasing scattered
+//! allocations and thousands of listeners do not fragment the heap.
+//!
+//! The arena frees all listeners at once when it is dropped, it never runs
+//! their destructors. Listeners holding resources released on drop, e.g.
+//! files or reference-counted state, keep them until the process exits.
+
+use super::{
+    key::{Identity, KeyExtract},
+    static_dispatcher::StaticListener,
+};
+use bumpalo::{collections::Vec as BumpVec, Bump};
+use std::collections::{HashMap, HashSet};
+
+/// A listener allocated in the arena.
+type ArenaListener<'a, T> = &'a mut dyn StaticListener<T>;
+
+/// A dispatcher allocating its listeners in the arena `'a`.
+///
+/// Listeners receive events mutably, like listeners of a
+/// [`BoundedDispatcher`](crate::bounded::BoundedDispatcher), and are
+/// dispatched to in the order they have been added.
+/// Removing listeners leaves their memory in the arena until it is dropped.
+///
+/// `X` is the [`KeyExtract`]-strategy deriving the key listeners are
+/// registered by from events, by default the event itself.
+///
+/// # Examples
+///
+/// ```rust
+/// use bumpalo::Bump;
+/// use hey_listen::{arena::ArenaDispatcher, static_dispatcher::StaticListener};
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Tick,
+/// }
+///
+/// struct Particle {
+///     age: u32,
+/// }
+///
+/// impl StaticListener<Event> for Particle {
+///     fn on_event(&mut self, _event: &Event) {
+///         self.age += 1;
+///     }
+/// }
+///
+/// let arena = Bump::new();
+/// let mut dispatcher: ArenaDispatcher<'_, Event> = ArenaDispatcher::new(&arena);
+///
+/// for _ in 0..1_000 {
+///     dispatcher.add_listener(Event::Tick, Particle { age: 0 });
+/// }
+///
+/// dispatcher.dispatch_event(&Event::Tick);
+///
+/// assert_eq!(dispatcher.listener_count(&Event::Tick), 1_000);
+/// ```
+pub struct ArenaDispatcher<'a, T, X = Identity>
+where
+    X: KeyExtract<T>,
+{
+    arena: &'a Bump,
+    events: HashMap<X::Key, BumpVec<'a, ArenaListener<'a, T>>>,
+}
+
+impl<'a, T, X> ArenaDispatcher<'a, T, X>
+where
+    T: 'a,
+    X: KeyExtract<T>,
+{
+    /// Creates a dispatcher allocating its listeners in `arena`.
+    #[must_use]
+    pub fn new(arena: &'a Bump) -> Self {
+        Self {
+            arena,
+            events: HashMap::new(),
+        }
+    }
+
+    /// Moves `listener` into the arena and adds it to listen for
+    /// `event_key`.
+    // Taking `event_key` by value mirrors the other dispatchers.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn add_listener<D: StaticListener<T> + 'a>(&mut self, event_key: T, listener: D) {
+        let arena = self.arena;
+        let listener: ArenaListener<'a, T> = arena.alloc(listener);
+
+        self.events
+            .entry(X::extract(&event_key))
+            .or_insert_with(|| BumpVec::new_in(arena))
+            .push(listener);
+    }
+
+    /// Moves the closure `listener` into the arena and adds it to listen
+    /// for `event_key`.
+    pub fn add_fn<F>(&mut self, event_key: T, listener: F)
+    where
+        F: FnMut(&T) + 'a,
+    {
+        self.add_listener(event_key, FnStaticListener(listener));
+    }
+
+    /// Removes all listeners of `event_key` and returns how many have been
+    /// removed, their memory is reclaimed once the arena is dropped.
+    pub fn remove_all_listeners(&mut self, event_key: &T) -> usize {
+        self.events
+            .remove(&X::extract(event_key))
+            .map_or(0, |listeners| listeners.len())
+    }
+
+    /// Returns how many listeners listen for `event_key`.
+    #[must_use]
+    pub fn listener_count(&self, event_key: &T) -> usize {
+        self.events
+            .get(&X::extract(event_key))
+            .map_or(0, BumpVec::len)
+    }
+
+    /// Returns how many listeners listen for any event-key.
+    #[must_use]
+    pub fn total_listeners(&self) -> usize {
+        self.events.values().map(BumpVec::len).sum()
+    }
+
+    /// Returns the event-keys at least one listener listens for.
+    #[must_use]
+    pub fn event_keys(&self) -> HashSet<X::Key> {
+        self.events
+            .iter()
+            .filter(|(_, listeners)| !listeners.is_empty())
+            .map(|(event_key, _)| event_key.clone())
+            .collect()
+    }
+
+    /// Returns whether no listener listens for any event-key.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.events.values().all(BumpVec::is_empty)
+    }
+
+    /// Dispatches `event` to all listeners of its key.
+    pub fn dispatch_event(&mut self, event: &T) {
+        if let Some(listeners) = self.events.get_mut(&X::extract(event)) {
+            for listener in listeners.iter_mut() {
+                listener.on_event(event);
+            }
+        }
+    }
+}
+
+/// A closure added via [`ArenaDispatcher::add_fn`].
+struct FnStaticListener<F>(F);
+
+impl<T, F> StaticListener<T> for FnStaticListener<F>
+where
+    F: FnMut(&T),
+{
+    fn on_event(&mut self, event: &T) {
+        (self.0)(event);
+    }
+}
@@ -0,0 +1,162 @@
+//! Hooks observing events dropped on purpose.
+//!
+//! Adapters such as [`Throttled`](crate::adapters::Throttled) and guards of
+//! a `PriorityDispatcher` intentionally drop events.
+//! Setting a [`DropHook`](crate::audit::DropHook) makes those drops
+//! observable, a [`DropAudit`](crate::audit::DropAudit) counts them per
+//! event-key.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "parallel")] {
+//! use hey_listen::{
+//!     adapters::Sampled,
+//!     audit::{DropAudit, DropReason},
+//!     sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener},
+//! };
+//!
+//! #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+//! enum Event {
+//!     Frame,
+//! }
+//!
+//! struct DebugOverlay;
+//!
+//! impl ParallelListener<Event> for DebugOverlay {
+//!     fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> { None }
+//! }
+//!
+//! let audit: DropAudit<Event> = DropAudit::new(|event: &Event, reason| {
+//!     println!("Dropped {:?}: {:?}", event, reason);
+//! });
+//! let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+//!     .expect("Failed to build threadpool");
+//!
+//! dispatcher.add_listener(
+//!     Event::Frame,
+//!     Sampled::every_n(DebugOverlay, 2).on_dropped(audit.clone()),
+//! );
+//!
+//! dispatcher.dispatch_event(&Event::Frame);
+//! dispatcher.dispatch_event(&Event::Frame);
+//!
+//! assert_eq!(audit.dropped(&Event::Frame), 1);
+//! # }
+//! ```
+use crate::key::{Identity, KeyExtract};
+use std::{
+    collections::HashMap,
+    marker::PhantomData,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+/// Why an event has been dropped.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DropReason {
+    /// A [`Throttled`](crate::adapters::Throttled) received the event
+    /// sooner than its minimum interval.
+    Throttled,
+    /// A [`Sampled`](crate::adapters::Sampled) skipped the event.
+    Sampled,
+    /// The guard of the event's key rejected the event.
+    Guarded,
+}
+
+/// Observes events dropped on purpose.
+///
+/// Implemented for closures taking the event and the [`DropReason`], and
+/// for `()`, ignoring drops.
+pub trait DropHook<T> {
+    /// Called with each `event` dropped for `reason`.
+    fn on_dropped(&self, event: &T, reason: DropReason);
+}
+
+impl<T> DropHook<T> for () {
+    fn on_dropped(&self, _event: &T, _reason: DropReason) {}
+}
+
+impl<T, F> DropHook<T> for F
+where
+    F: Fn(&T, DropReason),
+{
+    fn on_dropped(&self, event: &T, reason: DropReason) {
+        self(event, reason);
+    }
+}
+
+/// Counts dropped events per event-key, before passing them on to a hook.
+///
+/// Clones share their counts, hence a clone can be handed to each adapter
+/// while the original is kept for reading the counts.
+///
+/// `X` is the [`KeyExtract`]-strategy deriving the key drops are counted
+/// by, by default the event itself.
+pub struct DropAudit<T, X = Identity>
+where
+    X: KeyExtract<T>,
+{
+    counts: Arc<Mutex<HashMap<X::Key, usize>>>,
+    hook: Arc<dyn DropHook<T> + Send + Sync>,
+    key_extract: PhantomData<fn() -> X>,
+}
+
+impl<T, X> DropAudit<T, X>
+where
+    X: KeyExtract<T>,
+{
+    /// Creates an audit without any drops, passing drops on to `hook`.
+    ///
+    /// Pass `()` to count drops only.
+    #[must_use]
+    pub fn new<H>(hook: H) -> Self
+    where
+        H: DropHook<T> + Send + Sync + 'static,
+    {
+        Self {
+            counts: Arc::new(Mutex::new(HashMap::new())),
+            hook: Arc::new(hook),
+            key_extract: PhantomData,
+        }
+    }
+
+    /// Returns how many events of `event_key` have been dropped.
+    #[must_use]
+    pub fn dropped(&self, event_key: &T) -> usize {
+        self.counts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&X::extract(event_key))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+impl<T, X> Clone for DropAudit<T, X>
+where
+    X: KeyExtract<T>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            counts: Arc::clone(&self.counts),
+            hook: Arc::clone(&self.hook),
+            key_extract: PhantomData,
+        }
+    }
+}
+
+impl<T, X> DropHook<T> for DropAudit<T, X>
+where
+    X: KeyExtract<T>,
+{
+    fn on_dropped(&self, event: &T, reason: DropReason) {
+        *self
+            .counts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(X::extract(event))
+            .or_default() += 1;
+
+        self.hook.on_dropped(event, reason);
+    }
+}
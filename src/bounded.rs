@@ -0,0 +1,238 @@
+//! Fixed-capacity dispatchers for allocation-averse contexts.
+//!
+//! A [`BoundedDispatcher`](crate::bounded::BoundedDispatcher) stores its
+//! event-keys and listeners inline, in arrays sized by its const parameters.
+//! Neither adding listeners nor dispatching allocates, which suits
+//! real-time loops and embedded targets; the module only relies on `core`.
+//!
+//! Listeners are borrowed instead of boxed and receive events mutably, see
+//! [`StaticListener`](crate::static_dispatcher::StaticListener).
+
+use super::static_dispatcher::StaticListener;
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    slice::Iter,
+};
+
+/// Error when adding a listener to a full [`BoundedDispatcher`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CapacityError {
+    /// All `MAX_KEYS` event-keys are in use.
+    Keys,
+    /// The event-key already has `MAX_LISTENERS` listeners.
+    Listeners,
+}
+
+impl Display for CapacityError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Keys => formatter.write_str("No event-key left"),
+            Self::Listeners => formatter.write_str("No listener left for the event-key"),
+        }
+    }
+}
+
+/// The listeners of one event-key.
+struct Slot<'a, T, const MAX_LISTENERS: usize> {
+    event_key: T,
+    listeners: [Option<&'a mut dyn StaticListener<T>>; MAX_LISTENERS],
+}
+
+/// Iterates the event-keys of a [`BoundedDispatcher`], created via
+/// [`BoundedDispatcher::event_keys`].
+pub struct EventKeys<'s, 'a, T, const MAX_LISTENERS: usize> {
+    slots: Iter<'s, Option<Slot<'a, T, MAX_LISTENERS>>>,
+}
+
+impl<'s, T, const MAX_LISTENERS: usize> Iterator for EventKeys<'s, '_, T, MAX_LISTENERS> {
+    type Item = &'s T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots
+            .by_ref()
+            .flatten()
+            .find(|slot| slot.listeners.iter().any(Option::is_some))
+            .map(|slot| &slot.event_key)
+    }
+}
+
+/// A dispatcher of at most `MAX_KEYS` event-keys with at most
+/// `MAX_LISTENERS` listeners each, never allocating.
+///
+/// Events are their own key, found by comparing them via [`PartialEq`]
+/// instead of hashing.
+/// Listeners are dispatched to in the order they have been added.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::{bounded::BoundedDispatcher, static_dispatcher::StaticListener};
+///
+/// #[derive(Clone, Eq, PartialEq)]
+/// enum Event {
+///     Tick,
+/// }
+///
+/// struct Motor {
+///     steps: usize,
+/// }
+///
+/// impl StaticListener<Event> for Motor {
+///     fn on_event(&mut self, _event: &Event) {
+///         self.steps += 1;
+///     }
+/// }
+///
+/// let mut motor = Motor { steps: 0 };
+///
+/// {
+///     let mut dispatcher: BoundedDispatcher<'_, Event, 4, 2> = BoundedDispatcher::new();
+///
+///     dispatcher
+///         .add_listener(Event::Tick, &mut motor)
+///         .expect("Capacity exceeded");
+///     dispatcher.dispatch_event(&Event::Tick);
+/// }
+///
+/// assert_eq!(motor.steps, 1);
+/// ```
+pub struct BoundedDispatcher<'a, T, const MAX_KEYS: usize, const MAX_LISTENERS: usize> {
+    slots: [Option<Slot<'a, T, MAX_LISTENERS>>; MAX_KEYS],
+}
+
+impl<'a, T, const MAX_KEYS: usize, const MAX_LISTENERS: usize>
+    BoundedDispatcher<'a, T, MAX_KEYS, MAX_LISTENERS>
+{
+    const NO_SLOT: Option<Slot<'a, T, MAX_LISTENERS>> = None;
+    const NO_LISTENER: Option<&'a mut dyn StaticListener<T>> = None;
+
+    /// Creates a dispatcher without any listeners.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            slots: [Self::NO_SLOT; MAX_KEYS],
+        }
+    }
+
+    /// Returns how many listeners listen for any event-key.
+    #[must_use]
+    pub fn total_listeners(&self) -> usize {
+        self.slots
+            .iter()
+            .flatten()
+            .flat_map(|slot| slot.listeners.iter().flatten())
+            .count()
+    }
+
+    /// Returns the event-keys at least one listener listens for, in the
+    /// order of their slots.
+    ///
+    /// Unlike other dispatchers, an iterator is returned to not allocate.
+    pub fn event_keys(&self) -> EventKeys<'_, 'a, T, MAX_LISTENERS> {
+        EventKeys {
+            slots: self.slots.iter(),
+        }
+    }
+
+    /// Returns whether no listener listens for any event-key.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.total_listeners() == 0
+    }
+}
+
+impl<'a, T, const MAX_KEYS: usize, const MAX_LISTENERS: usize>
+    BoundedDispatcher<'a, T, MAX_KEYS, MAX_LISTENERS>
+where
+    T: PartialEq,
+{
+    /// Adds `listener` to listen for `event_key`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`CapacityError::Keys`] if `event_key` is new but all
+    /// event-keys are in use and with [`CapacityError::Listeners`] if
+    /// `event_key` has no room for another listener.
+    pub fn add_listener(
+        &mut self,
+        event_key: T,
+        listener: &'a mut dyn StaticListener<T>,
+    ) -> Result<(), CapacityError> {
+        let slot_index = if let Some(slot_index) = self.position(&event_key) {
+            slot_index
+        } else {
+            let slot_index = self
+                .slots
+                .iter()
+                .position(Option::is_none)
+                .ok_or(CapacityError::Keys)?;
+
+            self.slots[slot_index] = Some(Slot {
+                event_key,
+                listeners: [Self::NO_LISTENER; MAX_LISTENERS],
+            });
+
+            slot_index
+        };
+
+        let free = self.slots[slot_index]
+            .as_mut()
+            .and_then(|slot| {
+                slot.listeners
+                    .iter_mut()
+                    .find(|listener| listener.is_none())
+            })
+            .ok_or(CapacityError::Listeners)?;
+        *free = Some(listener);
+
+        Ok(())
+    }
+
+    /// Removes all listeners of `event_key`, freeing the event-key.
+    ///
+    /// Returns whether `event_key` had listeners.
+    pub fn remove_listeners(&mut self, event_key: &T) -> bool {
+        self.position(event_key)
+            .map_or(false, |slot_index| self.slots[slot_index].take().is_some())
+    }
+
+    /// Returns how many listeners listen for `event_key`.
+    #[must_use]
+    pub fn listener_count(&self, event_key: &T) -> usize {
+        self.position(event_key).map_or(0, |slot_index| {
+            self.slots[slot_index]
+                .iter()
+                .flat_map(|slot| slot.listeners.iter().flatten())
+                .count()
+        })
+    }
+
+    /// Returns the index of the slot of `event_key`, if any.
+    fn position(&self, event_key: &T) -> Option<usize> {
+        self.slots
+            .iter()
+            .position(|slot| matches!(slot, Some(slot) if slot.event_key == *event_key))
+    }
+
+    /// Dispatches `event` to all listeners of it.
+    pub fn dispatch_event(&mut self, event: &T) {
+        if let Some(slot) = self
+            .slots
+            .iter_mut()
+            .flatten()
+            .find(|slot| slot.event_key == *event)
+        {
+            for listener in slot.listeners.iter_mut().flatten() {
+                listener.on_event(event);
+            }
+        }
+    }
+}
+
+impl<T, const MAX_KEYS: usize, const MAX_LISTENERS: usize> Default
+    for BoundedDispatcher<'_, T, MAX_KEYS, MAX_LISTENERS>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
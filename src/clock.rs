@@ -0,0 +1,98 @@
+//! Clocks telling time-based adapters the current time.
+//!
+//! Adapters such as [`Throttled`](crate::adapters::Throttled) read the
+//! time from the [`SystemClock`](crate::clock::SystemClock) by default.
+//! Injecting a [`ManualClock`](crate::clock::ManualClock) instead lets tests
+//! advance time themselves rather than sleeping.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "parallel")] {
+//! use hey_listen::{
+//!     adapters::Throttled,
+//!     clock::ManualClock,
+//!     sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener},
+//! };
+//! use std::time::Duration;
+//!
+//! #[derive(Clone, Eq, Hash, PartialEq)]
+//! enum Event {
+//!     MouseMoved,
+//! }
+//!
+//! struct Tooltip;
+//!
+//! impl ParallelListener<Event> for Tooltip {
+//!     fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> { None }
+//! }
+//!
+//! let clock = ManualClock::new();
+//! let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+//!     .expect("Failed to build threadpool");
+//!
+//! dispatcher.add_listener(
+//!     Event::MouseMoved,
+//!     Throttled::new(Tooltip, Duration::from_millis(100)).clock(clock.clone()),
+//! );
+//!
+//! dispatcher.dispatch_event(&Event::MouseMoved);
+//! clock.advance(Duration::from_millis(100));
+//! dispatcher.dispatch_event(&Event::MouseMoved);
+//! # }
+//! ```
+use std::{
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// Tells the current time.
+pub trait Clock {
+    /// Returns the current time.
+    fn now(&self) -> Instant;
+}
+
+/// Tells the time of the operating system, the default clock.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Tells a time only advancing when told to.
+///
+/// Clones share their time, advancing one clone advances all of them.
+#[derive(Clone, Debug)]
+pub struct ManualClock {
+    now: Arc<Mutex<Instant>>,
+}
+
+impl ManualClock {
+    /// Creates a clock starting at the current time.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            now: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    /// Advances the clock by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner) += duration;
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
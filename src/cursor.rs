@@ -0,0 +1,184 @@
+//! Dispatches spread across multiple calls.
+//!
+//! The sequential dispatchers' `dispatch_event_budgeted`-method dispatches
+//! to as many listeners as fit in a [`Budget`](crate::cursor::Budget) and
+//! returns a [`DispatchCursor`](crate::cursor::DispatchCursor) if listeners
+//! are left, resuming the cursor continues with the remaining listeners,
+//! e.g. during the next frame.
+//!
+//! Cursors created via [`DispatchCursor::new`](crate::cursor::DispatchCursor::new)
+//! dispatch nothing until resumed, letting engines interleave a dispatch
+//! with other work at their own granularity, by time or by listener count.
+//!
+//! Listeners are identified via their [`ListenerId`](crate::handle::ListenerId),
+//! adding or removing listeners between two calls never repeats a listener.
+//! Listeners added in between receive the event as well, unless they are
+//! added to a tier the cursor has already passed: a priority-level
+//! dispatched to before the cursor's, or, on `rc`-dispatchers, the warm
+//! listeners once the cursor has reached the cold ones.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "priority")] {
+//! use hey_listen::sync::PriorityDispatcher;
+//! use std::time::Duration;
+//!
+//! #[derive(Clone, Eq, Hash, PartialEq)]
+//! enum Event {
+//!     Autosave,
+//! }
+//!
+//! let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+//!
+//! for chunk in 0..100 {
+//!     dispatcher.add_fn(Event::Autosave, |_event| None, chunk);
+//! }
+//!
+//! let mut cursor = dispatcher.dispatch_event_budgeted(&Event::Autosave, Duration::from_millis(2));
+//!
+//! // Continue with the remaining chunks during the following frames.
+//! while let Some(remaining) = cursor {
+//!     cursor = dispatcher.resume_dispatch(remaining, Duration::from_millis(2));
+//! }
+//! # }
+//! ```
+//!
+//! Dispatching ten listeners at a time:
+//!
+//! ```rust
+//! # #[cfg(feature = "priority")] {
+//! use hey_listen::{
+//!     cursor::{Budget, DispatchCursor},
+//!     sync::PriorityDispatcher,
+//! };
+//!
+//! #[derive(Clone, Eq, Hash, PartialEq)]
+//! enum Event {
+//!     Autosave,
+//! }
+//!
+//! let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+//!
+//! for chunk in 0..100 {
+//!     dispatcher.add_fn(Event::Autosave, |_event| None, chunk);
+//! }
+//!
+//! let mut cursor = Some(DispatchCursor::new(Event::Autosave));
+//! let mut steps = 0;
+//!
+//! while let Some(remaining) = cursor {
+//!     cursor = remaining.resume(&mut dispatcher, Budget::Listeners(10));
+//!     steps += 1;
+//! }
+//!
+//! assert_eq!(steps, 10);
+//! # }
+//! ```
+use crate::handle::ListenerId;
+use std::time::{Duration, Instant};
+
+/// Limits how many listeners a resumed dispatch reaches per call.
+///
+/// At least one listener is dispatched to per call, whichever the budget,
+/// ensuring every dispatch eventually finishes.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Budget {
+    /// Dispatches to listeners until the duration has passed.
+    Time(Duration),
+    /// Dispatches to at most this many listeners.
+    Listeners(usize),
+}
+
+impl Budget {
+    /// Starts spending the budget.
+    pub(crate) fn start(self) -> Spending {
+        Spending {
+            budget: self,
+            started: Instant::now(),
+            dispatched: 0,
+        }
+    }
+}
+
+impl From<Duration> for Budget {
+    fn from(duration: Duration) -> Self {
+        Self::Time(duration)
+    }
+}
+
+/// Tracks the spending of a [`Budget`] during one call.
+pub(crate) struct Spending {
+    budget: Budget,
+    started: Instant,
+    dispatched: usize,
+}
+
+impl Spending {
+    /// Returns whether another listener fits the budget, counting it as
+    /// dispatched if it does.
+    pub(crate) fn spend(&mut self) -> bool {
+        let fits = self.dispatched == 0
+            || match self.budget {
+                Budget::Time(duration) => self.started.elapsed() < duration,
+                Budget::Listeners(listeners) => self.dispatched < listeners,
+            };
+
+        if fits {
+            self.dispatched += 1;
+        }
+
+        fits
+    }
+}
+
+/// Dispatchers able to resume a [`DispatchCursor`].
+pub trait Resumable<T, P = ()> {
+    /// Continues the dispatch of `cursor` with as many of the remaining
+    /// listeners as fit in `budget`, returns the cursor if listeners are
+    /// still left.
+    fn resume_dispatch(
+        &mut self,
+        cursor: DispatchCursor<T, P>,
+        budget: Budget,
+    ) -> Option<DispatchCursor<T, P>>;
+}
+
+/// Tracks the progress of a dispatch spread across multiple calls.
+///
+/// `P` is the priority-level of the dispatcher's listeners.
+#[derive(Debug)]
+pub struct DispatchCursor<T, P = ()> {
+    pub(crate) event: T,
+    /// The tier of the listener dispatched to last.
+    pub(crate) tier: Option<P>,
+    /// The listener dispatched to last.
+    pub(crate) last_listener: Option<ListenerId>,
+}
+
+impl<T, P> DispatchCursor<T, P> {
+    /// Creates a cursor before the first listener of `event`, nothing is
+    /// dispatched until it is resumed.
+    pub const fn new(event: T) -> Self {
+        Self {
+            event,
+            tier: None,
+            last_listener: None,
+        }
+    }
+
+    /// Returns the event being dispatched.
+    pub const fn event(&self) -> &T {
+        &self.event
+    }
+
+    /// Continues the dispatch on `dispatcher` with as many of the remaining
+    /// listeners as fit in `budget`, returns the cursor if listeners are
+    /// still left.
+    pub fn resume<D>(self, dispatcher: &mut D, budget: impl Into<Budget>) -> Option<Self>
+    where
+        D: Resumable<T, P>,
+    {
+        dispatcher.resume_dispatch(self, budget.into())
+    }
+}
@@ -0,0 +1,177 @@
+//! Graphviz export of the event topology, for debugging.
+//!
+//! A [`Topology`](crate::dot::Topology) collects [`Snapshot`](crate::snapshot::Snapshot)s of named
+//! dispatchers and the bridges forwarding events between them, e.g. from a
+//! child- to its parent-dispatcher.
+//! [`Topology::export_dot`](crate::dot::Topology::export_dot) describes them
+//! in Graphviz' DOT-language, rendering it, e.g. via `dot -Tsvg`, visualises
+//! which listeners react to which event-keys.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "priority")] {
+//! use hey_listen::{
+//!     dot::Topology,
+//!     sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener},
+//! };
+//!
+//! #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+//! enum Event {
+//!     Tick,
+//! }
+//!
+//! struct Physics;
+//!
+//! impl PriorityListener<Event> for Physics {
+//!     fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> { None }
+//!
+//!     fn name(&self) -> &str {
+//!         "physics"
+//!     }
+//! }
+//!
+//! let world: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+//! let mut scene: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+//!
+//! scene.add_listener(Event::Tick, Physics, 1);
+//!
+//! let dot = Topology::new()
+//!     .dispatcher("world", &world.snapshot())
+//!     .dispatcher("scene", &scene.snapshot())
+//!     .bridge("scene", "world")
+//!     .export_dot();
+//!
+//! assert!(dot.contains("physics"));
+//! # }
+//! ```
+use crate::snapshot::Snapshot;
+use std::fmt::{Debug, Write};
+
+/// A listener as described by a [`Topology`].
+struct Entry {
+    event_key: String,
+    priority: String,
+    name: String,
+}
+
+/// Describes dispatchers, their listeners, and the bridges between them.
+#[derive(Default)]
+pub struct Topology {
+    dispatchers: Vec<(String, Vec<Entry>)>,
+    bridges: Vec<(String, String)>,
+}
+
+impl Topology {
+    /// Creates an empty topology.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the dispatcher `name` with the listeners of `snapshot`.
+    ///
+    /// Event-keys and priorities are described via their
+    /// [`Debug`]-implementation, priorities of `()` are omitted.
+    #[must_use]
+    pub fn dispatcher<K, P>(mut self, name: impl Into<String>, snapshot: &Snapshot<K, P>) -> Self
+    where
+        K: Debug,
+        P: Debug,
+    {
+        let entries = snapshot
+            .listeners
+            .iter()
+            .map(|entry| Entry {
+                event_key: format!("{:?}", entry.event_key),
+                priority: format!("{:?}", entry.priority),
+                name: entry.name.clone(),
+            })
+            .collect();
+
+        self.dispatchers.push((name.into(), entries));
+
+        self
+    }
+
+    /// Adds a bridge forwarding events from the dispatcher `from` to the
+    /// dispatcher `to`.
+    #[must_use]
+    pub fn bridge(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.bridges.push((from.into(), to.into()));
+
+        self
+    }
+
+    /// Describes the topology in Graphviz' DOT-language.
+    ///
+    /// Dispatchers point to their event-keys, event-keys point to their
+    /// listeners labelled by priority, and bridges point from dispatcher to
+    /// dispatcher.
+    #[must_use]
+    pub fn export_dot(&self) -> String {
+        let mut dot = String::from("digraph events {\n");
+
+        for (dispatcher, entries) in &self.dispatchers {
+            let mut event_keys: Vec<&str> = Vec::new();
+
+            // Writing to a `String` cannot fail.
+            let _ = writeln!(
+                dot,
+                "    {} [label = {}, shape = box3d];",
+                quote(dispatcher),
+                quote(dispatcher)
+            );
+
+            for (index, entry) in entries.iter().enumerate() {
+                let event_key = quote(&format!("{}/key/{}", dispatcher, entry.event_key));
+                let listener = quote(&format!("{}/listener/{}", dispatcher, index));
+
+                if !event_keys.contains(&entry.event_key.as_str()) {
+                    event_keys.push(&entry.event_key);
+
+                    let _ = writeln!(
+                        dot,
+                        "    {} [label = {}, shape = box];",
+                        event_key,
+                        quote(&entry.event_key)
+                    );
+                    let _ = writeln!(dot, "    {} -> {};", quote(dispatcher), event_key);
+                }
+
+                let _ = writeln!(dot, "    {} [label = {}];", listener, quote(&entry.name));
+
+                if entry.priority == "()" {
+                    let _ = writeln!(dot, "    {} -> {};", event_key, listener);
+                } else {
+                    let _ = writeln!(
+                        dot,
+                        "    {} -> {} [label = {}];",
+                        event_key,
+                        listener,
+                        quote(&entry.priority)
+                    );
+                }
+            }
+        }
+
+        for (from, to) in &self.bridges {
+            let _ = writeln!(
+                dot,
+                "    {} -> {} [style = dashed, label = \"forwards\"];",
+                quote(from),
+                quote(to)
+            );
+        }
+
+        dot.push('}');
+        dot.push('\n');
+
+        dot
+    }
+}
+
+/// Quotes `id` as DOT-identifier.
+fn quote(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
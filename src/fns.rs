@@ -0,0 +1,313 @@
+//! Helpers turning closures into listeners.
+//!
+//! [`weakly`](crate::fns::weakly) creates a listener holding only a weak
+//! reference to shared state, calling a closure with the upgraded state for
+//! every event.
+//! Once the state has been dropped, the listener stops listening on its own,
+//! replacing the usual downgrade-upgrade boilerplate.
+//! Dispatchers purge such dead listeners via their `purge_dead`-method or
+//! when adding a listener to the same event-key, without waiting for an
+//! event.
+//!
+//! With any of the `sync`-module's dispatchers enabled,
+//! [`bind`](crate::fns::bind) creates a listener locking a shared value and
+//! calling one of its `&mut self`-methods with every event.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "parallel")] {
+//! use hey_listen::{fns::weakly, sync::ParallelDispatcher, RwLock};
+//! use std::sync::Arc;
+//!
+//! #[derive(Clone, Eq, Hash, PartialEq)]
+//! enum Event {
+//!     Hit,
+//! }
+//!
+//! let health = Arc::new(RwLock::new(10));
+//! let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+//!     .expect("Failed to build threadpool");
+//!
+//! dispatcher.add_listener(
+//!     Event::Hit,
+//!     weakly(&health, |health, _event| {
+//!         *health.write() -= 3;
+//!
+//!         None
+//!     }),
+//! );
+//!
+//! dispatcher.dispatch_event(&Event::Hit);
+//! assert_eq!(*health.read(), 7);
+//!
+//! // Once `health` is dropped, the listener stops listening.
+//! drop(health);
+//! dispatcher.dispatch_event(&Event::Hit);
+//! # }
+//! ```
+#[cfg(feature = "blocking")]
+use crate::rc::DispatcherRequest;
+#[cfg(feature = "async")]
+use crate::sync::AsyncDispatchResult;
+#[cfg(feature = "parallel")]
+use crate::sync::ParallelDispatchResult;
+#[cfg(feature = "priority")]
+use crate::sync::PriorityDispatcherResult;
+#[cfg(feature = "sync")]
+use crate::Mutex;
+use std::sync::{Arc, Weak};
+
+/// Creates a listener calling `on_event` with the upgraded `shared` and each
+/// event, stopping to listen once `shared` has been dropped.
+///
+/// The listener implements every enabled listener-trait whose request-type
+/// `on_event` returns.
+pub fn weakly<L, T, R, F>(shared: &Arc<L>, on_event: F) -> Weakly<L, F>
+where
+    F: Fn(&L, &T) -> Option<R>,
+{
+    Weakly {
+        shared: Arc::downgrade(shared),
+        on_event,
+    }
+}
+
+/// A listener created via [`weakly`].
+pub struct Weakly<L, F> {
+    shared: Weak<L>,
+    on_event: F,
+}
+
+impl<L, F> Weakly<L, F> {
+    /// Calls `on_event` with the upgraded shared state and `event`, returns
+    /// `stop_listening` if the shared state has been dropped.
+    fn call<T, R>(&self, event: &T, stop_listening: R) -> Option<R>
+    where
+        F: Fn(&L, &T) -> Option<R>,
+    {
+        self.shared
+            .upgrade()
+            .map_or(Some(stop_listening), |shared| {
+                (self.on_event)(&shared, event)
+            })
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<L, T, F> crate::rc::Listener<T> for Weakly<L, F>
+where
+    T: Clone + 'static,
+    F: Fn(&L, &T) -> Option<DispatcherRequest>,
+{
+    fn on_event(&self, event: &T) -> Option<DispatcherRequest> {
+        self.call(event, DispatcherRequest::StopListening)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.shared.strong_count() > 0
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<L, T, F> crate::sync::ParallelListener<T> for Weakly<L, F>
+where
+    T: Clone + Send + Sync + 'static,
+    F: Fn(&L, &T) -> Option<ParallelDispatchResult>,
+{
+    fn on_event(&self, event: &T) -> Option<ParallelDispatchResult> {
+        self.call(event, ParallelDispatchResult::StopListening)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.shared.strong_count() > 0
+    }
+}
+
+#[cfg(feature = "priority")]
+impl<L, T, F> crate::sync::PriorityListener<T> for Weakly<L, F>
+where
+    T: Clone + Send + Sync + 'static,
+    F: Fn(&L, &T) -> Option<PriorityDispatcherResult>,
+{
+    fn on_event(&self, event: &T) -> Option<PriorityDispatcherResult> {
+        self.call(event, PriorityDispatcherResult::StopListening)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.shared.strong_count() > 0
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<L, T, F> crate::sync::AsyncListener<T> for Weakly<L, F>
+where
+    L: Send + Sync,
+    T: Clone + Send + Sync + 'static,
+    F: Fn(&L, &T) -> Option<AsyncDispatchResult> + Send + Sync,
+{
+    async fn on_event(&self, event: &T) -> Option<AsyncDispatchResult> {
+        self.call(event, AsyncDispatchResult::StopListening)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.shared.strong_count() > 0
+    }
+}
+
+/// A closure boxed by the dispatchers' `add_fn`-methods.
+#[cfg(any(feature = "blocking", feature = "priority", feature = "parallel"))]
+pub(crate) struct FnListener<F>(pub(crate) F);
+
+#[cfg(feature = "blocking")]
+impl<T, F> crate::rc::Listener<T> for FnListener<F>
+where
+    T: Clone + 'static,
+    F: Fn(&T) -> Option<DispatcherRequest>,
+{
+    fn on_event(&self, event: &T) -> Option<DispatcherRequest> {
+        (self.0)(event)
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T, F> crate::sync::ParallelListener<T> for FnListener<F>
+where
+    T: Clone + Send + Sync + 'static,
+    F: Fn(&T) -> Option<ParallelDispatchResult>,
+{
+    fn on_event(&self, event: &T) -> Option<ParallelDispatchResult> {
+        (self.0)(event)
+    }
+}
+
+#[cfg(feature = "priority")]
+impl<T, F> crate::sync::PriorityListener<T> for FnListener<F>
+where
+    T: Clone + Send + Sync + 'static,
+    F: Fn(&T) -> Option<PriorityDispatcherResult>,
+{
+    fn on_event(&self, event: &T) -> Option<PriorityDispatcherResult> {
+        (self.0)(event)
+    }
+}
+
+/// Creates a listener locking `shared` and calling `method` with the locked
+/// value and each event, e.g. a `&mut self`-method of the value's type.
+///
+/// The listener implements every enabled listener-trait whose request-type
+/// `method` returns.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "parallel")] {
+/// use hey_listen::{
+///     fns::bind,
+///     sync::{ParallelDispatchResult, ParallelDispatcher},
+///     Mutex,
+/// };
+/// use std::sync::Arc;
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Hit,
+/// }
+///
+/// struct Player {
+///     health: u32,
+/// }
+///
+/// impl Player {
+///     fn on_hit(&mut self, _event: &Event) -> Option<ParallelDispatchResult> {
+///         self.health -= 3;
+///
+///         None
+///     }
+/// }
+///
+/// let player = Arc::new(Mutex::new(Player { health: 10 }));
+/// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+///     .expect("Failed to build threadpool");
+///
+/// dispatcher.add_listener(Event::Hit, bind(&player, Player::on_hit));
+/// dispatcher.dispatch_event(&Event::Hit);
+///
+/// assert_eq!(player.lock().health, 7);
+/// # }
+/// ```
+#[cfg(feature = "sync")]
+pub fn bind<L, T, R, F>(shared: &Arc<Mutex<L>>, method: F) -> Binding<L, F>
+where
+    F: Fn(&mut L, &T) -> Option<R>,
+{
+    Binding {
+        shared: Arc::clone(shared),
+        method,
+    }
+}
+
+/// A listener created via [`bind`].
+#[cfg(feature = "sync")]
+pub struct Binding<L, F> {
+    shared: Arc<Mutex<L>>,
+    method: F,
+}
+
+#[cfg(feature = "sync")]
+impl<L, F> Binding<L, F> {
+    /// Calls `method` with the locked shared value and `event`.
+    fn call<T, R>(&self, event: &T) -> Option<R>
+    where
+        F: Fn(&mut L, &T) -> Option<R>,
+    {
+        (self.method)(&mut self.shared.lock(), event)
+    }
+}
+
+#[cfg(all(feature = "blocking", feature = "sync"))]
+impl<L, T, F> crate::rc::Listener<T> for Binding<L, F>
+where
+    T: Clone + 'static,
+    F: Fn(&mut L, &T) -> Option<DispatcherRequest>,
+{
+    fn on_event(&self, event: &T) -> Option<DispatcherRequest> {
+        self.call(event)
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<L, T, F> crate::sync::ParallelListener<T> for Binding<L, F>
+where
+    T: Clone + Send + Sync + 'static,
+    F: Fn(&mut L, &T) -> Option<ParallelDispatchResult>,
+{
+    fn on_event(&self, event: &T) -> Option<ParallelDispatchResult> {
+        self.call(event)
+    }
+}
+
+#[cfg(feature = "priority")]
+impl<L, T, F> crate::sync::PriorityListener<T> for Binding<L, F>
+where
+    T: Clone + Send + Sync + 'static,
+    F: Fn(&mut L, &T) -> Option<PriorityDispatcherResult>,
+{
+    fn on_event(&self, event: &T) -> Option<PriorityDispatcherResult> {
+        self.call(event)
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<L, T, F> crate::sync::AsyncListener<T> for Binding<L, F>
+where
+    L: Send,
+    T: Clone + Send + Sync + 'static,
+    F: Fn(&mut L, &T) -> Option<AsyncDispatchResult> + Send + Sync,
+{
+    async fn on_event(&self, event: &T) -> Option<AsyncDispatchResult> {
+        self.call(event)
+    }
+}
@@ -0,0 +1,30 @@
+//! Handles identifying registered listeners.
+//!
+//! Adding a listener returns its [`ListenerId`](crate::handle::ListenerId),
+//! removing the listener via the id later does not require the listener to
+//! stop listening itself.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Source of listener-ids, unique across all dispatchers of the process.
+static NEXT_LISTENER_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Identifies a listener added to a dispatcher.
+///
+/// Ids are unique across all dispatchers, removing a listener via the id
+/// of a listener of another dispatcher removes nothing.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ListenerId(u64);
+
+impl ListenerId {
+    /// Creates an id no other listener has been given.
+    pub(crate) fn next() -> Self {
+        Self(NEXT_LISTENER_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Returns the number identifying the listener.
+    #[must_use]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+}
@@ -0,0 +1,133 @@
+//! Hooks observing every call of a listener.
+//!
+//! Each dispatcher accepts a pair of hooks via its `set_listener_hooks`,
+//! called right before and after each listener receives an event, e.g. to
+//! profile listeners, assert on their requests or step through dispatches.
+//! The after-hook receives the request the listener returned.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "priority")] {
+//! use hey_listen::sync::PriorityDispatcher;
+//! use std::time::Instant;
+//!
+//! #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+//! enum Event {
+//!     Tick,
+//! }
+//!
+//! let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+//! let start = Instant::now();
+//!
+//! dispatcher.set_listener_hooks(
+//!     |event, listener| println!("{} receives {:?}", listener.name, event),
+//!     move |_event, listener, request| {
+//!         println!("{} returned {:?} after {:?}", listener.name, request, start.elapsed());
+//!     },
+//! );
+//! dispatcher.add_fn(Event::Tick, |_event| None, 1);
+//!
+//! dispatcher.dispatch_event(&Event::Tick);
+//! # }
+//! ```
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::sync::Arc;
+
+/// Describes the listener a hook is called for.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct ListenerInfo<'a> {
+    /// The listener's name, as reported in a
+    /// [`Snapshot`](crate::snapshot::Snapshot).
+    pub name: &'a str,
+}
+
+/// Called with the event before a listener receives it.
+pub type BeforeHook<T> = Arc<dyn Fn(&T, ListenerInfo<'_>) + Send + Sync + 'static>;
+
+/// Called with the event and the listener's request after the listener
+/// received it.
+pub type AfterHook<T, R> = Arc<dyn Fn(&T, ListenerInfo<'_>, &Option<R>) + Send + Sync + 'static>;
+
+/// A pair of hooks surrounding every call of a listener.
+pub struct ListenerHooks<T, R> {
+    before: BeforeHook<T>,
+    after: AfterHook<T, R>,
+}
+
+impl<T, R> ListenerHooks<T, R> {
+    /// Creates hooks calling `before` and `after` around every listener.
+    pub fn new<B, A>(before: B, after: A) -> Self
+    where
+        B: Fn(&T, ListenerInfo<'_>) + Send + Sync + 'static,
+        A: Fn(&T, ListenerInfo<'_>, &Option<R>) + Send + Sync + 'static,
+    {
+        Self {
+            before: Arc::new(before),
+            after: Arc::new(after),
+        }
+    }
+}
+
+impl<T, R> Clone for ListenerHooks<T, R> {
+    fn clone(&self) -> Self {
+        Self {
+            before: Arc::clone(&self.before),
+            after: Arc::clone(&self.after),
+        }
+    }
+}
+
+/// Calls `on_event` surrounded by `hooks`, if any.
+#[cfg(any(feature = "blocking", feature = "priority", feature = "parallel"))]
+pub(crate) fn surround<T, R, F>(
+    hooks: Option<&ListenerHooks<T, R>>,
+    event: &T,
+    name: &str,
+    on_event: F,
+) -> Option<R>
+where
+    F: FnOnce() -> Option<R>,
+{
+    match hooks {
+        Some(hooks) => {
+            let info = ListenerInfo { name };
+
+            (hooks.before)(event, info);
+            let request = on_event();
+            (hooks.after)(event, info, &request);
+
+            request
+        }
+        None => on_event(),
+    }
+}
+
+/// Awaits `on_event` surrounded by `hooks`, if any.
+#[cfg(feature = "async")]
+pub(crate) async fn surround_async<T, R, F>(
+    hooks: Option<&ListenerHooks<T, R>>,
+    event: &T,
+    name: &str,
+    on_event: F,
+) -> Option<R>
+where
+    T: Sync,
+    R: Send,
+    F: Future<Output = Option<R>> + Send,
+{
+    match hooks {
+        Some(hooks) => {
+            let info = ListenerInfo { name };
+
+            (hooks.before)(event, info);
+            let request = on_event.await;
+            (hooks.after)(event, info, &request);
+
+            request
+        }
+        None => on_event.await,
+    }
+}
@@ -0,0 +1,308 @@
+//! Strategies deriving the key listeners are registered by from events.
+//!
+//! Dispatchers look listeners up by a key extracted from the dispatched
+//! event, while listeners still receive the full event.
+//! By default, the [`Identity`](crate::key::Identity)-strategy uses the event
+//! itself as key, requiring events to implement [`Eq`] and [`Hash`].
+//!
+//! Implementing [`KeyExtract`](crate::key::KeyExtract) allows keying by
+//! anything derived from events instead, e.g. their discriminant, without
+//! hand-writing [`Hash`]- and [`PartialEq`]-implementations ignoring fields.
+//! The [`ByDiscriminant`](crate::key::ByDiscriminant)-strategy does exactly
+//! that for `Enum`s.
+//!
+//! For string-named events, the `Interned`-strategy maps names to small
+//! [`KeyId`](crate::key::KeyId)s at registration, dispatchers then hash a
+//! `u32` instead of the full name to look listeners up, and callers caching
+//! the id dispatch via `dispatch_by_id` without hashing the name at all.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "priority")] {
+//! use hey_listen::{
+//!     key::KeyExtract,
+//!     sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener},
+//! };
+//!
+//! #[derive(Clone)]
+//! enum Event {
+//!     Damage(u32),
+//! }
+//!
+//! struct ByVariant;
+//!
+//! impl KeyExtract<Event> for ByVariant {
+//!     type Key = &'static str;
+//!
+//!     fn extract(event: &Event) -> Self::Key {
+//!         match event {
+//!             Event::Damage(_) => "damage",
+//!         }
+//!     }
+//! }
+//!
+//! struct Health;
+//!
+//! impl PriorityListener<Event> for Health {
+//!     fn on_event(&self, Event::Damage(amount): &Event) -> Option<PriorityDispatcherResult> {
+//!         assert_eq!(*amount, 3);
+//!
+//!         None
+//!     }
+//! }
+//!
+//! let mut dispatcher: PriorityDispatcher<u32, Event, ByVariant> = PriorityDispatcher::default();
+//!
+//! dispatcher.add_listener(Event::Damage(0), Health, 0);
+//! dispatcher.dispatch_event(&Event::Damage(3));
+//! # }
+//! ```
+#[cfg(feature = "sync")]
+use parking_lot::{const_rwlock, RwLock};
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    hash::Hash,
+    mem::{discriminant, Discriminant},
+};
+
+/// The names interned by the [`Interned`]-strategy, created on first use.
+#[cfg(feature = "sync")]
+static INTERNED: RwLock<Option<Interner>> = const_rwlock(None);
+
+/// Derives the key listeners are registered by from an event of type `T`.
+///
+/// Strategies are never instantiated, dispatchers merely name them as
+/// type-parameter.
+pub trait KeyExtract<T> {
+    /// The key listeners are registered and looked up by.
+    type Key: Eq + Hash + Clone;
+
+    /// Derives the key of `event`.
+    fn extract(event: &T) -> Self::Key;
+}
+
+/// Uses events themselves as key, the dispatchers' default strategy.
+pub struct Identity;
+
+impl<T> KeyExtract<T> for Identity
+where
+    T: Eq + Hash + Clone,
+{
+    type Key = T;
+
+    fn extract(event: &T) -> Self::Key {
+        event.clone()
+    }
+}
+
+/// Uses the discriminant of `Enum`-events as key, ignoring their fields.
+///
+/// Events only need to implement [`Clone`], listeners registered for one
+/// variant receive every event of that variant.
+pub struct ByDiscriminant;
+
+impl<T> KeyExtract<T> for ByDiscriminant {
+    type Key = Discriminant<T>;
+
+    fn extract(event: &T) -> Self::Key {
+        discriminant(event)
+    }
+}
+
+/// Enumerates every key of a type, e.g. one per variant of an event-`Enum`.
+///
+/// Allows [`Snapshot::unhandled_keys`] to report keys nobody listens to.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::key::EnumKey;
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Jump,
+///     Land,
+/// }
+///
+/// impl EnumKey for Event {
+///     fn keys() -> Vec<Self> {
+///         vec![Event::Jump, Event::Land]
+///     }
+/// }
+/// ```
+///
+/// [`Snapshot::unhandled_keys`]: crate::snapshot::Snapshot::unhandled_keys
+pub trait EnumKey: Sized {
+    /// Returns every key.
+    fn keys() -> Vec<Self>;
+}
+
+/// A small id standing in for an interned name, see [`Interner`].
+///
+/// Ids are events themselves, dispatchers register and dispatch them via
+/// the default [`Identity`]-strategy.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct KeyId(u32);
+
+/// Maps names to [`KeyId`]s, assigning each new name the next id.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "priority")] {
+/// use hey_listen::{
+///     key::{Interner, KeyId},
+///     sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener},
+/// };
+///
+/// struct Sound;
+///
+/// impl PriorityListener<KeyId> for Sound {
+///     fn on_event(&self, _event: &KeyId) -> Option<PriorityDispatcherResult> { None }
+/// }
+///
+/// let mut interner = Interner::new();
+/// let mut dispatcher: PriorityDispatcher<u32, KeyId> = PriorityDispatcher::default();
+///
+/// // Names are hashed once, at registration.
+/// let jump = interner.intern("player.jump");
+/// dispatcher.add_listener(jump, Sound, 1);
+///
+/// // Callers caching the id dispatch by `u32`.
+/// dispatcher.dispatch_event(&jump);
+/// assert_eq!(interner.resolve(jump), Some("player.jump"));
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Interner {
+    ids: HashMap<String, KeyId>,
+    names: Vec<String>,
+}
+
+impl Interner {
+    /// Creates an interner without any names.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the id of `name`, assigning the next id if `name` is new.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than [`u32::MAX`] names are interned.
+    pub fn intern(&mut self, name: &str) -> KeyId {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+
+        let id = KeyId(u32::try_from(self.names.len()).expect("Interned too many names"));
+
+        self.ids.insert(name.to_string(), id);
+        self.names.push(name.to_string());
+
+        id
+    }
+
+    /// Returns the id of `name`, if it has been interned.
+    #[must_use]
+    pub fn id(&self, name: &str) -> Option<KeyId> {
+        self.ids.get(name).copied()
+    }
+
+    /// Returns the name `id` has been assigned to.
+    #[must_use]
+    pub fn resolve(&self, id: KeyId) -> Option<&str> {
+        self.names.get(id.0 as usize).map(String::as_str)
+    }
+}
+
+/// Keys string-named events by the [`KeyId`] of their name, interned in a
+/// process-wide [`Interner`].
+///
+/// Names are interned the first time an event carrying them is registered
+/// or dispatched, afterwards dispatchers hash the `u32`-id to look listeners
+/// up. Callers caching the id skip hashing the name entirely by dispatching
+/// via the dispatchers' `dispatch_by_id`-method.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "priority")] {
+/// use hey_listen::{
+///     key::Interned,
+///     sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener},
+/// };
+///
+/// struct Sound;
+///
+/// impl PriorityListener<String> for Sound {
+///     fn on_event(&self, _event: &String) -> Option<PriorityDispatcherResult> { None }
+/// }
+///
+/// let mut dispatcher: PriorityDispatcher<u32, String, Interned> = PriorityDispatcher::default();
+///
+/// // The name is hashed once, at registration.
+/// dispatcher.add_listener(String::from("player.jump"), Sound, 1);
+///
+/// let jump = Interned::id("player.jump").expect("Interned at registration");
+///
+/// // Callers caching the id dispatch by `u32`.
+/// dispatcher.dispatch_by_id(jump, &String::from("player.jump"));
+/// assert_eq!(Interned::resolve(jump).as_deref(), Some("player.jump"));
+/// # }
+/// ```
+#[cfg(feature = "sync")]
+pub struct Interned;
+
+#[cfg(feature = "sync")]
+impl Interned {
+    /// Returns the id of `name`, assigning the next id if `name` is new.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than [`u32::MAX`] names are interned.
+    pub fn intern(name: &str) -> KeyId {
+        if let Some(id) = Self::id(name) {
+            return id;
+        }
+
+        INTERNED
+            .write()
+            .get_or_insert_with(Interner::new)
+            .intern(name)
+    }
+
+    /// Returns the id of `name`, if it has been interned.
+    #[must_use]
+    pub fn id(name: &str) -> Option<KeyId> {
+        INTERNED
+            .read()
+            .as_ref()
+            .and_then(|interner| interner.id(name))
+    }
+
+    /// Returns the name `id` has been assigned to.
+    #[must_use]
+    pub fn resolve(id: KeyId) -> Option<String> {
+        INTERNED
+            .read()
+            .as_ref()
+            .and_then(|interner| interner.resolve(id))
+            .map(str::to_string)
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<T> KeyExtract<T> for Interned
+where
+    T: AsRef<str>,
+{
+    type Key = KeyId;
+
+    fn extract(event: &T) -> Self::Key {
+        Self::intern(event.as_ref())
+    }
+}
@@ -13,6 +13,18 @@
 //! hey_listen = "0.5"
 //! ```
 //!
+//! # Features
+//! Each dispatcher is enabled by its own feature, `default` enables
+//! `priority`, `parallel`, and `async`, `full` enables all of them:
+//!
+//! * `rc`: the single-threaded [`rc`]-dispatchers, formerly `blocking`.
+//! * `priority`: the prioritised dispatchers, without depending on Rayon.
+//! * `parallel`: the Rayon-driven [`ParallelDispatcher`](sync::ParallelDispatcher).
+//! * `async`: the Tokio-driven [`AsyncDispatcher`](sync::AsyncDispatcher).
+//!
+//! The latter three enable `sync`, providing the [`sync`]-module and its
+//! locks.
+//!
 //! # Example
 //! Here is a quick example on how to use the sync event-dispatcher:
 //!
@@ -50,26 +62,189 @@
 #![deny(clippy::cargo)]
 #![deny(missing_docs)]
 
+#[cfg(any(
+    feature = "blocking",
+    feature = "priority",
+    feature = "parallel",
+    feature = "async"
+))]
+/// The listener adapter module.
+pub mod adapters;
+#[cfg(feature = "arena")]
+/// The arena-allocated dispatcher module.
+pub mod arena;
+#[cfg(any(
+    feature = "blocking",
+    feature = "priority",
+    feature = "parallel",
+    feature = "async"
+))]
+/// The drop auditing module.
+pub mod audit;
+/// The fixed-capacity dispatcher module.
+pub mod bounded;
+/// The clock module.
+pub mod clock;
+#[cfg(any(feature = "blocking", feature = "priority"))]
+/// The resumable dispatch module.
+pub mod cursor;
+#[cfg(all(
+    feature = "dot",
+    any(
+        feature = "blocking",
+        feature = "priority",
+        feature = "parallel",
+        feature = "async"
+    )
+))]
+/// The Graphviz export module.
+pub mod dot;
+#[cfg(any(
+    feature = "blocking",
+    feature = "priority",
+    feature = "parallel",
+    feature = "async"
+))]
+/// The closure listener module.
+pub mod fns;
+#[cfg(any(
+    feature = "blocking",
+    feature = "priority",
+    feature = "parallel",
+    feature = "async"
+))]
+/// The listener handle module.
+pub mod handle;
+#[cfg(any(
+    feature = "blocking",
+    feature = "priority",
+    feature = "parallel",
+    feature = "async"
+))]
+/// The listener hooks module.
+pub mod hooks;
+/// The event-key extraction module.
+pub mod key;
+#[cfg(all(
+    any(feature = "log", feature = "tracing"),
+    any(
+        feature = "blocking",
+        feature = "priority",
+        feature = "parallel",
+        feature = "async"
+    )
+))]
+/// The event logging module.
+pub mod logging;
+#[cfg(any(feature = "blocking", feature = "priority", feature = "parallel"))]
+/// The multi-event listener module.
+pub mod multi;
+#[cfg(any(
+    feature = "blocking",
+    feature = "priority",
+    feature = "parallel",
+    feature = "async"
+))]
+/// The delivery order module.
+pub mod order;
+#[cfg(any(feature = "blocking", feature = "priority"))]
+/// The listener panic policy module.
+pub mod panic_policy;
 #[cfg(feature = "blocking")]
 /// The blocking dispatcher module.
 pub mod rc;
-#[cfg(any(feature = "parallel", feature = "async"))]
-/// The parallel/async dispatcher module.
+#[cfg(feature = "remote")]
+/// The remote dispatch module.
+pub mod remote;
+#[cfg(any(
+    feature = "blocking",
+    feature = "priority",
+    feature = "parallel",
+    feature = "async"
+))]
+/// The scoped subscription module.
+pub mod scope;
+#[cfg(any(
+    feature = "blocking",
+    feature = "priority",
+    feature = "parallel",
+    feature = "async"
+))]
+/// The dispatcher snapshot module.
+pub mod snapshot;
+/// The compile-time registered dispatcher module.
+pub mod static_dispatcher;
+#[cfg(all(
+    feature = "stress",
+    any(feature = "blocking", feature = "priority", feature = "parallel")
+))]
+/// The stress testing module.
+pub mod stress;
+#[cfg(feature = "sync")]
+/// The priority/parallel/async dispatcher module.
 pub mod sync;
+/// The type-keyed listener storage module.
+mod type_map;
+/// The typed result aggregator module.
+pub mod typed;
+#[cfg(all(
+    feature = "winit",
+    any(feature = "blocking", feature = "priority", feature = "parallel")
+))]
+/// The `winit` integration module.
+pub mod winit;
 
-#[cfg(any(feature = "parallel", feature = "async"))]
+#[cfg(feature = "sync")]
 pub use parking_lot::{Mutex, RwLock};
 
 #[cfg(feature = "parallel")]
 use rayon::ThreadPoolBuildError;
 
+/// Compiles the `README.md`'s code examples as doc-tests.
+#[cfg(doctest)]
+#[doc = include_str!("../README.md")]
+struct ReadmeDoctests;
+
 /// `hey_listen`'s Error collection.
 #[derive(Debug)]
 /// As long as there are no other errors, keep it locked away.
-#[cfg(feature = "parallel")]
+#[cfg(any(
+    feature = "priority",
+    feature = "parallel",
+    feature = "async",
+    feature = "remote"
+))]
 pub enum Error {
     /// Error when building a threadpool fails.
+    #[cfg(feature = "parallel")]
     ThreadPoolBuilder(String),
+    /// Error when referring to a stage that has not been added.
+    #[cfg(any(feature = "priority", feature = "parallel"))]
+    UnknownStage(String),
+    /// Error when referring to a listener that has not been registered.
+    #[cfg(feature = "priority")]
+    UnknownListener(String),
+    /// Error when dispatching on a dispatcher, or forwarding an event to a
+    /// parent-dispatcher, that is already dispatching on the same thread,
+    /// which would deadlock.
+    #[cfg(feature = "priority")]
+    ReentrantDispatch,
+    /// Error when dispatching without blocking finds the dispatcher locked
+    /// by another dispatch.
+    #[cfg(feature = "priority")]
+    WouldBlock,
+    /// Error when referring to an exclusion group that has not been added.
+    #[cfg(feature = "async")]
+    UnknownExclusionGroup(String),
+    /// Error when a dispatch has been aborted via its handle.
+    #[cfg(feature = "async")]
+    DispatchAborted,
+    /// Error when reading from or writing to a remote connection fails.
+    #[cfg(feature = "remote")]
+    Io(String),
+    /// Error when an event cannot be serialised or deserialised.
+    #[cfg(feature = "remote")]
+    Serialisation(String),
 }
 
 #[cfg(feature = "parallel")]
@@ -78,3 +253,17 @@ impl From<ThreadPoolBuildError> for Error {
         Self::ThreadPoolBuilder(error.to_string())
     }
 }
+
+#[cfg(feature = "remote")]
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error.to_string())
+    }
+}
+
+#[cfg(feature = "remote")]
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Serialisation(error.to_string())
+    }
+}
@@ -0,0 +1,177 @@
+//! Listeners logging every event they receive.
+//!
+//! [`LogListener`](crate::logging::LogListener) logs via the `log`-crate,
+//! [`TracingListener`](crate::logging::TracingListener) emits `tracing`
+//! events.
+//! Both format events via their [`Debug`]-implementation and never stop
+//! listening, adding one for every event-key yields a full event trace.
+//! Events dispatched via a `ParallelDispatcher` are logged along with their
+//! `Correlation`, relating follow-up events to the events causing them.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(all(feature = "log", feature = "parallel"))] {
+//! use hey_listen::{logging::LogListener, sync::ParallelDispatcher};
+//!
+//! #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+//! enum Event {
+//!     Damage(u32),
+//! }
+//!
+//! let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+//!     .expect("Failed to build threadpool");
+//!
+//! dispatcher.add_listener(Event::Damage(0), LogListener::new(log::Level::Debug));
+//! # }
+//! ```
+#[cfg(feature = "blocking")]
+use crate::rc::DispatcherRequest;
+#[cfg(feature = "parallel")]
+use crate::sync::correlation::Correlation;
+#[cfg(feature = "async")]
+use crate::sync::AsyncDispatchResult;
+#[cfg(feature = "parallel")]
+use crate::sync::ParallelDispatchResult;
+#[cfg(feature = "priority")]
+use crate::sync::PriorityDispatcherResult;
+use std::fmt::{Debug, Display};
+
+/// Returns the correlation of the event being dispatched, if any.
+#[cfg(feature = "parallel")]
+fn correlation() -> Option<impl Display> {
+    Correlation::current()
+}
+
+/// Returns the correlation of the event being dispatched, if any.
+#[cfg(not(feature = "parallel"))]
+const fn correlation() -> Option<impl Display> {
+    None::<&str>
+}
+
+/// Implements every enabled listener-trait for a logging listener.
+///
+/// `$log` logs `$event` and is called with every received event.
+macro_rules! impl_logging_listener {
+    ($listener:ty: |$this:ident, $event:ident| $log:expr) => {
+        #[cfg(feature = "blocking")]
+        impl<T> crate::rc::Listener<T> for $listener
+        where
+            T: Clone + Debug + 'static,
+        {
+            fn on_event(&self, event: &T) -> Option<DispatcherRequest> {
+                let ($this, $event) = (self, event);
+                $log;
+
+                None
+            }
+        }
+
+        #[cfg(feature = "parallel")]
+        impl<T> crate::sync::ParallelListener<T> for $listener
+        where
+            T: Clone + Debug + Send + Sync + 'static,
+        {
+            fn on_event(&self, event: &T) -> Option<ParallelDispatchResult> {
+                let ($this, $event) = (self, event);
+                $log;
+
+                None
+            }
+        }
+
+        #[cfg(feature = "priority")]
+        impl<T> crate::sync::PriorityListener<T> for $listener
+        where
+            T: Clone + Debug + Send + Sync + 'static,
+        {
+            fn on_event(&self, event: &T) -> Option<PriorityDispatcherResult> {
+                let ($this, $event) = (self, event);
+                $log;
+
+                None
+            }
+        }
+
+        #[cfg(feature = "async")]
+        #[async_trait::async_trait]
+        impl<T> crate::sync::AsyncListener<T> for $listener
+        where
+            T: Clone + Debug + Send + Sync + 'static,
+        {
+            async fn on_event(&self, event: &T) -> Option<AsyncDispatchResult> {
+                let ($this, $event) = (self, event);
+                $log;
+
+                None
+            }
+        }
+    };
+}
+
+/// Logs every received event via the `log`-crate.
+#[cfg(feature = "log")]
+pub struct LogListener {
+    level: log::Level,
+}
+
+#[cfg(feature = "log")]
+impl LogListener {
+    /// Creates a listener logging events at `level`.
+    #[must_use]
+    pub const fn new(level: log::Level) -> Self {
+        Self { level }
+    }
+}
+
+#[cfg(feature = "log")]
+impl Default for LogListener {
+    fn default() -> Self {
+        Self::new(log::Level::Debug)
+    }
+}
+
+#[cfg(feature = "log")]
+impl_logging_listener!(LogListener: |this, event| match correlation() {
+    Some(correlation) => log::log!(this.level, "{:?} ({})", event, correlation),
+    None => log::log!(this.level, "{:?}", event),
+});
+
+/// Emits a `tracing`-event for every received event.
+#[cfg(feature = "tracing")]
+pub struct TracingListener {
+    level: tracing::Level,
+}
+
+#[cfg(feature = "tracing")]
+impl TracingListener {
+    /// Creates a listener emitting events at `level`.
+    #[must_use]
+    pub const fn new(level: tracing::Level) -> Self {
+        Self { level }
+    }
+
+    /// Emits a `tracing`-event describing `event`.
+    fn trace(&self, event: &impl Debug) {
+        let correlation = correlation().map(tracing::field::display);
+
+        // `tracing` requires levels known at compile-time.
+        match self.level {
+            tracing::Level::ERROR => tracing::error!(event = ?event, correlation = correlation),
+            tracing::Level::WARN => tracing::warn!(event = ?event, correlation = correlation),
+            tracing::Level::INFO => tracing::info!(event = ?event, correlation = correlation),
+            tracing::Level::DEBUG => tracing::debug!(event = ?event, correlation = correlation),
+            tracing::Level::TRACE => tracing::trace!(event = ?event, correlation = correlation),
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Default for TracingListener {
+    fn default() -> Self {
+        Self::new(tracing::Level::DEBUG)
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl_logging_listener!(TracingListener: |this, event| this.trace(event));
@@ -0,0 +1,152 @@
+//! Listeners handling several events via one method per event.
+//!
+//! [`multi_listener!`](crate::multi_listener!) generates a trait with one
+//! method per declared event-pattern, instead of matching every event in a
+//! single `on_event`-method.
+//! Wrapping an implementor in a [`Multi`](crate::multi::Multi) turns it into
+//! a listener routing each event to the method of the first matching
+//! pattern.
+use std::sync::Arc;
+
+/// Routes events to the methods of a trait generated by
+/// [`multi_listener!`](crate::multi_listener!).
+///
+/// Clones share the wrapped listener, hence a single listener can be added
+/// for every event-key it handles.
+pub struct Multi<L> {
+    listener: Arc<L>,
+}
+
+impl<L> Multi<L> {
+    /// Wraps `listener`.
+    #[must_use]
+    pub fn new(listener: L) -> Self {
+        Self {
+            listener: Arc::new(listener),
+        }
+    }
+
+    /// Returns the wrapped listener.
+    #[must_use]
+    pub fn listener(&self) -> &L {
+        &self.listener
+    }
+}
+
+impl<L> Clone for Multi<L> {
+    fn clone(&self) -> Self {
+        Self {
+            listener: Arc::clone(&self.listener),
+        }
+    }
+}
+
+/// Generates a trait with one method per event-pattern, implemented by
+/// listeners handling several events.
+///
+/// The trait is declared alongside the listener-trait and request-type
+/// of the dispatcher it is used with, e.g.
+/// `PriorityListener<Event> as PriorityDispatcherResult`, the
+/// listener-trait needs to be in scope.
+/// Every method is followed by the patterns of events it shall receive and
+/// returns `None` unless implemented.
+///
+/// [`Multi`] implements the listener-trait for every implementor, calling
+/// the method of the first pattern matching an event.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "priority")] {
+/// use hey_listen::{
+///     multi::Multi,
+///     multi_listener,
+///     sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener},
+///     RwLock,
+/// };
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Jump,
+///     Land,
+/// }
+///
+/// multi_listener! {
+///     /// Handles the player's events.
+///     pub trait PlayerListener: PriorityListener<Event> as PriorityDispatcherResult {
+///         /// Called once the player jumps.
+///         fn on_jump => [Event::Jump],
+///         /// Called once the player lands.
+///         fn on_land => [Event::Land],
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct Player {
+///     is_airborne: RwLock<bool>,
+/// }
+///
+/// impl PlayerListener for Player {
+///     fn on_jump(&self, _event: &Event) -> Option<PriorityDispatcherResult> {
+///         *self.is_airborne.write() = true;
+///
+///         None
+///     }
+///
+///     fn on_land(&self, _event: &Event) -> Option<PriorityDispatcherResult> {
+///         *self.is_airborne.write() = false;
+///
+///         None
+///     }
+/// }
+///
+/// let player = Multi::new(Player::default());
+/// let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+///
+/// for event_key in &[Event::Jump, Event::Land] {
+///     dispatcher.add_listener(event_key.clone(), player.clone(), 1);
+/// }
+///
+/// dispatcher.dispatch_event(&Event::Jump);
+/// assert!(*player.listener().is_airborne.read());
+/// # }
+/// ```
+///
+/// [`Multi`]: crate::multi::Multi
+#[macro_export]
+macro_rules! multi_listener {
+    (
+        $(#[$attribute:meta])*
+        $visibility:vis trait $name:ident: $listener:ident<$event:ty> as $request:ty {
+            $(
+                $(#[$method_attribute:meta])*
+                fn $method:ident => [$($pattern:pat)|+]
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attribute])*
+        $visibility trait $name {
+            $(
+                $(#[$method_attribute])*
+                fn $method(&self, _event: &$event) -> Option<$request> {
+                    None
+                }
+            )*
+        }
+
+        impl<L> $listener<$event> for $crate::multi::Multi<L>
+        where
+            L: $name,
+        {
+            fn on_event(&self, event: &$event) -> Option<$request> {
+                $(
+                    if matches!(event, $($pattern)|+) {
+                        return $name::$method(self.listener(), event);
+                    }
+                )*
+
+                None
+            }
+        }
+    };
+}
@@ -0,0 +1,211 @@
+//! Marks whether dispatchers deliver events to listeners in a fixed order.
+//!
+//! Each dispatcher names its [`DeliveryOrder`](crate::order::DeliveryOrder):
+//! [`OrderedDispatch`](crate::order::OrderedDispatch)-dispatchers call
+//! listeners one after another in a fixed order, e.g. by priority-level and
+//! then the order they have been added in.
+//! [`UnorderedDispatch`](crate::order::UnorderedDispatch)-dispatchers call
+//! listeners concurrently, in no particular order.
+//!
+//! Listeners relying on the order are wrapped in an
+//! [`Ordered`](crate::order::Ordered), which only implements the
+//! listener-traits of ordered dispatchers, registering it on an unordered
+//! dispatcher fails to compile.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "priority")] {
+//! use hey_listen::{
+//!     order::Ordered,
+//!     sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener},
+//! };
+//!
+//! #[derive(Clone, Eq, Hash, PartialEq)]
+//! enum Event {
+//!     Frame,
+//! }
+//!
+//! // Expects the physics-step of the same frame to be done.
+//! struct Renderer;
+//!
+//! impl PriorityListener<Event> for Renderer {
+//!     fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> { None }
+//! }
+//!
+//! let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+//!
+//! // A `ParallelDispatcher` would not accept the listener.
+//! dispatcher.add_listener(Event::Frame, Ordered(Renderer), 2);
+//! # }
+//! ```
+use crate::key::KeyExtract;
+#[cfg(feature = "blocking")]
+use crate::rc::{Dispatcher, DispatcherRequest};
+#[cfg(feature = "async")]
+use crate::sync::AsyncDispatcher;
+#[cfg(all(feature = "priority", feature = "arc-swap"))]
+use crate::sync::CowDispatcher;
+#[cfg(feature = "parallel")]
+use crate::sync::ParallelDispatcher;
+#[cfg(feature = "priority")]
+use crate::sync::{FrozenDispatcher, PriorityDispatcher, PriorityDispatcherResult};
+
+/// Marks dispatchers calling listeners one after another in a fixed order.
+pub struct OrderedDispatch;
+
+/// Marks dispatchers calling listeners concurrently, in no particular
+/// order.
+pub struct UnorderedDispatch;
+
+/// Names whether a dispatcher delivers events in order.
+pub trait DeliveryOrder {
+    /// Either [`OrderedDispatch`] or [`UnorderedDispatch`].
+    type Order;
+}
+
+/// Implements [`DeliveryOrder`] for a dispatcher.
+macro_rules! impl_delivery_order {
+    ($dispatcher:ty, [$($param:ident),*], [$($bounds:tt)*], $order:ty) => {
+        impl<$($param),*> DeliveryOrder for $dispatcher
+        where
+            $($bounds)*
+        {
+            type Order = $order;
+        }
+    };
+}
+
+#[cfg(feature = "blocking")]
+impl_delivery_order!(
+    Dispatcher<T, X>,
+    [T, X],
+    [T: Clone + 'static, X: KeyExtract<T>],
+    OrderedDispatch
+);
+
+// Listeners of a tier dispatched in parallel via
+// [`PriorityDispatcher::parallel_tiers`] are called in no particular
+// order, unless one of them is [`Ordered`].
+#[cfg(feature = "priority")]
+impl_delivery_order!(
+    PriorityDispatcher<P, T, X>,
+    [P, T, X],
+    [P: Ord + Clone, T: Clone + Send + Sync + 'static, X: KeyExtract<T>],
+    OrderedDispatch
+);
+
+#[cfg(feature = "priority")]
+impl_delivery_order!(
+    FrozenDispatcher<P, T, X>,
+    [P, T, X],
+    [P: Ord, T: Clone + Send + Sync + 'static, X: KeyExtract<T>],
+    OrderedDispatch
+);
+
+#[cfg(all(feature = "priority", feature = "arc-swap"))]
+impl_delivery_order!(
+    CowDispatcher<P, T, X>,
+    [P, T, X],
+    [P: Ord + Clone, T: Clone + Send + Sync + 'static, X: KeyExtract<T>],
+    OrderedDispatch
+);
+
+#[cfg(feature = "parallel")]
+impl_delivery_order!(
+    ParallelDispatcher<T, X>,
+    [T, X],
+    [T: Clone + Send + Sync + 'static, X: KeyExtract<T>],
+    UnorderedDispatch
+);
+
+#[cfg(feature = "async")]
+impl_delivery_order!(
+    AsyncDispatcher<T, X>,
+    [T, X],
+    [T: Clone + Send + Sync + 'static, X: KeyExtract<T>],
+    UnorderedDispatch
+);
+
+/// Wraps a listener relying on listeners being called in order.
+///
+/// Only implements the listener-traits of [`OrderedDispatch`]-dispatchers.
+/// Within a parallel tier of a `PriorityDispatcher`, an `Ordered` listener
+/// makes the tier be dispatched to one listener after another.
+///
+/// # Examples
+///
+/// Unordered dispatchers reject ordered listeners:
+///
+/// ```compile_fail
+/// use hey_listen::{
+///     order::Ordered,
+///     sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener},
+/// };
+///
+/// struct Renderer;
+///
+/// impl ParallelListener<u32> for Renderer {
+///     fn on_event(&self, _event: &u32) -> Option<ParallelDispatchResult> { None }
+/// }
+///
+/// let mut dispatcher: ParallelDispatcher<u32> = ParallelDispatcher::new(2)
+///     .expect("Failed to build threadpool");
+///
+/// dispatcher.add_listener(0, Ordered(Renderer));
+/// ```
+pub struct Ordered<L>(pub L);
+
+#[cfg(feature = "blocking")]
+impl<T, L> crate::rc::Listener<T> for Ordered<L>
+where
+    T: Clone + 'static,
+    L: crate::rc::Listener<T>,
+{
+    fn on_event(&self, event: &T) -> Option<DispatcherRequest> {
+        self.0.on_event(event)
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.0.is_alive()
+    }
+
+    fn listener_id(&self) -> Option<crate::handle::ListenerId> {
+        self.0.listener_id()
+    }
+}
+
+#[cfg(feature = "priority")]
+impl<T, L> crate::sync::PriorityListener<T> for Ordered<L>
+where
+    T: Clone + Send + Sync + 'static,
+    L: crate::sync::PriorityListener<T>,
+{
+    fn on_event(&self, event: &T) -> Option<PriorityDispatcherResult> {
+        self.0.on_event(event)
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn requires_order(&self) -> bool {
+        true
+    }
+
+    fn owner(&self) -> Option<crate::sync::Owner> {
+        self.0.owner()
+    }
+
+    fn listener_id(&self) -> Option<crate::handle::ListenerId> {
+        self.0.listener_id()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.0.is_alive()
+    }
+}
@@ -0,0 +1,73 @@
+//! Decides how sequential dispatchers handle panicking listeners.
+//!
+//! By default, a listener's panic unwinds through the dispatcher into the
+//! dispatching code.
+//! Hosts of untrusted listeners, e.g. plugins, can instead let the
+//! dispatcher catch panics via a [`PanicPolicy`](crate::panic_policy::PanicPolicy).
+//!
+//! **Note**: Panics can only be caught if they unwind, listeners compiled
+//! with `panic = "abort"` still abort the process.
+use std::{
+    any::Any,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::Arc,
+};
+
+/// Called with a listener's name and the payload of its caught panic.
+pub type PanicHook = Arc<dyn Fn(&str, &(dyn Any + Send)) + Send + Sync + 'static>;
+
+/// What a dispatcher does when one of its listeners panics.
+pub enum PanicPolicy {
+    /// Lets the panic unwind into the dispatching code.
+    Propagate,
+    /// Catches the panic and removes the panicking listener.
+    RemoveListener,
+    /// Catches the panic, calls the hook, and keeps the listener.
+    Hook(PanicHook),
+}
+
+impl PanicPolicy {
+    /// Creates a [`PanicPolicy::Hook`] calling `hook`.
+    pub fn hook<F>(hook: F) -> Self
+    where
+        F: Fn(&str, &(dyn Any + Send)) + Send + Sync + 'static,
+    {
+        Self::Hook(Arc::new(hook))
+    }
+
+    /// Calls `on_event` and handles its panic according to the policy.
+    ///
+    /// A removed listener results in `stop_listening`.
+    pub(crate) fn guard<R, F>(
+        &self,
+        listener_name: &str,
+        stop_listening: R,
+        on_event: F,
+    ) -> Option<R>
+    where
+        F: FnOnce() -> Option<R>,
+    {
+        if matches!(self, Self::Propagate) {
+            return on_event();
+        }
+
+        let payload = match catch_unwind(AssertUnwindSafe(on_event)) {
+            Ok(request) => return request,
+            Err(payload) => payload,
+        };
+
+        if let Self::Hook(hook) = self {
+            hook(listener_name, payload.as_ref());
+
+            None
+        } else {
+            Some(stop_listening)
+        }
+    }
+}
+
+impl Default for PanicPolicy {
+    fn default() -> Self {
+        Self::Propagate
+    }
+}
@@ -1,29 +1,242 @@
-use super::{execute_dispatcher_requests, Listener};
-use std::{collections::HashMap, hash::Hash};
+use super::{
+    super::{
+        cursor::{Budget, DispatchCursor, Resumable},
+        fns::FnListener,
+        handle::ListenerId,
+        hooks::{self, ListenerHooks, ListenerInfo},
+        key::{ByDiscriminant, Identity, KeyExtract, KeyId},
+        panic_policy::PanicPolicy,
+        scope::{Scoped, SubscriptionGuard},
+        snapshot::{ListenerEntry, Snapshot},
+        type_map::TypeMap,
+        typed::{EventResult, TypedListener},
+    },
+    execute_dispatcher_requests, DispatcherRequest, ExecuteRequestsResult, Listener,
+};
+use std::{
+    borrow::Borrow,
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    iter,
+    rc::{Rc, Weak},
+};
 
 /// In charge of parallel dispatching to all listeners.
-pub struct Dispatcher<T>
+///
+/// `X` is the [`KeyExtract`]-strategy deriving the key listeners are
+/// registered by from events, by default the event itself.
+pub struct Dispatcher<T, X = Identity>
 where
-    T: PartialEq + Eq + Hash + Clone + 'static,
+    T: Clone + 'static,
+    X: KeyExtract<T>,
 {
-    events: HashMap<T, Vec<Box<dyn Listener<T> + 'static>>>,
+    events: HashMap<X::Key, Vec<Box<dyn Listener<T> + 'static>>>,
+    cold_events: HashMap<X::Key, Vec<Box<dyn Listener<T> + 'static>>>,
+    woken_keys: HashSet<X::Key>,
+    parent: Option<Weak<RefCell<Self>>>,
+    panic_policy: PanicPolicy,
+    listener_hooks: Option<ListenerHooks<T, DispatcherRequest>>,
+    /// Stores the `TypedListener<T>`s of each event-key, `T` implementing
+    /// [`EventResult`].
+    typed_listeners: TypeMap<X::Key>,
 }
 
-impl<T> Dispatcher<T>
+/// A [`Dispatcher`] registering listeners by the variant of `Enum`-events.
+///
+/// Events carrying fields work without implementing [`Hash`]- and
+/// [`PartialEq`]-traits ignoring those fields, [`Clone`] suffices.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::rc::{DiscriminantDispatcher, DispatcherRequest, Listener};
+/// use std::{cell::Cell, rc::Rc};
+///
+/// #[derive(Clone)]
+/// enum Event {
+///     Damage(u32),
+///     Heal(u32),
+/// }
+///
+/// struct Health {
+///     damage_taken: Rc<Cell<u32>>,
+/// }
+///
+/// impl Listener<Event> for Health {
+///     fn on_event(&self, event: &Event) -> Option<DispatcherRequest> {
+///         if let Event::Damage(amount) = event {
+///             self.damage_taken.set(self.damage_taken.get() + amount);
+///         }
+///
+///         None
+///     }
+/// }
+///
+/// let damage_taken = Rc::new(Cell::new(0));
+/// let mut dispatcher: DiscriminantDispatcher<Event> = DiscriminantDispatcher::new();
+///
+/// dispatcher.add_listener(
+///     Event::Damage(0),
+///     Health {
+///         damage_taken: Rc::clone(&damage_taken),
+///     },
+/// );
+///
+/// dispatcher.dispatch_event(&Event::Damage(3));
+/// dispatcher.dispatch_event(&Event::Heal(1));
+/// dispatcher.dispatch_event(&Event::Damage(4));
+///
+/// assert_eq!(damage_taken.get(), 7);
+/// ```
+///
+/// [`Hash`]: std::hash::Hash
+pub type DiscriminantDispatcher<T> = Dispatcher<T, ByDiscriminant>;
+
+impl<T, X> Dispatcher<T, X>
 where
-    T: PartialEq + Eq + Hash + Clone + Sized + 'static,
+    T: Clone + Sized + 'static,
+    X: KeyExtract<T>,
 {
     /// Create a new blocking dispatcher.
     #[must_use]
     pub fn new() -> Self {
         Self {
             events: HashMap::new(),
+            cold_events: HashMap::new(),
+            woken_keys: HashSet::new(),
+            parent: None,
+            panic_policy: PanicPolicy::default(),
+            listener_hooks: None,
+            typed_listeners: TypeMap::new(),
         }
     }
 
+    /// Sets how panics of listeners are handled, they are propagated by
+    /// default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{
+    ///     panic_policy::PanicPolicy,
+    ///     rc::{Dispatcher, DispatcherRequest, Listener},
+    /// };
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct Plugin;
+    ///
+    /// impl Listener<Event> for Plugin {
+    ///     fn on_event(&self, _event: &Event) -> Option<DispatcherRequest> {
+    ///         panic!("Misbehaving plugin");
+    ///     }
+    /// }
+    ///
+    /// let mut dispatcher: Dispatcher<Event> =
+    ///     Dispatcher::new().panic_policy(PanicPolicy::RemoveListener);
+    ///
+    /// dispatcher.add_listener(Event::EventType, Plugin);
+    ///
+    /// // The plugin panics and is removed, the dispatcher survives.
+    /// dispatcher.dispatch_event(&Event::EventType);
+    /// assert!(dispatcher.snapshot().listeners.is_empty());
+    /// ```
+    #[must_use]
+    pub fn panic_policy(mut self, panic_policy: PanicPolicy) -> Self {
+        self.panic_policy = panic_policy;
+
+        self
+    }
+
+    /// Sets hooks called right before and after each listener receives an
+    /// event, `after` receives the listener's request.
+    ///
+    /// Replaces previously set hooks, see the [`hooks`] module.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// let calls = Arc::new(Mutex::new(Vec::new()));
+    /// let mut dispatcher: Dispatcher<Event> = Dispatcher::new();
+    ///
+    /// let before = Arc::clone(&calls);
+    /// let after = Arc::clone(&calls);
+    /// dispatcher.set_listener_hooks(
+    ///     move |_event, listener| before.lock().unwrap().push(format!("before {}", listener.name)),
+    ///     move |_event, listener, request| {
+    ///         after.lock().unwrap().push(format!("after {} {:?}", listener.name, request));
+    ///     },
+    /// );
+    /// dispatcher.add_fn(Event::EventType, |_event| None);
+    ///
+    /// dispatcher.dispatch_event(&Event::EventType);
+    ///
+    /// assert_eq!(calls.lock().unwrap().len(), 2);
+    /// ```
+    pub fn set_listener_hooks<B, A>(&mut self, before: B, after: A)
+    where
+        B: Fn(&T, ListenerInfo<'_>) + Send + Sync + 'static,
+        A: Fn(&T, ListenerInfo<'_>, &Option<DispatcherRequest>) + Send + Sync + 'static,
+    {
+        self.listener_hooks = Some(ListenerHooks::new(before, after));
+    }
+
+    /// Sets `parent` as parent-dispatcher.
+    ///
+    /// Every event whose propagation has not been stopped by this
+    /// dispatcher's listeners will be dispatched by `parent` afterwards,
+    /// even if this dispatcher has no listener for the event.
+    ///
+    /// Only a weak reference to `parent` is kept, once `parent` is dropped,
+    /// events will no longer be forwarded.
+    ///
+    /// **Note**: Dispatching on the child from within one of `parent`'s
+    /// listeners panics, as `parent` is already borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    /// use std::{cell::RefCell, rc::Rc};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// let world = Rc::new(RefCell::new(Dispatcher::<Event>::new()));
+    /// let mut scene = Dispatcher::new();
+    ///
+    /// scene.set_parent(&world);
+    ///
+    /// // Dispatched to the scene's listeners and then to the world's.
+    /// scene.dispatch_event(&Event::EventType);
+    /// ```
+    pub fn set_parent(&mut self, parent: &Rc<RefCell<Self>>) {
+        self.parent = Some(Rc::downgrade(parent));
+    }
+
     /// Adds a [`Listener`] to listen for an `event_key`.
     ///
-    /// **Note**: If your `Enum` owns fields you need to consider implementing
+    /// Listeners are dispatched to in the order they have been added, even
+    /// after others of them stopped listening.
+    ///
+    /// Returns the [`ListenerId`] removing the listener via
+    /// [`remove_listener`](Self::remove_listener).
+    ///
+    /// **Note**: If your `Enum` owns fields you need to consider a
+    /// [`KeyExtract`]-strategy or implementing
     /// the [`Hash`]- and [`PartialEq`]-trait if you want to ignore fields,
     /// see second example for an implementation-suggestion.
     ///
@@ -81,13 +294,488 @@ where
     /// [`Listener`]: trait.Listener.html
     /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
     /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
-    pub fn add_listener<D: Listener<T> + Sized + 'static>(&mut self, event_key: T, listener: D) {
-        let listener = Box::new(listener);
+    pub fn add_listener<D: Listener<T> + Sized + 'static>(
+        &mut self,
+        event_key: T,
+        listener: D,
+    ) -> ListenerId {
+        Self::register(
+            self.events.entry(X::extract(&event_key)).or_default(),
+            listener,
+        )
+    }
+
+    /// Adds the closure `listener` to listen for an `event_key`, like
+    /// [`add_listener`](Self::add_listener) does for [`Listener`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// let mut dispatcher: Dispatcher<Event> = Dispatcher::new();
+    ///
+    /// dispatcher.add_fn(Event::EventType, |_event| None);
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    pub fn add_fn<F>(&mut self, event_key: T, listener: F) -> ListenerId
+    where
+        F: Fn(&T) -> Option<DispatcherRequest> + 'static,
+    {
+        self.add_listener(event_key, FnListener(listener))
+    }
+
+    /// Adds a [`Listener`] to listen for an `event_key` until the returned
+    /// [`SubscriptionGuard`] is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::{Dispatcher, DispatcherRequest, Listener};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Click,
+    /// }
+    ///
+    /// struct Button;
+    ///
+    /// impl Listener<Event> for Button {
+    ///     fn on_event(&self, _event: &Event) -> Option<DispatcherRequest> { None }
+    /// }
+    ///
+    /// let mut dispatcher: Dispatcher<Event> = Dispatcher::new();
+    ///
+    /// let guard = dispatcher.subscribe_scoped(Event::Click, Button);
+    /// drop(guard);
+    ///
+    /// assert_eq!(dispatcher.purge_dead(), 1);
+    /// ```
+    pub fn subscribe_scoped<D: Listener<T> + Sized + 'static>(
+        &mut self,
+        event_key: T,
+        listener: D,
+    ) -> SubscriptionGuard {
+        let (listener, guard) = Scoped::new(listener);
+
+        self.add_listener(event_key, listener);
+
+        guard
+    }
+
+    /// Adds the closure `listener` returning an output of the event's
+    /// [`EventResult`] for an `event_key`, outputs are combined via
+    /// [`dispatch_typed`](Self::dispatch_typed).
+    ///
+    /// Typed listeners are kept apart from [`Listener`]s, other dispatches
+    /// do not reach them. They are counted and removed like any other
+    /// listener, but neither [`take_listeners`](Self::take_listeners) nor
+    /// [`drain`](Self::drain) returns them.
+    // Taking `event_key` by value mirrors `add_fn`.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn add_typed_fn<F>(&mut self, event_key: T, listener: F)
+    where
+        T: EventResult,
+        F: Fn(&T) -> T::Output + Send + Sync + 'static,
+    {
+        self.typed_listeners
+            .push::<TypedListener<T>>(X::extract(&event_key), Box::new(listener));
+    }
+
+    /// Adds a cold [`Listener`] to listen for an `event_key`, like
+    /// [`add_listener`](Self::add_listener) does for warm listeners.
+    ///
+    /// Dispatches skip the cold listeners of an event-key without any cost,
+    /// unless they have been woken via
+    /// [`wake_cold_listeners`](Self::wake_cold_listeners), letting large
+    /// sets of dormant listeners, e.g. one per entity, exist for free.
+    /// Woken cold listeners are dispatched to after the warm listeners of
+    /// their event-key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::{Dispatcher, DispatcherRequest, Listener};
+    /// use std::{cell::Cell, rc::Rc};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Explosion,
+    /// }
+    ///
+    /// struct Entity {
+    ///     hits: Rc<Cell<u32>>,
+    /// }
+    ///
+    /// impl Listener<Event> for Entity {
+    ///     fn on_event(&self, _event: &Event) -> Option<DispatcherRequest> {
+    ///         self.hits.set(self.hits.get() + 1);
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// let hits = Rc::new(Cell::new(0));
+    /// let mut dispatcher: Dispatcher<Event> = Dispatcher::new();
+    ///
+    /// for _entity in 0..1000 {
+    ///     let hits = Rc::clone(&hits);
+    ///
+    ///     dispatcher.add_cold_listener(Event::Explosion, Entity { hits });
+    /// }
+    ///
+    /// dispatcher.dispatch_event(&Event::Explosion);
+    /// assert_eq!(hits.get(), 0);
+    ///
+    /// dispatcher.wake_cold_listeners(Event::Explosion);
+    /// dispatcher.dispatch_event(&Event::Explosion);
+    /// assert_eq!(hits.get(), 1000);
+    /// ```
+    pub fn add_cold_listener<D: Listener<T> + Sized + 'static>(
+        &mut self,
+        event_key: T,
+        listener: D,
+    ) -> ListenerId {
+        Self::register(
+            self.cold_events.entry(X::extract(&event_key)).or_default(),
+            listener,
+        )
+    }
+
+    /// Wakes the cold listeners of `event_key`, dispatches reach them until
+    /// they are put back to sleep via
+    /// [`sleep_cold_listeners`](Self::sleep_cold_listeners).
+    pub fn wake_cold_listeners(&mut self, event_key: T) {
+        self.woken_keys.insert(X::extract(&event_key));
+    }
+
+    /// Puts the cold listeners of `event_key` woken via
+    /// [`wake_cold_listeners`](Self::wake_cold_listeners) back to sleep,
+    /// returns whether they have been awake.
+    pub fn sleep_cold_listeners(&mut self, event_key: &T) -> bool {
+        self.woken_keys.remove(&X::extract(event_key))
+    }
+
+    /// Returns whether dispatches of `event_key` reach its cold listeners,
+    /// as they have been woken via
+    /// [`wake_cold_listeners`](Self::wake_cold_listeners).
+    #[must_use]
+    pub fn are_cold_listeners_awake(&self, event_key: &T) -> bool {
+        self.woken_keys.contains(&X::extract(event_key))
+    }
+
+    /// Purges the dead listeners of `listeners` and appends `listener`,
+    /// returning the id it has been registered under.
+    fn register<D: Listener<T> + Sized + 'static>(
+        listeners: &mut Vec<Box<dyn Listener<T> + 'static>>,
+        listener: D,
+    ) -> ListenerId {
+        let id = ListenerId::next();
+        let listener = Box::new(Registered { id, listener });
+
+        listeners.retain(Listener::is_alive);
+        listeners.push(listener as Box<dyn Listener<T> + 'static>);
+
+        id
+    }
+
+    /// Returns how many listeners listen for `event_key`, warm and cold
+    /// ones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    ///     Render,
+    /// }
+    ///
+    /// let mut dispatcher: Dispatcher<Event> = Dispatcher::new();
+    ///
+    /// assert!(dispatcher.is_empty());
+    ///
+    /// dispatcher.add_fn(Event::Tick, |_event| None);
+    /// dispatcher.add_fn(Event::Tick, |_event| None);
+    ///
+    /// assert_eq!(dispatcher.listener_count(&Event::Tick), 2);
+    /// assert_eq!(dispatcher.listener_count(&Event::Render), 0);
+    /// assert_eq!(dispatcher.total_listeners(), 2);
+    /// assert_eq!(dispatcher.event_keys(), vec![Event::Tick].into_iter().collect());
+    /// ```
+    #[must_use]
+    pub fn listener_count(&self, event_key: &T) -> usize {
+        let event_key = X::extract(event_key);
+        let listener_count = [&self.events, &self.cold_events]
+            .iter()
+            .filter_map(|events| events.get(&event_key))
+            .flatten()
+            .filter(|listener| listener.is_alive())
+            .count();
+
+        listener_count + self.typed_listeners.len(&event_key)
+    }
+
+    /// Returns how many listeners listen for any event-key, including typed
+    /// listeners.
+    #[must_use]
+    pub fn total_listeners(&self) -> usize {
+        let listener_count = self
+            .events
+            .values()
+            .chain(self.cold_events.values())
+            .flatten()
+            .filter(|listener| listener.is_alive())
+            .count();
+
+        listener_count + self.typed_listeners.total_len()
+    }
+
+    /// Returns the event-keys at least one listener listens for, including
+    /// typed listeners.
+    #[must_use]
+    pub fn event_keys(&self) -> HashSet<X::Key> {
+        let mut event_keys: HashSet<_> = self
+            .events
+            .iter()
+            .chain(&self.cold_events)
+            .filter(|(_, listeners)| listeners.iter().any(Listener::is_alive))
+            .map(|(event_key, _)| event_key.clone())
+            .collect();
+
+        event_keys.extend(
+            self.typed_listeners
+                .iter()
+                .filter(|(_, _, count)| *count > 0)
+                .map(|(event_key, _, _)| event_key.clone()),
+        );
+
+        event_keys
+    }
+
+    /// Returns whether no listener listens for any event-key, including
+    /// typed listeners.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.total_listeners() == 0
+    }
+
+    /// Takes a [`Snapshot`] of all registered listeners.
+    ///
+    /// [`Snapshot`]: crate::snapshot::Snapshot
+    #[must_use]
+    pub fn snapshot(&self) -> Snapshot<X::Key> {
+        let listeners = self
+            .events
+            .iter()
+            .chain(&self.cold_events)
+            .flat_map(|(event_key, listeners)| {
+                listeners
+                    .iter()
+                    .filter(|listener| listener.is_alive())
+                    .map(move |listener| ListenerEntry {
+                        event_key: event_key.clone(),
+                        priority: (),
+                        name: listener.name().to_string(),
+                    })
+            })
+            .chain(
+                self.typed_listeners
+                    .iter()
+                    .flat_map(|(event_key, name, count)| {
+                        iter::repeat_with(move || ListenerEntry {
+                            event_key: event_key.clone(),
+                            priority: (),
+                            name: name.to_string(),
+                        })
+                        .take(count)
+                    }),
+            )
+            .collect();
+
+        Snapshot { listeners }
+    }
+
+    /// Removes all listeners of `event_key` and returns them, its cold
+    /// listeners after its warm ones.
+    ///
+    /// Boxed listeners implement [`Listener`] themselves, hence can be
+    /// added to another dispatcher. Typed listeners are removed as well, but
+    /// not returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::{Dispatcher, DispatcherRequest, Listener};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// struct Physics;
+    ///
+    /// impl Listener<Event> for Physics {
+    ///     fn on_event(&self, _event: &Event) -> Option<DispatcherRequest> { None }
+    /// }
+    ///
+    /// let mut old_world: Dispatcher<Event> = Dispatcher::new();
+    /// let mut new_world: Dispatcher<Event> = Dispatcher::new();
+    ///
+    /// old_world.add_listener(Event::Tick, Physics);
+    ///
+    /// for listener in old_world.take_listeners(&Event::Tick) {
+    ///     new_world.add_listener(Event::Tick, listener);
+    /// }
+    ///
+    /// assert!(old_world.snapshot().listeners.is_empty());
+    /// assert_eq!(new_world.snapshot().listeners.len(), 1);
+    /// ```
+    pub fn take_listeners(&mut self, event_key: &T) -> Vec<Box<dyn Listener<T> + 'static>> {
+        let event_key = X::extract(event_key);
+        let mut listeners = self.events.remove(&event_key).unwrap_or_default();
+
+        self.typed_listeners.remove(&event_key);
+
+        listeners.extend(self.cold_events.remove(&event_key).unwrap_or_default());
+
+        listeners
+    }
 
+    /// Removes all listeners and returns them alongside the key they have
+    /// been registered by, typed listeners are removed but not returned.
+    ///
+    /// Another dispatcher adds the listeners via its [`Extend`]-implementation.
+    pub fn drain(&mut self) -> Vec<(X::Key, Box<dyn Listener<T> + 'static>)> {
+        self.typed_listeners.clear();
         self.events
-            .entry(event_key)
-            .or_insert_with(Vec::new)
-            .push(listener as Box<dyn Listener<T> + 'static>);
+            .drain()
+            .chain(self.cold_events.drain())
+            .flat_map(|(event_key, listeners)| {
+                listeners
+                    .into_iter()
+                    .map(move |listener| (event_key.clone(), listener))
+            })
+            .collect()
+    }
+
+    /// Removes all listeners of `event_key`, warm, cold and typed ones, and
+    /// returns how many live listeners have been removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    ///     Render,
+    /// }
+    ///
+    /// let mut dispatcher: Dispatcher<Event> = Dispatcher::new();
+    ///
+    /// dispatcher.add_fn(Event::Tick, |_event| None);
+    /// dispatcher.add_fn(Event::Tick, |_event| None);
+    /// dispatcher.add_fn(Event::Render, |_event| None);
+    ///
+    /// assert_eq!(dispatcher.remove_all_listeners(&Event::Tick), 2);
+    /// assert_eq!(dispatcher.snapshot().listeners.len(), 1);
+    ///
+    /// dispatcher.clear();
+    /// assert!(dispatcher.snapshot().listeners.is_empty());
+    /// ```
+    pub fn remove_all_listeners(&mut self, event_key: &T) -> usize {
+        let event_key = X::extract(event_key);
+
+        let listener_count = [&mut self.events, &mut self.cold_events]
+            .iter_mut()
+            .filter_map(|events| events.remove(&event_key))
+            .flatten()
+            .filter(Listener::is_alive)
+            .count();
+
+        listener_count + self.typed_listeners.remove(&event_key)
+    }
+
+    /// Removes all listeners of all event-keys, including typed listeners,
+    /// keeping the dispatcher's settings, e.g. its parent-dispatcher and
+    /// woken event-keys.
+    pub fn clear(&mut self) {
+        self.events.clear();
+        self.cold_events.clear();
+        self.typed_listeners.clear();
+    }
+
+    /// Removes the listener added under `id` and returns whether it has
+    /// been found.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// let mut dispatcher: Dispatcher<Event> = Dispatcher::new();
+    ///
+    /// let id = dispatcher.add_fn(Event::Tick, |_event| None);
+    /// dispatcher.add_fn(Event::Tick, |_event| None);
+    ///
+    /// assert!(dispatcher.remove_listener(id));
+    /// assert!(!dispatcher.remove_listener(id));
+    /// assert_eq!(dispatcher.snapshot().listeners.len(), 1);
+    /// ```
+    pub fn remove_listener(&mut self, id: ListenerId) -> bool {
+        let mut removed = false;
+
+        for listeners in self
+            .events
+            .values_mut()
+            .chain(self.cold_events.values_mut())
+        {
+            let listener_count = listeners.len();
+
+            listeners.retain(|listener| listener.listener_id() != Some(id));
+            removed |= listener_count != listeners.len();
+        }
+
+        removed
+    }
+
+    /// Removes all listeners that are no longer alive, e.g. listeners
+    /// created via [`weakly`](crate::fns::weakly) whose shared state has
+    /// been dropped, and returns how many have been removed.
+    ///
+    /// Adding a listener purges the dead listeners of its event-key,
+    /// calling this periodically bounds the memory of event-keys that
+    /// rarely see new listeners or events.
+    pub fn purge_dead(&mut self) -> usize {
+        let mut removed = 0;
+
+        for listeners in self
+            .events
+            .values_mut()
+            .chain(self.cold_events.values_mut())
+        {
+            let listener_count = listeners.len();
+
+            listeners.retain(Listener::is_alive);
+            removed += listener_count - listeners.len();
+        }
+
+        removed
     }
 
     /// All [`Listener`]s listening to a passed `event_identifier`
@@ -101,10 +789,402 @@ where
     /// [`DispatcherRequest`]: enum.DispatcherRequest.html
     /// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
     pub fn dispatch_event(&mut self, event_identifier: &T) {
-        if let Some(mut listener_collection) = self.events.get_mut(event_identifier) {
-            execute_dispatcher_requests(&mut listener_collection, |listener| {
-                listener.on_event(event_identifier)
+        self.dispatch(&X::extract(event_identifier), || event_identifier);
+    }
+
+    /// All [`Listener`]s listening to `event_key` will receive the event
+    /// constructed by `build_event`.
+    ///
+    /// `build_event` is only called if at least one [`Listener`] listens to
+    /// `event_key`, expensive events will not be built for nobody.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::{Dispatcher, DispatcherRequest, Listener};
+    /// use std::hash::{Hash, Hasher};
+    /// use std::mem::discriminant;
+    ///
+    /// #[derive(Clone)]
+    /// enum Event {
+    ///     Loaded(Vec<u8>),
+    /// }
+    ///
+    /// impl Hash for Event {
+    ///     fn hash<H: Hasher>(&self, _state: &mut H) {}
+    /// }
+    ///
+    /// impl PartialEq for Event {
+    ///     fn eq(&self, other: &Event) -> bool {
+    ///         discriminant(self) == discriminant(other)
+    ///     }
+    /// }
+    ///
+    /// impl Eq for Event {}
+    ///
+    /// let mut dispatcher: Dispatcher<Event> = Dispatcher::new();
+    ///
+    /// // Nobody listens, the closure will not be called.
+    /// dispatcher.dispatch_with(&Event::Loaded(Vec::new()), || unreachable!());
+    /// ```
+    ///
+    /// [`Listener`]: Listener
+    pub fn dispatch_with<F>(&mut self, event_key: &T, build_event: F)
+    where
+        F: FnOnce() -> T,
+    {
+        self.dispatch(&X::extract(event_key), build_event);
+    }
+
+    /// Dispatches `event` to the listeners registered by `id`, without
+    /// deriving the key from `event`, e.g. for callers caching the id of an
+    /// interned name.
+    pub fn dispatch_by_id(&mut self, id: KeyId, event: &T)
+    where
+        X: KeyExtract<T, Key = KeyId>,
+    {
+        self.dispatch(&id, || event);
+    }
+
+    /// Dispatches `event` to the typed listeners of its key and returns
+    /// their outputs combined via [`EventResult::combine`], in the order the
+    /// listeners have been added in.
+    ///
+    /// Returns the [`Default`] output if no typed listener listens for the
+    /// key. Other listeners and parent-dispatchers are not involved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{rc::Dispatcher, typed::EventResult};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Heal,
+    /// }
+    ///
+    /// impl EventResult for Event {
+    ///     type Output = bool;
+    ///
+    ///     fn combine(is_vetoed: &mut bool, veto: bool) {
+    ///         *is_vetoed |= veto;
+    ///     }
+    /// }
+    ///
+    /// let mut dispatcher: Dispatcher<Event> = Dispatcher::new();
+    ///
+    /// dispatcher.add_typed_fn(Event::Heal, |_event| false);
+    /// dispatcher.add_typed_fn(Event::Heal, |_event| true);
+    ///
+    /// assert!(dispatcher.dispatch_typed(&Event::Heal));
+    /// ```
+    pub fn dispatch_typed(&self, event: &T) -> T::Output
+    where
+        T: EventResult,
+    {
+        let mut total = T::Output::default();
+
+        for listener in self
+            .typed_listeners
+            .get::<TypedListener<T>>(X::extract(event))
+        {
+            T::combine(&mut total, listener(event));
+        }
+
+        total
+    }
+
+    /// Dispatches `event` to as many listeners as fit in `budget`, like
+    /// [`dispatch_event`](Self::dispatch_event) does to all of them.
+    ///
+    /// Returns a [`DispatchCursor`] if listeners are left, passing it to
+    /// [`resume_dispatch`](Self::resume_dispatch) continues with them,
+    /// e.g. during the next frame.
+    /// At least one listener is dispatched to per call, even if it exceeds
+    /// `budget`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// let mut dispatcher: Dispatcher<Event> = Dispatcher::new();
+    ///
+    /// dispatcher.add_fn(Event::Tick, |_event| None);
+    /// dispatcher.add_fn(Event::Tick, |_event| None);
+    ///
+    /// // A budget of zero dispatches to one listener per call.
+    /// let cursor = dispatcher
+    ///     .dispatch_event_budgeted(&Event::Tick, Duration::from_secs(0))
+    ///     .expect("One listener is left");
+    ///
+    /// assert!(dispatcher.resume_dispatch(cursor, Duration::from_secs(0)).is_none());
+    /// ```
+    pub fn dispatch_event_budgeted(
+        &mut self,
+        event: &T,
+        budget: impl Into<Budget>,
+    ) -> Option<DispatchCursor<T>> {
+        self.resume_dispatch(DispatchCursor::new(event.clone()), budget)
+    }
+
+    /// Continues the dispatch of `cursor` started via
+    /// [`dispatch_event_budgeted`](Self::dispatch_event_budgeted) with as
+    /// many of the remaining listeners as fit in `budget`.
+    ///
+    /// Returns the cursor if listeners are still left, once all have been
+    /// dispatched to the event is forwarded to the parent-dispatcher.
+    pub fn resume_dispatch(
+        &mut self,
+        mut cursor: DispatchCursor<T>,
+        budget: impl Into<Budget>,
+    ) -> Option<DispatchCursor<T>> {
+        let mut budget = budget.into().start();
+        let event_key = X::extract(&cursor.event);
+        let panic_policy = &self.panic_policy;
+        let listener_hooks = self.listener_hooks.as_ref();
+        let is_woken = self.woken_keys.contains(&event_key);
+
+        for tier in [Tier::Warm, Tier::Cold] {
+            match (Tier::of(&cursor), tier) {
+                (Tier::Cold, Tier::Warm) => continue,
+                (Tier::Warm, Tier::Cold) => tier.enter(&mut cursor),
+                _ => {}
+            }
+
+            let events = match tier {
+                Tier::Warm => &mut self.events,
+                Tier::Cold if is_woken => &mut self.cold_events,
+                Tier::Cold => break,
+            };
+            let listener_collection = match events.get_mut(&event_key) {
+                Some(listener_collection) => listener_collection,
+                None => continue,
+            };
+            let mut index = cursor.last_listener.map_or(0, |last_listener| {
+                listener_collection
+                    .partition_point(|listener| listener.listener_id() <= Some(last_listener))
             });
+
+            while let Some(listener) = listener_collection.get(index) {
+                if !budget.spend() {
+                    return Some(cursor);
+                }
+
+                cursor.last_listener = listener.listener_id();
+
+                let event = &cursor.event;
+                let request = hooks::surround(listener_hooks, event, listener.name(), || {
+                    panic_policy.guard(listener.name(), DispatcherRequest::StopListening, || {
+                        listener.on_event(event)
+                    })
+                });
+
+                match request {
+                    None => index += 1,
+                    Some(DispatcherRequest::StopListening) => {
+                        listener_collection.remove(index);
+                    }
+                    Some(DispatcherRequest::StopPropagation) => return None,
+                    Some(DispatcherRequest::StopListeningAndPropagation) => {
+                        listener_collection.remove(index);
+
+                        return None;
+                    }
+                }
+            }
+        }
+
+        if let Some(parent) = self.parent.as_ref().and_then(Weak::upgrade) {
+            parent
+                .borrow_mut()
+                .dispatch_forwarded(&event_key, &cursor.event);
+        }
+
+        None
+    }
+
+    /// Dispatches the event built by `build_event` to all listeners of
+    /// `event_key`, building it only if there is at least one listener.
+    ///
+    /// Cold listeners are only dispatched to if `event_key` has been woken.
+    fn dispatch<E, F>(&mut self, event_key: &X::Key, build_event: F)
+    where
+        E: Borrow<T>,
+        F: FnOnce() -> E,
+    {
+        let panic_policy = &self.panic_policy;
+        let listener_hooks = self.listener_hooks.as_ref();
+        let has_listeners = |listener_collection: &&mut Vec<_>| !listener_collection.is_empty();
+        let warm_listeners = self.events.get_mut(event_key).filter(has_listeners);
+        let cold_listeners = if self.woken_keys.contains(event_key) {
+            self.cold_events.get_mut(event_key).filter(has_listeners)
+        } else {
+            None
+        };
+        let parent = self.parent.as_ref().and_then(Weak::upgrade);
+
+        if warm_listeners.is_none() && cold_listeners.is_none() && parent.is_none() {
+            return;
+        }
+
+        let event = build_event();
+        let event = event.borrow();
+
+        for listener_collection in warm_listeners.into_iter().chain(cold_listeners) {
+            if matches!(
+                execute_dispatcher_requests(listener_collection, |listener| {
+                    hooks::surround(listener_hooks, event, listener.name(), || {
+                        panic_policy.guard(
+                            listener.name(),
+                            DispatcherRequest::StopListening,
+                            || listener.on_event(event),
+                        )
+                    })
+                }),
+                ExecuteRequestsResult::Stopped
+            ) {
+                return;
+            }
+        }
+
+        if let Some(parent) = parent {
+            parent.borrow_mut().dispatch_forwarded(event_key, event);
+        }
+    }
+
+    /// Dispatches an `event` forwarded by a child-dispatcher.
+    fn dispatch_forwarded(&mut self, event_key: &X::Key, event: &T) {
+        self.dispatch(event_key, || event);
+    }
+}
+
+impl<T, X> Resumable<T> for Dispatcher<T, X>
+where
+    T: Clone + Sized + 'static,
+    X: KeyExtract<T>,
+{
+    fn resume_dispatch(
+        &mut self,
+        cursor: DispatchCursor<T>,
+        budget: Budget,
+    ) -> Option<DispatchCursor<T>> {
+        Self::resume_dispatch(self, cursor, budget)
+    }
+}
+
+/// Adds listeners returned by [`Dispatcher::drain`] by the key they have
+/// been registered by, migrating them between dispatchers keyed by other
+/// means than the event itself.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::rc::{DiscriminantDispatcher, DispatcherRequest, Listener};
+///
+/// #[derive(Clone)]
+/// enum Event {
+///     Tick(u64),
+/// }
+///
+/// struct Physics;
+///
+/// impl Listener<Event> for Physics {
+///     fn on_event(&self, _event: &Event) -> Option<DispatcherRequest> { None }
+/// }
+///
+/// let mut old_world: DiscriminantDispatcher<Event> = DiscriminantDispatcher::new();
+/// let mut new_world: DiscriminantDispatcher<Event> = DiscriminantDispatcher::new();
+///
+/// old_world.add_listener(Event::Tick(0), Physics);
+/// new_world.extend(old_world.drain());
+///
+/// assert_eq!(new_world.listener_count(&Event::Tick(1)), 1);
+/// ```
+impl<T, X> Extend<(X::Key, Box<dyn Listener<T> + 'static>)> for Dispatcher<T, X>
+where
+    T: Clone + Sized + 'static,
+    X: KeyExtract<T>,
+{
+    fn extend<I>(&mut self, listeners: I)
+    where
+        I: IntoIterator<Item = (X::Key, Box<dyn Listener<T> + 'static>)>,
+    {
+        for (event_key, listener) in listeners {
+            Self::register(self.events.entry(event_key).or_default(), listener);
         }
     }
 }
+
+impl<T, X> Default for Dispatcher<T, X>
+where
+    T: Clone + Sized + 'static,
+    X: KeyExtract<T>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The listeners of an event-key a [`DispatchCursor`] is within, cold
+/// listeners are dispatched to after warm ones.
+#[derive(Clone, Copy)]
+enum Tier {
+    Warm,
+    Cold,
+}
+
+impl Tier {
+    /// Returns the tier `cursor` is within, a cursor without a tier is
+    /// within the warm listeners.
+    const fn of<T>(cursor: &DispatchCursor<T>) -> Self {
+        match cursor.tier {
+            Some(()) => Self::Cold,
+            None => Self::Warm,
+        }
+    }
+
+    /// Moves `cursor` before the first listener of this tier.
+    fn enter<T>(self, cursor: &mut DispatchCursor<T>) {
+        cursor.tier = match self {
+            Self::Warm => None,
+            Self::Cold => Some(()),
+        };
+        cursor.last_listener = None;
+    }
+}
+
+/// Wraps a listener added to a [`Dispatcher`], carrying the id it has been
+/// added under.
+struct Registered<L> {
+    id: ListenerId,
+    listener: L,
+}
+
+impl<T, L> Listener<T> for Registered<L>
+where
+    T: Clone + 'static,
+    L: Listener<T>,
+{
+    fn on_event(&self, event: &T) -> Option<DispatcherRequest> {
+        self.listener.on_event(event)
+    }
+
+    fn name(&self) -> &str {
+        self.listener.name()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.listener.is_alive()
+    }
+
+    fn listener_id(&self) -> Option<ListenerId> {
+        Some(self.id)
+    }
+}
@@ -1,21 +1,89 @@
+use super::handle::ListenerId;
 use std::hash::Hash;
 
 /// Contains the blocking dispatcher.
 pub mod dispatcher;
+/// Contains the blocking query dispatcher.
+pub mod query_dispatcher;
 
 /// Puts the blocking dispatcher in scope.
-pub use dispatcher::Dispatcher;
+pub use dispatcher::{DiscriminantDispatcher, Dispatcher};
+/// Puts the blocking query dispatcher in scope.
+pub use query_dispatcher::QueryDispatcher;
 
 /// Every event-receiver needs to implement this trait
 /// in order to receive dispatched events.
 /// `T` being the type you use for events, e.g. an `Enum`.
 pub trait Listener<T>
 where
-    T: PartialEq + Eq + Hash + Clone + 'static,
+    T: Clone + 'static,
 {
     /// This function will be called once a listened
     /// event-type `T` has been dispatched.
     fn on_event(&self, event: &T) -> Option<DispatcherRequest>;
+
+    /// Returns the listener's name, used to describe the listener
+    /// in a [`Snapshot`](crate::snapshot::Snapshot).
+    ///
+    /// Defaults to the listener's type name.
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Returns whether the listener can still receive events, listeners
+    /// that are no longer alive are purged by their dispatcher's
+    /// `purge_dead` and whenever a listener is added to their event-key.
+    ///
+    /// Defaults to `true`, see [`weakly`](crate::fns::weakly).
+    fn is_alive(&self) -> bool {
+        true
+    }
+
+    /// Returns the [`ListenerId`] the listener has been registered under
+    /// by the dispatcher it has been added to.
+    ///
+    /// Only meant for dispatchers, which assign ids by wrapping the
+    /// listeners they are given, overriding it has no effect.
+    #[doc(hidden)]
+    fn listener_id(&self) -> Option<ListenerId> {
+        None
+    }
+}
+
+impl<T, L> Listener<T> for Box<L>
+where
+    T: Clone + 'static,
+    L: Listener<T> + ?Sized,
+{
+    fn on_event(&self, event: &T) -> Option<DispatcherRequest> {
+        (**self).on_event(event)
+    }
+
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn is_alive(&self) -> bool {
+        (**self).is_alive()
+    }
+
+    fn listener_id(&self) -> Option<ListenerId> {
+        (**self).listener_id()
+    }
+}
+
+/// Every query-receiver needs to implement this trait
+/// in order to answer dispatched queries.
+/// `Q` being the type you use for queries, e.g. an `Enum`,
+/// and `R` being the type of the response.
+pub trait QueryHandler<Q, R>
+where
+    Q: PartialEq + Eq + Hash + Clone + 'static,
+{
+    /// This function will be called once a handled
+    /// query-type `Q` has been dispatched.
+    /// Returning `None` leaves the query for other handlers to answer.
+    fn handle(&mut self, query: &Q) -> Option<R>;
 }
 
 /// When `execute_sync_dispatcher_requests` returns,
@@ -28,6 +96,7 @@ pub(crate) enum ExecuteRequestsResult {
 }
 
 /// An `enum` returning a request from a listener to the single-threaded dispatcher.
+///
 /// This `enum` is not restricted to dispatcher residing in the `sync`-module.
 /// A request will be processed by the event-dispatcher depending on the variant:
 ///
@@ -60,9 +129,7 @@ pub enum DispatcherRequest {
 /// then execute `StopPropagation`.
 ///
 /// **Note**: When `StopListening` is being executed,
-/// removal of items from `vec` will result use a swap of elements,
-/// resulting in an alteration of the order items were originally
-/// inserted into `vec`.
+/// removal of items from `vec` keeps the order of the remaining items.
 ///
 /// **Note**: Unlike [`retain`], `execute_sync_dispatcher_requests`
 /// can break the current iteration and is able to match [`SyncDispatchResult`]
@@ -84,11 +151,11 @@ where
             match function(&vec[index]) {
                 None => index += 1,
                 Some(DispatcherRequest::StopListening) => {
-                    vec.swap_remove(index);
+                    vec.remove(index);
                 }
                 Some(DispatcherRequest::StopPropagation) => return ExecuteRequestsResult::Stopped,
                 Some(DispatcherRequest::StopListeningAndPropagation) => {
-                    vec.swap_remove(index);
+                    vec.remove(index);
                     return ExecuteRequestsResult::Stopped;
                 }
             }
@@ -122,7 +189,7 @@ mod tests {
             let mut vec = vec![0, 0, 0, 1, 1, 1, 1];
             execute_dispatcher_requests(&mut vec, map_usize_to_request);
 
-            assert_eq!(vec, [1, 0, 0, 1, 1, 1]);
+            assert_eq!(vec, [1, 1, 1, 1]);
         }
 
         #[test]
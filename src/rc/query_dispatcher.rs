@@ -0,0 +1,128 @@
+use super::QueryHandler;
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+/// In charge of asking handlers to answer queries.
+///
+/// Opposed to [`Dispatcher`], handlers respond with a value of type `R`.
+///
+/// [`Dispatcher`]: super::Dispatcher
+pub struct QueryDispatcher<Q, R>
+where
+    Q: PartialEq + Eq + Hash + Clone + 'static,
+{
+    handlers: HashMap<Q, Vec<Box<dyn QueryHandler<Q, R> + 'static>>>,
+}
+
+impl<Q, R> QueryDispatcher<Q, R>
+where
+    Q: PartialEq + Eq + Hash + Clone + 'static,
+{
+    /// Create a new query dispatcher.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            handlers: HashMap::new(),
+        }
+    }
+
+    /// Adds a [`QueryHandler`] to answer queries equal to `query_key`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::{QueryDispatcher, QueryHandler};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Query {
+    ///     PlayerHealth,
+    /// }
+    ///
+    /// struct Player {
+    ///     health: u32,
+    /// }
+    ///
+    /// impl QueryHandler<Query, u32> for Player {
+    ///     fn handle(&mut self, _query: &Query) -> Option<u32> {
+    ///         Some(self.health)
+    ///     }
+    /// }
+    ///
+    /// let mut dispatcher: QueryDispatcher<Query, u32> = QueryDispatcher::new();
+    ///
+    /// dispatcher.add_handler(Query::PlayerHealth, Player { health: 100 });
+    ///
+    /// assert_eq!(dispatcher.query(&Query::PlayerHealth), Some(100));
+    /// ```
+    pub fn add_handler<D: QueryHandler<Q, R> + 'static>(&mut self, query_key: Q, handler: D) {
+        let handler = Box::new(handler);
+
+        self.handlers
+            .entry(query_key)
+            .or_default()
+            .push(handler as Box<dyn QueryHandler<Q, R> + 'static>);
+    }
+
+    /// Asks the [`QueryHandler`]s of `query` in order of their registration
+    /// and returns the first response.
+    ///
+    /// Handlers after the first responding one will not be asked.
+    pub fn query(&mut self, query: &Q) -> Option<R> {
+        self.handlers
+            .get_mut(query)?
+            .iter_mut()
+            .find_map(|handler| handler.handle(query))
+    }
+
+    /// Asks all [`QueryHandler`]s of `query` in order of their registration
+    /// and collects every response.
+    pub fn query_all(&mut self, query: &Q) -> Vec<R> {
+        self.handlers
+            .get_mut(query)
+            .map_or_else(Vec::new, |handlers| {
+                handlers
+                    .iter_mut()
+                    .filter_map(|handler| handler.handle(query))
+                    .collect()
+            })
+    }
+
+    /// Returns how many handlers answer queries equal to `query_key`.
+    #[must_use]
+    pub fn listener_count(&self, query_key: &Q) -> usize {
+        self.handlers.get(query_key).map_or(0, Vec::len)
+    }
+
+    /// Returns how many handlers answer any query.
+    #[must_use]
+    pub fn total_listeners(&self) -> usize {
+        self.handlers.values().map(Vec::len).sum()
+    }
+
+    /// Returns the query-keys at least one handler answers.
+    #[must_use]
+    pub fn event_keys(&self) -> HashSet<Q> {
+        self.handlers
+            .iter()
+            .filter(|(_, handlers)| !handlers.is_empty())
+            .map(|(query_key, _)| query_key.clone())
+            .collect()
+    }
+
+    /// Returns whether no handler answers any query.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.handlers.values().all(Vec::is_empty)
+    }
+}
+
+impl<Q, R> Default for QueryDispatcher<Q, R>
+where
+    Q: PartialEq + Eq + Hash + Clone + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
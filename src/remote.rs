@@ -0,0 +1,163 @@
+//! Sends events to dispatchers living in other processes.
+//!
+//! A [`RemoteEmitter`](crate::remote::RemoteEmitter) serialises events as
+//! JSON, one event per line, onto any writable connection.
+//! A [`RemoteReceiver`](crate::remote::RemoteReceiver) reads them from the
+//! other end and dispatches them into a local
+//! [`AsyncDispatcher`](crate::sync::AsyncDispatcher).
+//!
+//! Both are generic over Tokio's [`AsyncWrite`]- and [`AsyncRead`]-traits,
+//! hence work on TCP- as well as Unix-sockets.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use hey_listen::{
+//!     remote::{RemoteEmitter, RemoteReceiver},
+//!     sync::AsyncDispatcher,
+//! };
+//! use serde::{Deserialize, Serialize};
+//! use tokio::net::TcpListener;
+//!
+//! #[derive(Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+//! enum Event {
+//!     Ping,
+//! }
+//!
+//! # tokio::runtime::Builder::new_current_thread().enable_io().build().unwrap().block_on(async {
+//! let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+//! let address = listener.local_addr().unwrap();
+//!
+//! let mut emitter = RemoteEmitter::connect(address).await.unwrap();
+//! emitter.emit(&Event::Ping).await.unwrap();
+//! drop(emitter);
+//!
+//! let (stream, _) = listener.accept().await.unwrap();
+//! let mut dispatcher: AsyncDispatcher<Event> = AsyncDispatcher::new();
+//!
+//! RemoteReceiver::new(stream).run(&mut dispatcher).await.unwrap();
+//! # });
+//! ```
+//!
+//! [`AsyncWrite`]: tokio::io::AsyncWrite
+//! [`AsyncRead`]: tokio::io::AsyncRead
+use super::{key::KeyExtract, sync::AsyncDispatcher, Error};
+use serde::{de::DeserializeOwned, Serialize};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader},
+    net::{TcpStream, ToSocketAddrs},
+};
+
+/// Serialises events and writes them onto a connection.
+pub struct RemoteEmitter<W> {
+    writer: W,
+}
+
+impl RemoteEmitter<TcpStream> {
+    /// Connects to a [`RemoteReceiver`] listening on `address` via TCP.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the connection cannot be established.
+    pub async fn connect<A: ToSocketAddrs>(address: A) -> Result<Self, Error> {
+        Ok(Self::new(TcpStream::connect(address).await?))
+    }
+}
+
+impl<W> RemoteEmitter<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Creates an emitter writing onto `writer`.
+    pub const fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Serialises `event` and writes it onto the connection.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `event` cannot be serialised or the connection cannot be
+    /// written to.
+    pub async fn emit<T: Serialize + Sync>(&mut self, event: &T) -> Result<(), Error> {
+        let mut line = serde_json::to_vec(event)?;
+        line.push(b'\n');
+
+        self.writer.write_all(&line).await?;
+        self.writer.flush().await?;
+
+        Ok(())
+    }
+
+    /// Returns the underlying connection.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads events from a connection and dispatches them locally.
+pub struct RemoteReceiver<R> {
+    reader: BufReader<R>,
+    line: String,
+}
+
+impl<R> RemoteReceiver<R>
+where
+    R: AsyncRead + Unpin,
+{
+    /// Creates a receiver reading from `reader`.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            line: String::new(),
+        }
+    }
+
+    /// Reads the next event and dispatches it into `dispatcher`.
+    ///
+    /// Returns `false` once the connection has been closed.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the connection cannot be read from or the received event
+    /// cannot be deserialised.
+    pub async fn dispatch_next<T, X>(
+        &mut self,
+        dispatcher: &mut AsyncDispatcher<T, X>,
+    ) -> Result<bool, Error>
+    where
+        T: Clone + Send + Sync + DeserializeOwned + 'static,
+        X: KeyExtract<T>,
+        X::Key: Send + Sync,
+    {
+        self.line.clear();
+
+        if self.reader.read_line(&mut self.line).await? == 0 {
+            return Ok(false);
+        }
+
+        let event: T = serde_json::from_str(&self.line)?;
+        dispatcher.dispatch_event(&event).await;
+
+        Ok(true)
+    }
+
+    /// Dispatches every received event into `dispatcher` until the
+    /// connection has been closed.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the connection cannot be read from or a received event
+    /// cannot be deserialised.
+    pub async fn run<T, X>(mut self, dispatcher: &mut AsyncDispatcher<T, X>) -> Result<(), Error>
+    where
+        T: Clone + Send + Sync + DeserializeOwned + 'static,
+        X: KeyExtract<T>,
+        X::Key: Send + Sync,
+    {
+        while self.dispatch_next(dispatcher).await? {}
+
+        Ok(())
+    }
+}
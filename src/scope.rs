@@ -0,0 +1,249 @@
+//! Subscriptions tied to the lifetime of a guard.
+//!
+//! Each dispatcher's `subscribe_scoped`-method adds a listener and returns a
+//! [`SubscriptionGuard`](crate::scope::SubscriptionGuard), once the guard is
+//! dropped the listener stops receiving events.
+//! Keeping the guard in e.g. a game state or UI screen ties the listener to
+//! the lifetime of the state, without removing it manually.
+//!
+//! Removal is lazy: a guard does not know its dispatcher, hence dropping it
+//! only marks the listener as dead. Dispatchers exclude dead listeners from
+//! their counts and snapshots right away, but only free them like any other
+//! dead listener, via their `purge_dead`-method, when adding a listener to
+//! the same event-key, or when dispatching to them.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "parallel")] {
+//! use hey_listen::{
+//!     scope::SubscriptionGuard,
+//!     sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener},
+//! };
+//! use std::sync::{
+//!     atomic::{AtomicUsize, Ordering},
+//!     Arc,
+//! };
+//!
+//! #[derive(Clone, Eq, Hash, PartialEq)]
+//! enum Event {
+//!     Click,
+//! }
+//!
+//! struct ClickCounter(Arc<AtomicUsize>);
+//!
+//! impl ParallelListener<Event> for ClickCounter {
+//!     fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> {
+//!         self.0.fetch_add(1, Ordering::SeqCst);
+//!
+//!         None
+//!     }
+//! }
+//!
+//! struct MenuScreen {
+//!     _clicks: SubscriptionGuard,
+//! }
+//!
+//! let clicks = Arc::new(AtomicUsize::new(0));
+//! let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+//!     .expect("Failed to build threadpool");
+//!
+//! let screen = MenuScreen {
+//!     _clicks: dispatcher.subscribe_scoped(Event::Click, ClickCounter(Arc::clone(&clicks))),
+//! };
+//!
+//! dispatcher.dispatch_event(&Event::Click);
+//!
+//! // Closing the screen unsubscribes its listener.
+//! drop(screen);
+//! dispatcher.dispatch_event(&Event::Click);
+//!
+//! assert_eq!(clicks.load(Ordering::SeqCst), 1);
+//! # }
+//! ```
+#[cfg(feature = "blocking")]
+use crate::rc::DispatcherRequest;
+#[cfg(feature = "async")]
+use crate::sync::AsyncDispatchResult;
+#[cfg(feature = "parallel")]
+use crate::sync::ParallelDispatchResult;
+#[cfg(feature = "priority")]
+use crate::sync::PriorityDispatcherResult;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// Unsubscribes the listener it has been returned for once dropped.
+///
+/// The listener is not removed from its dispatcher until the dispatcher
+/// purges dead listeners, but neither receives events nor is counted
+/// anymore.
+///
+/// Returned by the dispatchers' `subscribe_scoped`-methods.
+#[must_use = "Dropping the guard unsubscribes the listener"]
+#[derive(Debug)]
+pub struct SubscriptionGuard {
+    /// `None` once detached.
+    is_subscribed: Option<Arc<AtomicBool>>,
+}
+
+impl SubscriptionGuard {
+    /// Drops the guard without unsubscribing the listener, leaving it
+    /// subscribed for as long as its dispatcher keeps it.
+    pub fn detach(mut self) {
+        self.is_subscribed = None;
+    }
+}
+
+impl Drop for SubscriptionGuard {
+    fn drop(&mut self) {
+        if let Some(is_subscribed) = &self.is_subscribed {
+            is_subscribed.store(false, Ordering::Release);
+        }
+    }
+}
+
+/// A listener added via a dispatcher's `subscribe_scoped`-method, stopping
+/// to listen once its [`SubscriptionGuard`] has been dropped.
+pub(crate) struct Scoped<L> {
+    listener: L,
+    is_subscribed: Arc<AtomicBool>,
+}
+
+impl<L> Scoped<L> {
+    /// Wraps `listener`, unsubscribing it once the returned guard is
+    /// dropped.
+    pub(crate) fn new(listener: L) -> (Self, SubscriptionGuard) {
+        let is_subscribed = Arc::new(AtomicBool::new(true));
+        let guard = SubscriptionGuard {
+            is_subscribed: Some(Arc::clone(&is_subscribed)),
+        };
+
+        (
+            Self {
+                listener,
+                is_subscribed,
+            },
+            guard,
+        )
+    }
+
+    /// Returns whether the guard is still alive.
+    fn is_subscribed(&self) -> bool {
+        self.is_subscribed.load(Ordering::Acquire)
+    }
+
+    /// Calls `on_event` unless the guard has been dropped, returns
+    /// `stop_listening` if it has.
+    #[cfg(any(feature = "blocking", feature = "priority", feature = "parallel"))]
+    fn call<R, F>(&self, stop_listening: R, on_event: F) -> Option<R>
+    where
+        F: FnOnce(&L) -> Option<R>,
+    {
+        if self.is_subscribed() {
+            on_event(&self.listener)
+        } else {
+            Some(stop_listening)
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, L> crate::rc::Listener<T> for Scoped<L>
+where
+    T: Clone + 'static,
+    L: crate::rc::Listener<T>,
+{
+    fn on_event(&self, event: &T) -> Option<DispatcherRequest> {
+        self.call(DispatcherRequest::StopListening, |listener| {
+            listener.on_event(event)
+        })
+    }
+
+    fn name(&self) -> &str {
+        self.listener.name()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.is_subscribed() && self.listener.is_alive()
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T, L> crate::sync::ParallelListener<T> for Scoped<L>
+where
+    T: Clone + Send + Sync + 'static,
+    L: crate::sync::ParallelListener<T>,
+{
+    fn on_event(&self, event: &T) -> Option<ParallelDispatchResult> {
+        self.call(ParallelDispatchResult::StopListening, |listener| {
+            listener.on_event(event)
+        })
+    }
+
+    fn name(&self) -> &str {
+        self.listener.name()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.is_subscribed() && self.listener.is_alive()
+    }
+}
+
+#[cfg(feature = "priority")]
+impl<T, L> crate::sync::PriorityListener<T> for Scoped<L>
+where
+    T: Clone + Send + Sync + 'static,
+    L: crate::sync::PriorityListener<T>,
+{
+    fn on_event(&self, event: &T) -> Option<PriorityDispatcherResult> {
+        self.call(PriorityDispatcherResult::StopListening, |listener| {
+            listener.on_event(event)
+        })
+    }
+
+    fn name(&self) -> &str {
+        self.listener.name()
+    }
+
+    fn requires_order(&self) -> bool {
+        self.listener.requires_order()
+    }
+
+    fn owner(&self) -> Option<crate::sync::Owner> {
+        self.listener.owner()
+    }
+
+    fn listener_id(&self) -> Option<crate::handle::ListenerId> {
+        self.listener.listener_id()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.is_subscribed() && self.listener.is_alive()
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<T, L> crate::sync::AsyncListener<T> for Scoped<L>
+where
+    T: Clone + Send + Sync + 'static,
+    L: crate::sync::AsyncListener<T> + Send + Sync,
+{
+    async fn on_event(&self, event: &T) -> Option<AsyncDispatchResult> {
+        if self.is_subscribed() {
+            self.listener.on_event(event).await
+        } else {
+            Some(AsyncDispatchResult::StopListening)
+        }
+    }
+
+    fn name(&self) -> &str {
+        self.listener.name()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.is_subscribed() && self.listener.is_alive()
+    }
+}
@@ -0,0 +1,228 @@
+//! Snapshots describe which listeners are registered for which event-keys.
+//!
+//! A [`Snapshot`](crate::snapshot::Snapshot) is taken via the dispatchers'
+//! `snapshot`-method, comparing two snapshots via
+//! [`Snapshot::diff`](crate::snapshot::Snapshot::diff) reveals how
+//! registrations changed in between, while
+//! [`Snapshot::health`](crate::snapshot::Snapshot::health) reports
+//! suspicious registrations.
+//!
+//! With the `serde`-feature enabled, snapshots can be serialised.
+use crate::key::EnumKey;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, hash::Hash};
+
+/// Describes the registered listeners of a dispatcher at a point in time.
+///
+/// `P` is the priority-type of the dispatcher, dispatchers without
+/// priorities use `()`.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Snapshot<T, P = ()> {
+    /// Every registered listener, in no particular order.
+    pub listeners: Vec<ListenerEntry<T, P>>,
+}
+
+/// Describes a single registered listener.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ListenerEntry<T, P = ()> {
+    /// The event-key the listener listens to.
+    pub event_key: T,
+    /// The priority the listener has been added with.
+    pub priority: P,
+    /// The listener's name, as returned by its `name`-method.
+    pub name: String,
+}
+
+/// The difference between two [`Snapshot`]s.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SnapshotDiff<T, P = ()> {
+    /// Listeners only registered in the newer snapshot.
+    pub added: Vec<ListenerEntry<T, P>>,
+    /// Listeners only registered in the older snapshot.
+    pub removed: Vec<ListenerEntry<T, P>>,
+}
+
+/// Suspicious registrations found by [`Snapshot::health`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HealthReport<T, P = ()> {
+    /// Listeners registered multiple times for the same event-key and
+    /// priority, alongside the amount of registrations.
+    pub duplicates: Vec<(ListenerEntry<T, P>, usize)>,
+    /// Event-keys with more listeners than expected, alongside the amount
+    /// of listeners.
+    pub crowded_keys: Vec<(T, usize)>,
+}
+
+impl<T, P> HealthReport<T, P> {
+    /// Returns whether nothing suspicious has been found.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.duplicates.is_empty() && self.crowded_keys.is_empty()
+    }
+}
+
+impl<T, P> Snapshot<T, P>
+where
+    T: Eq + Hash + Clone,
+    P: Eq + Hash + Clone,
+{
+    /// Scans for suspicious registrations, reporting listeners registered
+    /// multiple times and event-keys with more than `max_listeners`
+    /// listeners, e.g. to detect listeners leaking by being re-added.
+    ///
+    /// Listeners are told apart by event-key, priority, and name, naming
+    /// listeners avoids reporting distinct listeners of the same type.
+    ///
+    /// **Note**: Listeners holding a dropped weak reference cannot be told
+    /// apart from others until they are dispatched to and stop listening.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "priority")] {
+    /// use hey_listen::sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// struct Physics;
+    ///
+    /// impl PriorityListener<Event> for Physics {
+    ///     fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> { None }
+    /// }
+    ///
+    /// let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///
+    /// // Accidentally added twice.
+    /// dispatcher.add_listener(Event::Tick, Physics, 1);
+    /// dispatcher.add_listener(Event::Tick, Physics, 1);
+    ///
+    /// let report = dispatcher.snapshot().health(8);
+    /// assert_eq!(report.duplicates.len(), 1);
+    /// assert!(report.crowded_keys.is_empty());
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn health(&self, max_listeners: usize) -> HealthReport<T, P> {
+        let mut registrations: HashMap<&ListenerEntry<T, P>, usize> = HashMap::new();
+        let mut listeners_per_key: HashMap<&T, usize> = HashMap::new();
+
+        for entry in &self.listeners {
+            *registrations.entry(entry).or_default() += 1;
+            *listeners_per_key.entry(&entry.event_key).or_default() += 1;
+        }
+
+        let duplicates = registrations
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .map(|(entry, count)| (entry.clone(), count))
+            .collect();
+        let crowded_keys = listeners_per_key
+            .into_iter()
+            .filter(|(_, count)| *count > max_listeners)
+            .map(|(event_key, count)| (event_key.clone(), count))
+            .collect();
+
+        HealthReport {
+            duplicates,
+            crowded_keys,
+        }
+    }
+
+    /// Compares `self` as older snapshot with `newer`.
+    ///
+    /// Listeners are told apart by event-key, priority, and name.
+    /// Registering the same listener multiple times counts as multiple
+    /// entries.
+    #[must_use]
+    pub fn diff(&self, newer: &Self) -> SnapshotDiff<T, P> {
+        let mut counts: HashMap<&ListenerEntry<T, P>, isize> = HashMap::new();
+
+        for entry in &self.listeners {
+            *counts.entry(entry).or_default() -= 1;
+        }
+
+        for entry in &newer.listeners {
+            *counts.entry(entry).or_default() += 1;
+        }
+
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+
+        for (entry, count) in counts {
+            let entries = std::iter::repeat(entry).take(count.unsigned_abs());
+
+            if count > 0 {
+                added.extend(entries.cloned());
+            } else {
+                removed.extend(entries.cloned());
+            }
+        }
+
+        SnapshotDiff { added, removed }
+    }
+}
+
+impl<T, P> Snapshot<T, P>
+where
+    T: EnumKey + PartialEq,
+{
+    /// Returns every key of [`EnumKey::keys`] without any listener, e.g. to
+    /// assert at startup that all events are handled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "priority")] {
+    /// use hey_listen::{
+    ///     key::EnumKey,
+    ///     sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener},
+    /// };
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Jump,
+    ///     Land,
+    /// }
+    ///
+    /// impl EnumKey for Event {
+    ///     fn keys() -> Vec<Self> {
+    ///         vec![Event::Jump, Event::Land]
+    ///     }
+    /// }
+    ///
+    /// struct Animation;
+    ///
+    /// impl PriorityListener<Event> for Animation {
+    ///     fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> { None }
+    /// }
+    ///
+    /// let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    /// dispatcher.add_listener(Event::Jump, Animation, 1);
+    ///
+    /// assert_eq!(dispatcher.snapshot().unhandled_keys(), [Event::Land]);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn unhandled_keys(&self) -> Vec<T> {
+        T::keys()
+            .into_iter()
+            .filter(|key| !self.listeners.iter().any(|entry| entry.event_key == *key))
+            .collect()
+    }
+}
+
+impl<T, P> SnapshotDiff<T, P> {
+    /// Returns whether both snapshots describe the same registrations.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
@@ -0,0 +1,116 @@
+//! Compile-time registered dispatchers for fixed topologies.
+//!
+//! [`static_dispatcher!`](crate::static_dispatcher!) generates a dispatcher
+//! owning one field per listener.
+//! Dispatching matches the event against the patterns each listener has
+//! been declared with and calls matching listeners directly, without boxing
+//! or hashing.
+//!
+//! As the dispatcher owns its listeners, they receive events mutably.
+//! In return, listeners can neither be added nor removed at runtime.
+
+/// Every listener of a static dispatcher needs to implement this trait.
+///
+/// Dispatchers generated by [`static_dispatcher!`](crate::static_dispatcher!)
+/// call it in order to deliver dispatched events.
+/// `T` being the type you use for events, e.g. an `Enum`.
+pub trait StaticListener<T> {
+    /// This function will be called once an event matching one of the
+    /// listener's declared patterns has been dispatched.
+    fn on_event(&mut self, event: &T);
+}
+
+/// Generates a dispatcher with a fixed set of listeners.
+///
+/// Every listener is declared as field, followed by the patterns of events
+/// it shall receive.
+/// Listeners are dispatched to in order of their declaration.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::{static_dispatcher, static_dispatcher::StaticListener};
+///
+/// enum Event {
+///     Tick,
+///     Collision { force: u32 },
+///     Quit,
+/// }
+///
+/// #[derive(Default)]
+/// struct Physics {
+///     ticks: usize,
+/// }
+///
+/// impl StaticListener<Event> for Physics {
+///     fn on_event(&mut self, _event: &Event) {
+///         self.ticks += 1;
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct Audio {
+///     played: usize,
+/// }
+///
+/// impl StaticListener<Event> for Audio {
+///     fn on_event(&mut self, _event: &Event) {
+///         self.played += 1;
+///     }
+/// }
+///
+/// static_dispatcher! {
+///     /// Dispatches the game's events.
+///     pub struct GameDispatcher for Event {
+///         pub physics: Physics => [Event::Tick],
+///         pub audio: Audio => [Event::Collision { .. } | Event::Quit],
+///     }
+/// }
+///
+/// let mut dispatcher = GameDispatcher {
+///     physics: Physics::default(),
+///     audio: Audio::default(),
+/// };
+///
+/// dispatcher.dispatch_event(&Event::Tick);
+/// dispatcher.dispatch_event(&Event::Collision { force: 3 });
+///
+/// assert_eq!(dispatcher.physics.ticks, 1);
+/// assert_eq!(dispatcher.audio.played, 1);
+/// ```
+#[macro_export]
+macro_rules! static_dispatcher {
+    (
+        $(#[$attribute:meta])*
+        $visibility:vis struct $name:ident for $event:ty {
+            $(
+                $(#[$field_attribute:meta])*
+                $field_visibility:vis $field:ident: $listener:ty => [$($pattern:pat)|+]
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attribute])*
+        $visibility struct $name {
+            $(
+                $(#[$field_attribute])*
+                $field_visibility $field: $listener,
+            )*
+        }
+
+        impl $name {
+            /// Dispatches `event` to every listener declared with a pattern
+            /// matching `event`, in order of their declaration.
+            #[allow(unused_variables)]
+            $visibility fn dispatch_event(&mut self, event: &$event) {
+                $(
+                    if matches!(event, $($pattern)|+) {
+                        $crate::static_dispatcher::StaticListener::<$event>::on_event(
+                            &mut self.$field,
+                            event,
+                        );
+                    }
+                )*
+            }
+        }
+    };
+}
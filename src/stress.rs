@@ -0,0 +1,367 @@
+//! Configurable event workloads, measuring how a dispatcher copes with them.
+//!
+//! A [`Workload`](crate::stress::Workload) describes how many event-keys,
+//! listeners, and events to generate, how fast to dispatch them, and how
+//! likely listeners stop listening.
+//! Running it against any [`StressTarget`](crate::stress::StressTarget)
+//! reports throughput and dispatch-latency in a
+//! [`StressReport`](crate::stress::StressReport), validating a configuration
+//! on the target hardware.
+//!
+//! Events are `usize`s, each naming its own event-key.
+//! Workloads are generated from a seed, the same seed generates the same
+//! events and requests to stop listening.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "parallel")] {
+//! use hey_listen::{stress::Workload, sync::ParallelDispatcher};
+//!
+//! let mut dispatcher: ParallelDispatcher<usize> = ParallelDispatcher::new(4)
+//!     .expect("Failed to build threadpool");
+//!
+//! let report = Workload::new()
+//!     .keys(8)
+//!     .listeners_per_key(16)
+//!     .events(1_000)
+//!     .stop_listening(0.001)
+//!     .run(&mut dispatcher);
+//!
+//! println!(
+//!     "{:.0} events/s, p99 latency {:?}",
+//!     report.throughput(),
+//!     report.latency_percentile(0.99)
+//! );
+//! # }
+//! ```
+#[cfg(feature = "blocking")]
+use crate::rc::{Dispatcher, DispatcherRequest};
+#[cfg(feature = "parallel")]
+use crate::sync::{ParallelDispatchResult, ParallelDispatcher};
+#[cfg(feature = "priority")]
+use crate::sync::{PriorityDispatcher, PriorityDispatcherResult};
+use std::{
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// A dispatcher a [`Workload`] can run against.
+pub trait StressTarget {
+    /// Adds `listener` to listen for `event_key`.
+    fn add_stress_listener(&mut self, event_key: usize, listener: StressListener);
+
+    /// Dispatches `event` to its listeners.
+    fn dispatch_stress_event(&mut self, event: &usize);
+}
+
+#[cfg(feature = "blocking")]
+impl StressTarget for Dispatcher<usize> {
+    fn add_stress_listener(&mut self, event_key: usize, listener: StressListener) {
+        self.add_listener(event_key, listener);
+    }
+
+    fn dispatch_stress_event(&mut self, event: &usize) {
+        self.dispatch_event(event);
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl StressTarget for ParallelDispatcher<usize> {
+    fn add_stress_listener(&mut self, event_key: usize, listener: StressListener) {
+        self.add_listener(event_key, listener);
+    }
+
+    fn dispatch_stress_event(&mut self, event: &usize) {
+        self.dispatch_event(event);
+    }
+}
+
+/// Listeners are added with the default priority-level.
+#[cfg(feature = "priority")]
+impl<P> StressTarget for PriorityDispatcher<P, usize>
+where
+    P: Ord + Clone + Default,
+{
+    fn add_stress_listener(&mut self, event_key: usize, listener: StressListener) {
+        self.add_listener(event_key, listener, P::default());
+    }
+
+    fn dispatch_stress_event(&mut self, event: &usize) {
+        self.dispatch_event(event);
+    }
+}
+
+/// A listener generated by a [`Workload`], counting received events and
+/// randomly stopping to listen.
+pub struct StressListener {
+    received: Arc<AtomicUsize>,
+    stopped: Arc<AtomicUsize>,
+    stop_threshold: u64,
+    random: AtomicU64,
+}
+
+impl StressListener {
+    /// Counts `event` and returns whether to stop listening.
+    fn receive(&self) -> bool {
+        self.received.fetch_add(1, Ordering::Relaxed);
+
+        let random = self
+            .random
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |state| {
+                Some(xorshift(state))
+            })
+            .map_or(0, xorshift);
+
+        let stops = random < self.stop_threshold;
+
+        if stops {
+            self.stopped.fetch_add(1, Ordering::Relaxed);
+        }
+
+        stops
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl crate::rc::Listener<usize> for StressListener {
+    fn on_event(&self, _event: &usize) -> Option<DispatcherRequest> {
+        Some(DispatcherRequest::StopListening).filter(|_| self.receive())
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl crate::sync::ParallelListener<usize> for StressListener {
+    fn on_event(&self, _event: &usize) -> Option<ParallelDispatchResult> {
+        Some(ParallelDispatchResult::StopListening).filter(|_| self.receive())
+    }
+}
+
+#[cfg(feature = "priority")]
+impl crate::sync::PriorityListener<usize> for StressListener {
+    fn on_event(&self, _event: &usize) -> Option<PriorityDispatcherResult> {
+        Some(PriorityDispatcherResult::StopListening).filter(|_| self.receive())
+    }
+}
+
+/// Describes the events and listeners to generate.
+#[derive(Clone, Debug)]
+pub struct Workload {
+    keys: usize,
+    listeners_per_key: usize,
+    events: usize,
+    events_per_second: Option<u32>,
+    stop_listening: f64,
+    seed: u64,
+}
+
+impl Default for Workload {
+    fn default() -> Self {
+        Self {
+            keys: 1,
+            listeners_per_key: 1,
+            events: 1_000,
+            events_per_second: None,
+            stop_listening: 0.0,
+            seed: 0x2545_f491_4f6c_dd1d,
+        }
+    }
+}
+
+impl Workload {
+    /// Creates a workload dispatching 1000 events to one listener of one
+    /// event-key, as fast as possible.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how many event-keys events are spread over, randomly.
+    #[must_use]
+    pub const fn keys(mut self, keys: usize) -> Self {
+        self.keys = keys;
+
+        self
+    }
+
+    /// Sets how many listeners are added per event-key.
+    #[must_use]
+    pub const fn listeners_per_key(mut self, listeners_per_key: usize) -> Self {
+        self.listeners_per_key = listeners_per_key;
+
+        self
+    }
+
+    /// Sets how many events are dispatched.
+    #[must_use]
+    pub const fn events(mut self, events: usize) -> Self {
+        self.events = events;
+
+        self
+    }
+
+    /// Limits dispatching to `events_per_second`, instead of dispatching as
+    /// fast as possible.
+    #[must_use]
+    pub const fn events_per_second(mut self, events_per_second: u32) -> Self {
+        self.events_per_second = Some(events_per_second);
+
+        self
+    }
+
+    /// Sets the probability of a listener stopping to listen whenever it
+    /// receives an event, from `0.0` to `1.0`.
+    #[must_use]
+    pub fn stop_listening(mut self, probability: f64) -> Self {
+        self.stop_listening = probability.clamp(0.0, 1.0);
+
+        self
+    }
+
+    /// Sets the seed events and requests to stop listening are generated
+    /// from.
+    #[must_use]
+    pub const fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+
+        self
+    }
+
+    /// Adds the workload's listeners to `target`, dispatches its events, and
+    /// reports how long dispatching took.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the workload has no event-keys.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub fn run<D: StressTarget>(&self, target: &mut D) -> StressReport {
+        assert!(self.keys > 0, "A workload needs at least one event-key");
+
+        let received = Arc::new(AtomicUsize::new(0));
+        let stopped = Arc::new(AtomicUsize::new(0));
+        let stop_threshold = (self.stop_listening * u64::MAX as f64) as u64;
+        let mut random = self.seed | 1;
+
+        for event_key in 0..self.keys {
+            for _ in 0..self.listeners_per_key {
+                random = xorshift(random);
+
+                target.add_stress_listener(
+                    event_key,
+                    StressListener {
+                        received: Arc::clone(&received),
+                        stopped: Arc::clone(&stopped),
+                        stop_threshold,
+                        random: AtomicU64::new(random),
+                    },
+                );
+            }
+        }
+
+        let interval = self
+            .events_per_second
+            .map(|events_per_second| Duration::from_secs(1) / events_per_second.max(1));
+        let mut latencies = Vec::with_capacity(self.events);
+        let started = Instant::now();
+
+        for index in 0..self.events {
+            if let Some(interval) = interval {
+                let due = started + interval * index as u32;
+                let now = Instant::now();
+
+                if due > now {
+                    thread::sleep(due - now);
+                }
+            }
+
+            random = xorshift(random);
+            let event = (random % self.keys as u64) as usize;
+
+            let dispatched = Instant::now();
+            target.dispatch_stress_event(&event);
+            latencies.push(dispatched.elapsed());
+        }
+
+        let elapsed = started.elapsed();
+        latencies.sort_unstable();
+
+        StressReport {
+            events: self.events,
+            deliveries: received.load(Ordering::Relaxed),
+            stopped_listening: stopped.load(Ordering::Relaxed),
+            elapsed,
+            latencies,
+        }
+    }
+}
+
+/// Describes how a dispatcher coped with a [`Workload`].
+#[derive(Clone, Debug)]
+pub struct StressReport {
+    /// How many events have been dispatched.
+    pub events: usize,
+    /// How many times a listener received an event.
+    pub deliveries: usize,
+    /// How many listeners stopped listening.
+    pub stopped_listening: usize,
+    /// How long dispatching all events took, including pauses to keep the
+    /// rate.
+    pub elapsed: Duration,
+    /// How long each dispatch took, sorted from fastest to slowest.
+    latencies: Vec<Duration>,
+}
+
+impl StressReport {
+    /// Returns how many events have been dispatched per second.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn throughput(&self) -> f64 {
+        self.events as f64 / self.elapsed.as_secs_f64()
+    }
+
+    /// Returns the average time a dispatch took.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn mean_latency(&self) -> Duration {
+        if self.latencies.is_empty() {
+            return Duration::default();
+        }
+
+        self.latencies.iter().sum::<Duration>() / self.latencies.len() as u32
+    }
+
+    /// Returns the time `percentile` of dispatches took at most, e.g. `0.99`
+    /// for the 99th percentile, `1.0` for the slowest dispatch.
+    #[must_use]
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub fn latency_percentile(&self, percentile: f64) -> Duration {
+        let last_index = match self.latencies.len().checked_sub(1) {
+            Some(last_index) => last_index,
+            None => return Duration::default(),
+        };
+        let index = (percentile.clamp(0.0, 1.0) * last_index as f64).round() as usize;
+
+        self.latencies[index]
+    }
+}
+
+/// Advances the pseudo-random `state`, which must not be zero.
+const fn xorshift(mut state: u64) -> u64 {
+    state ^= state << 13;
+    state ^= state >> 7;
+    state ^= state << 17;
+
+    state
+}
@@ -0,0 +1,157 @@
+use super::{super::Mutex, ParallelDispatchResult, ParallelListener};
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender};
+
+/// A call of a listener pinned to its owning thread.
+struct Call<T> {
+    event: T,
+    reply: SyncSender<Option<ParallelDispatchResult>>,
+}
+
+/// Owns a listener that must only run on the thread it has been created
+/// on, e.g. one holding a graphics context.
+///
+/// The [`Affine`]-listeners created via [`listener`] are added to a
+/// [`ParallelDispatcher`] in its place, forwarding each event to the host
+/// and waiting for the owning thread to call the listener via [`run`] or
+/// [`run_pending`].
+/// Other listeners keep being dispatched to on the thread-pool meanwhile.
+///
+/// **Note**: Dispatching from the owning thread itself deadlocks, as the
+/// host cannot run calls while the thread waits for the dispatch.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::sync::{
+///     affinity::AffinityHost, ParallelDispatchResult, ParallelDispatcher, ParallelListener,
+/// };
+/// use std::thread;
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Render,
+/// }
+///
+/// struct Renderer;
+///
+/// impl ParallelListener<Event> for Renderer {
+///     fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> { None }
+/// }
+///
+/// let host = AffinityHost::new(Renderer);
+/// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(2)
+///     .expect("Failed to build threadpool");
+///
+/// dispatcher.add_listener(Event::Render, host.listener());
+///
+/// thread::spawn(move || dispatcher.dispatch_event(&Event::Render));
+///
+/// // `Renderer` runs on this thread until the dispatcher is dropped.
+/// host.run();
+/// ```
+///
+/// [`listener`]: Self::listener
+/// [`run`]: Self::run
+/// [`run_pending`]: Self::run_pending
+/// [`ParallelDispatcher`]: super::ParallelDispatcher
+pub struct AffinityHost<T, L> {
+    listener: L,
+    sender: Sender<Call<T>>,
+    receiver: Receiver<Call<T>>,
+}
+
+impl<T, L> AffinityHost<T, L>
+where
+    T: Clone + Send + Sync + 'static,
+    L: ParallelListener<T>,
+{
+    /// Creates a host owning `listener` on the current thread.
+    pub fn new(listener: L) -> Self {
+        let (sender, receiver) = mpsc::channel();
+
+        Self {
+            listener,
+            sender,
+            receiver,
+        }
+    }
+
+    /// Creates a listener forwarding events to the owned listener.
+    ///
+    /// Once the host is dropped, the created listener stops listening.
+    pub fn listener(&self) -> Affine<T> {
+        Affine {
+            name: self.listener.name().to_string(),
+            sender: Mutex::new(self.sender.clone()),
+        }
+    }
+
+    /// Calls the owned listener for every forwarded event received so far,
+    /// without waiting for further events.
+    ///
+    /// Returns the number of calls.
+    pub fn run_pending(&self) -> usize {
+        self.receiver
+            .try_iter()
+            .map(|call| answer(&self.listener, &call))
+            .count()
+    }
+
+    /// Calls the owned listener for every forwarded event until all
+    /// listeners created via [`listener`](Self::listener) have been dropped.
+    pub fn run(self) {
+        let Self {
+            listener,
+            sender,
+            receiver,
+        } = self;
+
+        // Only created listeners shall keep the channel open.
+        drop(sender);
+
+        for call in receiver {
+            answer(&listener, &call);
+        }
+    }
+}
+
+/// Calls `listener` with `call`'s event and replies its request.
+fn answer<T, L>(listener: &L, call: &Call<T>)
+where
+    T: Clone + Send + Sync + 'static,
+    L: ParallelListener<T>,
+{
+    // The dispatch waiting for the reply may have been dropped.
+    let _ = call.reply.send(listener.on_event(&call.event));
+}
+
+/// Forwards events to the listener owned by an [`AffinityHost`].
+pub struct Affine<T> {
+    name: String,
+    sender: Mutex<Sender<Call<T>>>,
+}
+
+impl<T> ParallelListener<T> for Affine<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    fn on_event(&self, event: &T) -> Option<ParallelDispatchResult> {
+        let (reply, response) = mpsc::sync_channel(1);
+        let call = Call {
+            event: event.clone(),
+            reply,
+        };
+
+        if self.sender.lock().send(call).is_err() {
+            return Some(ParallelDispatchResult::StopListening);
+        }
+
+        response
+            .recv()
+            .unwrap_or(Some(ParallelDispatchResult::StopListening))
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
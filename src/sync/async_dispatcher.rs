@@ -1,18 +1,58 @@
-use super::{super::Mutex, AsyncDispatchResult, AsyncListener};
-use futures::{stream::FuturesUnordered, StreamExt};
-use std::{collections::HashMap, hash::Hash};
+use super::{
+    super::{
+        handle::ListenerId,
+        hooks::{self, ListenerHooks, ListenerInfo},
+        key::{ByDiscriminant, Identity, KeyExtract, KeyId},
+        scope::{Scoped, SubscriptionGuard},
+        snapshot::{ListenerEntry, Snapshot},
+        Error,
+    },
+    AsyncDispatchResult, AsyncListener,
+};
+use futures::{
+    future::{AbortHandle, Abortable, Aborted},
+    stream::FuturesUnordered,
+    StreamExt,
+};
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet},
+    future::Future,
+    sync::Arc,
+};
+use tokio::sync::Semaphore;
 
 /// In charge of parallel dispatching to all listeners.
-pub struct AsyncDispatcher<T>
+///
+/// `X` is the [`KeyExtract`]-strategy deriving the key listeners are
+/// registered by from events, by default the event itself.
+pub struct AsyncDispatcher<T, X = Identity>
 where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
 {
-    events: HashMap<T, Vec<Box<dyn AsyncListener<T> + Send + Sync + 'static>>>,
+    events: HashMap<X::Key, Vec<Box<dyn AsyncListener<T> + Send + Sync + 'static>>>,
+    exclusion_groups: HashMap<String, Arc<Semaphore>>,
+    /// Listeners that requested to stop listening during a dispatch that
+    /// has not removed them yet, by key and index.
+    stopped: Vec<(X::Key, usize)>,
+    listener_hooks: Option<ListenerHooks<T, AsyncDispatchResult>>,
 }
 
-impl<T> AsyncDispatcher<T>
+/// An [`AsyncDispatcher`] registering listeners by the variant of
+/// `Enum`-events.
+///
+/// Events carrying fields work without implementing [`Hash`]- and
+/// [`PartialEq`]-traits ignoring those fields.
+///
+/// [`Hash`]: std::hash::Hash
+pub type DiscriminantAsyncDispatcher<T> = AsyncDispatcher<T, ByDiscriminant>;
+
+impl<T, X> AsyncDispatcher<T, X>
 where
-    T: PartialEq + Eq + Hash + Clone + Send + Sized + Sync + 'static,
+    T: Clone + Send + Sized + Sync + 'static,
+    X: KeyExtract<T>,
+    X::Key: Send + Sync,
 {
     /// Create a new async dispatcher.
     /// Amount of threads must be set via Tokio.
@@ -20,12 +60,19 @@ where
     pub fn new() -> Self {
         Self {
             events: HashMap::new(),
+            exclusion_groups: HashMap::new(),
+            stopped: Vec::new(),
+            listener_hooks: None,
         }
     }
 
     /// Adds a [`AsyncListener`] to listen for an `event_key`.
     ///
-    /// **Note**: If your `Enum` owns fields you need to consider implementing
+    /// Returns the [`ListenerId`] removing the listener via
+    /// [`remove_listener`](Self::remove_listener).
+    ///
+    /// **Note**: If your `Enum` owns fields you need to consider a
+    /// [`KeyExtract`]-strategy or implementing
     /// the [`Hash`]- and [`PartialEq`]-trait if you want to ignore fields,
     /// see second example for an implementation-suggestion.
     ///
@@ -92,13 +139,328 @@ where
         &mut self,
         event_key: T,
         listener: D,
-    ) {
-        let listener = Box::new(listener);
+    ) -> ListenerId {
+        self.register(X::extract(&event_key), listener)
+    }
+
+    /// Adds `listener` for `event_key` and returns the id it has been
+    /// registered under.
+    fn register<D: AsyncListener<T> + Send + Sync + 'static>(
+        &mut self,
+        event_key: X::Key,
+        listener: D,
+    ) -> ListenerId {
+        // Purging shifts indices, pending removals must be applied first.
+        self.remove_stopped();
+
+        let id = ListenerId::next();
+        let listeners = self.events.entry(event_key).or_default();
+
+        listeners.retain(AsyncListener::is_alive);
+        listeners.push(Box::new(Registered { id, listener }));
+
+        id
+    }
+
+    /// Removes the listener added under `id`, returns whether it has been
+    /// found.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{AsyncDispatchResult, AsyncDispatcher, AsyncListener};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// struct Physics;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl AsyncListener<Event> for Physics {
+    ///     async fn on_event(&self, _event: &Event) -> Option<AsyncDispatchResult> { None }
+    /// }
+    ///
+    /// let mut dispatcher: AsyncDispatcher<Event> = AsyncDispatcher::new();
+    ///
+    /// let id = dispatcher.add_listener(Event::Tick, Physics);
+    /// dispatcher.add_listener(Event::Tick, Physics);
+    ///
+    /// assert!(dispatcher.remove_listener(id));
+    /// assert!(!dispatcher.remove_listener(id));
+    /// assert_eq!(dispatcher.listener_count(&Event::Tick), 1);
+    /// ```
+    pub fn remove_listener(&mut self, id: ListenerId) -> bool {
+        // Removing shifts indices, pending removals must be applied first.
+        self.remove_stopped();
+
+        let mut removed = false;
+
+        for listeners in self.events.values_mut() {
+            let listener_count = listeners.len();
+
+            listeners.retain(|listener| listener.listener_id() != Some(id));
+            removed |= listener_count != listeners.len();
+        }
+
+        removed
+    }
+
+    /// Adds an [`AsyncListener`] to listen for an `event_key` until the
+    /// returned [`SubscriptionGuard`] is dropped.
+    pub fn subscribe_scoped<D: AsyncListener<T> + Send + Sync + Sized + 'static>(
+        &mut self,
+        event_key: T,
+        listener: D,
+    ) -> SubscriptionGuard {
+        let (listener, guard) = Scoped::new(listener);
+
+        self.add_listener(event_key, listener);
+
+        guard
+    }
+
+    /// Adds an exclusion group named `name`, allowing at most `permits`
+    /// of its listeners to run at the same time, e.g. `1` for listeners
+    /// sharing a database-connection.
+    ///
+    /// Listeners added via
+    /// [`add_listener_to_exclusion_group`](Self::add_listener_to_exclusion_group)
+    /// wait for a permit before being called, while listeners outside the
+    /// group keep running concurrently.
+    ///
+    /// Adding a group with an already existing name keeps the original
+    /// group.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{AsyncDispatchResult, AsyncDispatcher, AsyncListener};
+    /// use async_trait::async_trait;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Saved,
+    /// }
+    ///
+    /// struct AuditLog;
+    /// struct Statistics;
+    ///
+    /// #[async_trait]
+    /// impl AsyncListener<Event> for AuditLog {
+    ///     async fn on_event(&self, _event: &Event) -> Option<AsyncDispatchResult> { None }
+    /// }
+    ///
+    /// #[async_trait]
+    /// impl AsyncListener<Event> for Statistics {
+    ///     async fn on_event(&self, _event: &Event) -> Option<AsyncDispatchResult> { None }
+    /// }
+    ///
+    /// let mut dispatcher: AsyncDispatcher<Event> = AsyncDispatcher::new();
+    ///
+    /// dispatcher.add_exclusion_group("database", 1);
+    ///
+    /// // Both listeners share the connection, they never run concurrently.
+    /// dispatcher.add_listener_to_exclusion_group(Event::Saved, "database", AuditLog)
+    ///     .expect("Exclusion group does not exist");
+    /// dispatcher.add_listener_to_exclusion_group(Event::Saved, "database", Statistics)
+    ///     .expect("Exclusion group does not exist");
+    /// ```
+    pub fn add_exclusion_group(&mut self, name: impl Into<String>, permits: usize) {
+        self.exclusion_groups
+            .entry(name.into())
+            .or_insert_with(|| Arc::new(Semaphore::new(permits)));
+    }
+
+    /// Adds a [`AsyncListener`] to listen for an `event_key` as member of the
+    /// exclusion group named `group`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::UnknownExclusionGroup`] if no exclusion group
+    /// named `group` has been added.
+    ///
+    /// [`AsyncListener`]: trait.AsyncListener.html
+    pub fn add_listener_to_exclusion_group<D: AsyncListener<T> + Send + Sync + Sized + 'static>(
+        &mut self,
+        event_key: T,
+        group: &str,
+        listener: D,
+    ) -> Result<ListenerId, Error> {
+        let semaphore = self
+            .exclusion_groups
+            .get(group)
+            .ok_or_else(|| Error::UnknownExclusionGroup(group.to_string()))?;
+        let listener = Exclusive {
+            semaphore: Arc::clone(semaphore),
+            listener,
+        };
+
+        Ok(self.add_listener(event_key, listener))
+    }
+
+    /// Returns how many listeners listen for `event_key`, excluding those
+    /// that stopped listening or died but have not been removed yet.
+    #[must_use]
+    pub fn listener_count(&self, event_key: &T) -> usize {
+        let event_key = X::extract(event_key);
+
+        self.live_listeners(&event_key).count()
+    }
+
+    /// Returns how many listeners listen for any event-key, excluding those
+    /// that stopped listening or died but have not been removed yet.
+    #[must_use]
+    pub fn total_listeners(&self) -> usize {
+        self.events
+            .keys()
+            .map(|event_key| self.live_listeners(event_key).count())
+            .sum()
+    }
+
+    /// Returns the event-keys at least one listener listens for.
+    #[must_use]
+    pub fn event_keys(&self) -> HashSet<X::Key> {
+        self.events
+            .keys()
+            .filter(|event_key| self.live_listeners(event_key).next().is_some())
+            .cloned()
+            .collect()
+    }
 
+    /// Returns whether no listener listens for any event-key.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.total_listeners() == 0
+    }
+
+    /// Takes a [`Snapshot`] of all registered listeners.
+    ///
+    /// [`Snapshot`]: crate::snapshot::Snapshot
+    #[must_use]
+    pub fn snapshot(&self) -> Snapshot<X::Key> {
+        let listeners = self
+            .events
+            .keys()
+            .flat_map(|event_key| {
+                self.live_listeners(event_key)
+                    .map(move |listener| ListenerEntry {
+                        event_key: event_key.clone(),
+                        priority: (),
+                        name: listener.name().to_string(),
+                    })
+            })
+            .collect();
+
+        Snapshot { listeners }
+    }
+
+    /// Returns the listeners of `event_key` that neither stopped listening
+    /// nor died.
+    fn live_listeners<'a>(
+        &'a self,
+        event_key: &'a X::Key,
+    ) -> impl Iterator<Item = &'a (dyn AsyncListener<T> + Send + Sync + 'static)> + 'a {
         self.events
-            .entry(event_key)
-            .or_insert_with(Vec::new)
-            .push(listener as Box<(dyn AsyncListener<T> + Send + Sync + 'static)>);
+            .get(event_key)
+            .into_iter()
+            .flatten()
+            .enumerate()
+            .filter(move |(index, listener)| {
+                listener.is_alive() && !self.stopped.contains(&(event_key.clone(), *index))
+            })
+            .map(|(_, listener)| listener.as_ref())
+    }
+
+    /// Removes all listeners of `event_key` and returns them.
+    ///
+    /// Boxed listeners implement [`AsyncListener`] themselves, hence can be
+    /// added to another dispatcher.
+    pub fn take_listeners(
+        &mut self,
+        event_key: &T,
+    ) -> Vec<Box<dyn AsyncListener<T> + Send + Sync + 'static>> {
+        self.remove_stopped();
+
+        self.events
+            .remove(&X::extract(event_key))
+            .unwrap_or_default()
+    }
+
+    /// Removes all listeners and returns them alongside the key they have
+    /// been registered by.
+    ///
+    /// Another dispatcher adds the listeners via its [`Extend`]-implementation.
+    pub fn drain(&mut self) -> Vec<(X::Key, Box<dyn AsyncListener<T> + Send + Sync + 'static>)> {
+        self.remove_stopped();
+
+        self.events
+            .drain()
+            .flat_map(|(event_key, listeners)| {
+                listeners
+                    .into_iter()
+                    .map(move |listener| (event_key.clone(), listener))
+            })
+            .collect()
+    }
+
+    /// Removes all listeners of `event_key` and returns how many live
+    /// listeners have been removed.
+    pub fn remove_all_listeners(&mut self, event_key: &T) -> usize {
+        self.remove_stopped();
+
+        self.events
+            .remove(&X::extract(event_key))
+            .map_or(0, |listeners| {
+                listeners
+                    .iter()
+                    .filter(|listener| listener.is_alive())
+                    .count()
+            })
+    }
+
+    /// Removes all listeners of all event-keys, keeping the dispatcher's
+    /// settings, e.g. its exclusion groups.
+    pub fn clear(&mut self) {
+        self.stopped.clear();
+        self.events.clear();
+    }
+
+    /// Removes all listeners that are no longer alive, e.g. listeners
+    /// created via [`weakly`](crate::fns::weakly) whose shared state has
+    /// been dropped, and returns how many have been removed.
+    ///
+    /// Adding a listener purges the dead listeners of its event-key,
+    /// calling this periodically bounds the memory of event-keys that
+    /// rarely see new listeners or events.
+    pub fn purge_dead(&mut self) -> usize {
+        self.remove_stopped();
+
+        let mut removed = 0;
+
+        for listeners in self.events.values_mut() {
+            let listener_count = listeners.len();
+
+            listeners.retain(AsyncListener::is_alive);
+            removed += listener_count - listeners.len();
+        }
+
+        removed
+    }
+
+    /// Sets hooks called right before and after each listener receives an
+    /// event, `after` receives the listener's request.
+    ///
+    /// Replaces previously set hooks, see the [`hooks`] module. The
+    /// after-hook is called once the listener's future completes, listeners
+    /// polled alongside each other may be surrounded by interleaved hooks.
+    pub fn set_listener_hooks<B, A>(&mut self, before: B, after: A)
+    where
+        B: Fn(&T, ListenerInfo<'_>) + Send + Sync + 'static,
+        A: Fn(&T, ListenerInfo<'_>, &Option<AsyncDispatchResult>) + Send + Sync + 'static,
+    {
+        self.listener_hooks = Some(ListenerHooks::new(before, after));
     }
 
     /// All [`AsyncListener`]s listening to a passed `event_identifier`
@@ -107,44 +469,324 @@ where
     /// with `AsyncDispatchResult::StopListening` will cause them
     /// to be removed from the event-dispatcher.
     ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel-safe, the returned future can be raced
+    /// against other futures, e.g. in `tokio::select!`.
+    /// Dropping it before completion drops the listeners' futures that are
+    /// still running, listeners that already requested to stop listening
+    /// are removed nonetheless.
+    ///
     /// [`AsyncListener`]: trait.AsyncListener.html
     /// [`on_event`]: trait.AsyncListener.html#tymethod.on_event
     /// [`AsyncDispatchResult`]: enum.AsyncDispatchResult.html
     /// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
-    pub async fn dispatch_event<'a>(&mut self, event_identifier: &T) {
-        if let Some(listeners) = self.events.get_mut(event_identifier) {
+    pub async fn dispatch_event(&mut self, event_identifier: &T) {
+        self.dispatch(&X::extract(event_identifier), || event_identifier)
+            .await;
+    }
+
+    /// Dispatches `event_identifier` like
+    /// [`dispatch_event`](Self::dispatch_event), returning the dispatch
+    /// alongside a [`DispatchHandle`] able to abort it from elsewhere,
+    /// e.g. from a shutdown-branch of a `tokio::select!`-loop.
+    ///
+    /// The dispatch is cancel-safe, see
+    /// [`dispatch_event`](Self::dispatch_event).
+    ///
+    /// # Errors
+    ///
+    /// The dispatch resolves to [`Error::DispatchAborted`] if it has been
+    /// aborted before completing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{sync::AsyncDispatcher, Error};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Saved,
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let mut dispatcher: AsyncDispatcher<Event> = AsyncDispatcher::new();
+    /// let (dispatch, handle) = dispatcher.dispatch_abortable(&Event::Saved);
+    ///
+    /// handle.abort();
+    ///
+    /// assert!(matches!(dispatch.await, Err(Error::DispatchAborted)));
+    /// # });
+    /// ```
+    pub fn dispatch_abortable<'a>(
+        &'a mut self,
+        event_identifier: &'a T,
+    ) -> (impl Future<Output = Result<(), Error>> + 'a, DispatchHandle) {
+        let (abort_handle, registration) = AbortHandle::new_pair();
+        let dispatch = Abortable::new(self.dispatch_event(event_identifier), registration);
+
+        (
+            async move { dispatch.await.map_err(|Aborted| Error::DispatchAborted) },
+            DispatchHandle(abort_handle),
+        )
+    }
+
+    /// All [`AsyncListener`]s listening to `event_key` will receive the
+    /// event constructed by `build_event`.
+    ///
+    /// `build_event` is only called if at least one [`AsyncListener`]
+    /// listens to `event_key`, expensive events will not be built for nobody.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::AsyncDispatcher;
+    /// use std::hash::{Hash, Hasher};
+    /// use std::mem::discriminant;
+    ///
+    /// #[derive(Clone)]
+    /// enum Event {
+    ///     Loaded(Vec<u8>),
+    /// }
+    ///
+    /// impl Hash for Event {
+    ///     fn hash<H: Hasher>(&self, _state: &mut H) {}
+    /// }
+    ///
+    /// impl PartialEq for Event {
+    ///     fn eq(&self, other: &Event) -> bool {
+    ///         discriminant(self) == discriminant(other)
+    ///     }
+    /// }
+    ///
+    /// impl Eq for Event {}
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let mut dispatcher: AsyncDispatcher<Event> = AsyncDispatcher::new();
+    ///
+    /// // Nobody listens, the closure will not be called.
+    /// dispatcher.dispatch_with(&Event::Loaded(Vec::new()), || unreachable!()).await;
+    /// # });
+    /// ```
+    ///
+    /// [`AsyncListener`]: AsyncListener
+    pub async fn dispatch_with<F>(&mut self, event_key: &T, build_event: F)
+    where
+        F: FnOnce() -> T,
+    {
+        self.dispatch(&X::extract(event_key), build_event).await;
+    }
+
+    /// Dispatches `event` to the listeners registered by `id`, without
+    /// deriving the key from `event`, e.g. for callers caching the id of an
+    /// [`Interned`](crate::key::Interned) name.
+    pub async fn dispatch_by_id(&mut self, id: KeyId, event: &T)
+    where
+        X: KeyExtract<T, Key = KeyId>,
+    {
+        self.dispatch(&id, || event).await;
+    }
+
+    /// Dispatches the event built by `build_event` to all listeners of
+    /// `event_key`, building it only if there is at least one listener.
+    async fn dispatch<E, F>(&mut self, event_key: &X::Key, build_event: F)
+    where
+        E: Borrow<T>,
+        F: FnOnce() -> E,
+    {
+        self.remove_stopped();
+
+        let Self {
+            events,
+            stopped,
+            listener_hooks,
+            ..
+        } = self;
+        let listener_hooks = listener_hooks.as_ref();
+
+        if let Some(listeners) = events.get_mut(event_key) {
+            if listeners.is_empty() {
+                return;
+            }
+
+            let event = build_event();
+            let event = event.borrow();
+
+            // A single listener needs neither polling alongside others nor
+            // collecting removals.
+            if let [listener] = listeners.as_slice() {
+                if matches!(
+                    hooks::surround_async(
+                        listener_hooks,
+                        event,
+                        listener.name(),
+                        listener.on_event(event)
+                    )
+                    .await,
+                    Some(AsyncDispatchResult::StopListening)
+                ) {
+                    listeners.clear();
+                }
+
+                return;
+            }
+
             let unordered_fut: FuturesUnordered<_> = FuturesUnordered::new();
 
             for (id, listener) in listeners.iter().enumerate() {
-                let item = async move { (id, listener.on_event(event_identifier).await) };
+                let item = async move {
+                    let request = hooks::surround_async(
+                        listener_hooks,
+                        event,
+                        listener.name(),
+                        listener.on_event(event),
+                    )
+                    .await;
+
+                    (id, request)
+                };
 
                 unordered_fut.push(item);
             }
 
-            let listeners_to_remove = Mutex::new(Vec::<usize>::new());
-
+            // Requests are recorded as they arrive, a dispatch dropped
+            // midway still removes listeners that asked to stop listening.
             unordered_fut
-                .for_each(|v| {
-                    if let Some(AsyncDispatchResult::StopListening) = v.1 {
-                        listeners_to_remove.lock().push(v.0);
+                .for_each(|(index, result)| {
+                    if matches!(result, Some(AsyncDispatchResult::StopListening)) {
+                        stopped.push((event_key.clone(), index));
                     }
 
                     futures::future::ready(())
                 })
                 .await;
+        }
 
-            listeners_to_remove.lock().iter().for_each(|index| {
-                listeners.swap_remove(*index);
-            });
+        self.remove_stopped();
+    }
+
+    /// Removes the listeners that requested to stop listening.
+    fn remove_stopped(&mut self) {
+        // Removing from the highest index downwards keeps the indices
+        // yet to be removed valid.
+        self.stopped
+            .sort_unstable_by_key(|&(_, index)| std::cmp::Reverse(index));
+
+        for (event_key, index) in self.stopped.drain(..) {
+            if let Some(listeners) = self.events.get_mut(&event_key) {
+                listeners.remove(index);
+            }
+        }
+    }
+}
+
+/// Adds listeners returned by [`AsyncDispatcher::drain`] by the key they
+/// have been registered by, migrating them between dispatchers keyed by
+/// other means than the event itself.
+impl<T, X> Extend<(X::Key, Box<dyn AsyncListener<T> + Send + Sync + 'static>)>
+    for AsyncDispatcher<T, X>
+where
+    T: Clone + Send + Sized + Sync + 'static,
+    X: KeyExtract<T>,
+    X::Key: Send + Sync,
+{
+    fn extend<I>(&mut self, listeners: I)
+    where
+        I: IntoIterator<Item = (X::Key, Box<dyn AsyncListener<T> + Send + Sync + 'static>)>,
+    {
+        for (event_key, listener) in listeners {
+            self.register(event_key, listener);
         }
     }
 }
 
-impl<T> Default for AsyncDispatcher<T>
+impl<T, X> Default for AsyncDispatcher<T, X>
 where
-    T: PartialEq + Eq + Hash + Clone + Send + Sized + Sync + 'static,
+    T: Clone + Send + Sized + Sync + 'static,
+    X: KeyExtract<T>,
+    X::Key: Send + Sync,
 {
     fn default() -> Self {
         Self::new()
     }
 }
+
+/// Aborts a dispatch started via
+/// [`AsyncDispatcher::dispatch_abortable`].
+#[derive(Clone, Debug)]
+pub struct DispatchHandle(AbortHandle);
+
+impl DispatchHandle {
+    /// Aborts the dispatch, listeners still running are dropped and the
+    /// dispatch resolves to [`Error::DispatchAborted`].
+    ///
+    /// Aborting a dispatch that has not been polled yet calls no listener.
+    pub fn abort(&self) {
+        self.0.abort();
+    }
+
+    /// Returns whether the dispatch has been aborted.
+    #[must_use]
+    pub fn is_aborted(&self) -> bool {
+        self.0.is_aborted()
+    }
+}
+
+/// Wraps a listener added to an [`AsyncDispatcher`], carrying the id it has
+/// been added under.
+struct Registered<L> {
+    id: ListenerId,
+    listener: L,
+}
+
+#[async_trait::async_trait]
+impl<T, L> AsyncListener<T> for Registered<L>
+where
+    T: Clone + Send + Sync + 'static,
+    L: AsyncListener<T> + Send + Sync,
+{
+    async fn on_event(&self, event: &T) -> Option<AsyncDispatchResult> {
+        self.listener.on_event(event).await
+    }
+
+    fn name(&self) -> &str {
+        self.listener.name()
+    }
+
+    fn listener_id(&self) -> Option<ListenerId> {
+        Some(self.id)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.listener.is_alive()
+    }
+}
+
+/// A listener waiting for a permit of its exclusion group before being
+/// called.
+struct Exclusive<L> {
+    semaphore: Arc<Semaphore>,
+    listener: L,
+}
+
+#[async_trait::async_trait]
+impl<T, L> AsyncListener<T> for Exclusive<L>
+where
+    T: Clone + Send + Sync + 'static,
+    L: AsyncListener<T> + Send + Sync,
+{
+    async fn on_event(&self, event: &T) -> Option<AsyncDispatchResult> {
+        // The semaphore is never closed, acquiring cannot fail.
+        let _permit = self.semaphore.acquire().await;
+
+        self.listener.on_event(event).await
+    }
+
+    fn name(&self) -> &str {
+        self.listener.name()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.listener.is_alive()
+    }
+}
@@ -0,0 +1,171 @@
+use super::{
+    super::key::{Identity, KeyExtract},
+    PriorityDispatcher,
+};
+use std::{
+    hash::Hash,
+    sync::mpsc::{self, Receiver, Sender},
+};
+
+/// Creates a [`Consumer`] owning a default [`PriorityDispatcher`] and an
+/// [`Emitter`] sending events to it.
+///
+/// Emitters can be cloned and sent to worker threads, while the consumer
+/// dispatches their events on its own thread, e.g. once per frame.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::sync::channel::channel_dispatcher;
+/// use std::{
+///     sync::{
+///         atomic::{AtomicUsize, Ordering},
+///         Arc,
+///     },
+///     thread,
+/// };
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Loaded,
+/// }
+///
+/// let loaded = Arc::new(AtomicUsize::new(0));
+/// let (emitter, mut consumer) = channel_dispatcher::<u32, Event>();
+///
+/// let counter = Arc::clone(&loaded);
+/// consumer.dispatcher_mut().add_fn(
+///     Event::Loaded,
+///     move |_event| {
+///         counter.fetch_add(1, Ordering::SeqCst);
+///
+///         None
+///     },
+///     1,
+/// );
+///
+/// thread::spawn(move || emitter.emit(Event::Loaded))
+///     .join()
+///     .expect("Worker panicked");
+///
+/// // Called by the frame-loop.
+/// assert_eq!(consumer.poll_dispatch(), 1);
+/// assert_eq!(loaded.load(Ordering::SeqCst), 1);
+/// ```
+#[must_use]
+pub fn channel_dispatcher<P, T>() -> (Emitter<T>, Consumer<P, T>)
+where
+    P: Ord + Clone,
+    T: Clone + Eq + Hash + Send + Sync + 'static,
+{
+    Consumer::new(PriorityDispatcher::default())
+}
+
+/// Sends events to a [`Consumer`], created via [`channel_dispatcher`] or
+/// [`Consumer::emitter`].
+///
+/// Emitters are [`Send`] and can be cloned, each worker thread may own one.
+pub struct Emitter<T> {
+    sender: Sender<T>,
+}
+
+impl<T> Emitter<T> {
+    /// Sends `event` to be dispatched by the next
+    /// [`Consumer::poll_dispatch`].
+    ///
+    /// Events emitted after the consumer has been dropped are discarded.
+    pub fn emit(&self, event: T) {
+        // A dropped consumer dispatches nothing anymore.
+        let _ = self.sender.send(event);
+    }
+}
+
+impl<T> Clone for Emitter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+/// Owns a [`PriorityDispatcher`] and dispatches the events sent via its
+/// [`Emitter`]s whenever polled.
+pub struct Consumer<P, T, X = Identity>
+where
+    P: Ord + Clone,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    dispatcher: PriorityDispatcher<P, T, X>,
+    sender: Sender<T>,
+    receiver: Receiver<T>,
+}
+
+impl<P, T, X> Consumer<P, T, X>
+where
+    P: Ord + Clone,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    /// Creates a consumer owning `dispatcher` and an [`Emitter`] sending
+    /// events to it.
+    #[must_use]
+    pub fn new(dispatcher: PriorityDispatcher<P, T, X>) -> (Emitter<T>, Self) {
+        let (sender, receiver) = mpsc::channel();
+        let consumer = Self {
+            dispatcher,
+            sender,
+            receiver,
+        };
+
+        (consumer.emitter(), consumer)
+    }
+
+    /// Creates another [`Emitter`] sending events to this consumer.
+    #[must_use]
+    pub fn emitter(&self) -> Emitter<T> {
+        Emitter {
+            sender: self.sender.clone(),
+        }
+    }
+
+    /// Returns the owned dispatcher.
+    #[must_use]
+    pub const fn dispatcher(&self) -> &PriorityDispatcher<P, T, X> {
+        &self.dispatcher
+    }
+
+    /// Returns the owned dispatcher, e.g. to add listeners.
+    pub fn dispatcher_mut(&mut self) -> &mut PriorityDispatcher<P, T, X> {
+        &mut self.dispatcher
+    }
+
+    /// Dispatches all events emitted until now in the order they have been
+    /// emitted in, without waiting for further events.
+    ///
+    /// Returns how many events have been dispatched.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as
+    /// [`PriorityDispatcher::dispatch_event`].
+    pub fn poll_dispatch(&mut self) -> usize {
+        // Events emitted during this poll are left to the next poll, keeping
+        // busy workers from stalling the frame.
+        let pending: Vec<T> = self.receiver.try_iter().collect();
+
+        for event in &pending {
+            self.dispatcher.dispatch_event(event);
+        }
+
+        pending.len()
+    }
+
+    /// Consumes the consumer, returning the owned dispatcher.
+    ///
+    /// Events not yet dispatched are discarded.
+    #[must_use]
+    pub fn into_dispatcher(self) -> PriorityDispatcher<P, T, X> {
+        self.dispatcher
+    }
+}
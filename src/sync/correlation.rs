@@ -0,0 +1,155 @@
+use std::{
+    cell::Cell,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// Source of event-ids, unique across all dispatchers of the process.
+static NEXT_EVENT_ID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    /// The correlation of the event whose listener runs on this thread.
+    static CURRENT: Cell<Option<Correlation>> = Cell::new(None);
+}
+
+/// Relates a dispatched event to the chain of events causing it.
+///
+/// Every event dispatched via a [`ParallelDispatcher`] gets a unique event-id.
+/// Events emitted via an [`Emitter`] while a listener is being dispatched to
+/// inherit the correlation-id of the event the listener received and
+/// record its event-id as their causation-id.
+/// Events dispatched or emitted from outside any listener start a new chain,
+/// their correlation-id is their own event-id.
+///
+/// Listeners obtain the correlation of the event they receive via
+/// [`Correlation::current`], e.g. to include it in their logs.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::sync::{correlation::Correlation, ParallelDispatcher};
+/// use std::sync::{Arc, Mutex};
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Hit,
+///     Die,
+/// }
+///
+/// let correlations = Arc::new(Mutex::new(Vec::new()));
+/// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+///     .expect("Failed to build threadpool");
+/// let emitter = dispatcher.emitter();
+///
+/// let hits = Arc::clone(&correlations);
+/// dispatcher.add_fn(Event::Hit, move |_event| {
+///     hits.lock().unwrap().push(Correlation::current().unwrap());
+///     emitter.emit(Event::Die);
+///
+///     None
+/// });
+/// let deaths = Arc::clone(&correlations);
+/// dispatcher.add_fn(Event::Die, move |_event| {
+///     deaths.lock().unwrap().push(Correlation::current().unwrap());
+///
+///     None
+/// });
+///
+/// dispatcher.dispatch_event(&Event::Hit);
+///
+/// let correlations = correlations.lock().unwrap();
+/// assert_eq!(correlations[1].correlation_id(), correlations[0].correlation_id());
+/// assert_eq!(correlations[1].causation_id(), Some(correlations[0].event_id()));
+/// ```
+///
+/// [`ParallelDispatcher`]: super::ParallelDispatcher
+/// [`Emitter`]: super::Emitter
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Correlation {
+    chain: u64,
+    event: u64,
+    cause: Option<u64>,
+}
+
+impl Correlation {
+    /// Returns the correlation of the event the listener running on the
+    /// calling thread receives, `None` outside of listeners.
+    #[must_use]
+    pub fn current() -> Option<Self> {
+        CURRENT.with(Cell::get)
+    }
+
+    /// Returns the id shared by all events of a causal chain, the event-id
+    /// of the chain's first event.
+    #[must_use]
+    pub const fn correlation_id(&self) -> u64 {
+        self.chain
+    }
+
+    /// Returns the id of the event itself.
+    #[must_use]
+    pub const fn event_id(&self) -> u64 {
+        self.event
+    }
+
+    /// Returns the event-id of the event whose listener emitted the event,
+    /// `None` for the first event of a chain.
+    #[must_use]
+    pub const fn causation_id(&self) -> Option<u64> {
+        self.cause
+    }
+
+    /// Creates the correlation of an event caused by `cause`, starting a new
+    /// chain if there is no cause.
+    pub(super) fn caused_by(cause: Option<Self>) -> Self {
+        let event = NEXT_EVENT_ID.fetch_add(1, Ordering::Relaxed);
+
+        cause.map_or(
+            Self {
+                chain: event,
+                event,
+                cause: None,
+            },
+            |cause| Self {
+                chain: cause.chain,
+                event,
+                cause: Some(cause.event),
+            },
+        )
+    }
+
+    /// Makes this the current correlation of the calling thread until the
+    /// returned guard is dropped.
+    pub(super) fn enter(self) -> Entered {
+        Entered {
+            previous: CURRENT.with(|current| current.replace(Some(self))),
+        }
+    }
+}
+
+impl Display for Correlation {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            formatter,
+            "correlation {}, event {}",
+            self.chain, self.event
+        )?;
+
+        if let Some(cause) = self.cause {
+            write!(formatter, ", caused by {}", cause)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Restores the previous correlation of the thread once dropped.
+pub(super) struct Entered {
+    previous: Option<Correlation>,
+}
+
+impl Drop for Entered {
+    fn drop(&mut self) {
+        CURRENT.with(|current| current.set(self.previous));
+    }
+}
@@ -0,0 +1,242 @@
+use super::{
+    super::key::{Identity, KeyExtract},
+    PriorityDispatcherResult, PriorityListener,
+};
+use arc_swap::ArcSwap;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+type SharedListener<T> = Arc<dyn PriorityListener<T> + Send + Sync + 'static>;
+type ListenerList<P, T> = Arc<[(P, SharedListener<T>)]>;
+
+/// In charge of prioritised sync dispatching, registering and dispatching
+/// without locking.
+///
+/// Each event-key's listeners are kept as an immutable list ordered by
+/// priority-level.
+/// Dispatching iterates the lists current at that moment, while adding or
+/// removing a listener copies the affected list and atomically swaps it in.
+/// Hence, both take `&self` and a dispatcher shared via [`Arc`] accepts
+/// listeners at runtime, unlike a [`FrozenDispatcher`].
+///
+/// Copying makes registration costlier than on a [`PriorityDispatcher`],
+/// favouring dispatchers dispatching far more often than registering.
+///
+/// `X` is the [`KeyExtract`]-strategy deriving the key listeners are
+/// registered by from events, by default the event itself.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::sync::{CowDispatcher, PriorityDispatcherResult, PriorityListener};
+/// use std::{sync::Arc, thread};
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Tick,
+/// }
+///
+/// struct Physics;
+///
+/// impl PriorityListener<Event> for Physics {
+///     fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> { None }
+/// }
+///
+/// let dispatcher: Arc<CowDispatcher<u32, Event>> = Arc::new(CowDispatcher::new());
+/// let shared = Arc::clone(&dispatcher);
+///
+/// let ticker = thread::spawn(move || shared.dispatch_event(&Event::Tick));
+///
+/// // Registering does not block the dispatching thread.
+/// dispatcher.add_listener(Event::Tick, Physics, 1);
+/// ticker.join().unwrap();
+/// ```
+///
+/// [`FrozenDispatcher`]: super::FrozenDispatcher
+/// [`PriorityDispatcher`]: super::PriorityDispatcher
+pub struct CowDispatcher<P, T, X = Identity>
+where
+    P: Ord + Clone,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    events: ArcSwap<HashMap<X::Key, ListenerList<P, T>>>,
+}
+
+impl<P, T, X> CowDispatcher<P, T, X>
+where
+    P: Ord + Clone,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    /// Create a new copy-on-write dispatcher.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            events: ArcSwap::from_pointee(HashMap::new()),
+        }
+    }
+
+    /// Adds a [`PriorityListener`] to listen for an `event_key`, ordered by
+    /// `priority` among the other listeners of `event_key`.
+    ///
+    /// Listeners of equal priority-level are dispatched to in the order
+    /// they have been added.
+    // Taking `priority` by value mirrors `PriorityDispatcher::add_listener`.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn add_listener<D: PriorityListener<T> + Send + Sync + 'static>(
+        &self,
+        event_key: T,
+        listener: D,
+        priority: P,
+    ) {
+        let event_key = X::extract(&event_key);
+        let listener: SharedListener<T> = Arc::new(listener);
+
+        self.events.rcu(|events| {
+            let mut events = HashMap::clone(events);
+            let mut listeners = events
+                .get(&event_key)
+                .map(|listeners| listeners.to_vec())
+                .unwrap_or_default();
+            let index = listeners.partition_point(|(other, _)| *other <= priority);
+
+            listeners.insert(index, (priority.clone(), Arc::clone(&listener)));
+            events.insert(event_key.clone(), listeners.into());
+
+            events
+        });
+    }
+
+    /// All [`PriorityListener`]s listening to `event_identifier` will
+    /// receive it, ordered by their priority-level.
+    ///
+    /// Listeners requesting to stop listening are removed once the dispatch
+    /// is done, they keep receiving events dispatched concurrently until
+    /// then.
+    pub fn dispatch_event(&self, event_identifier: &T) {
+        let event_key = X::extract(event_identifier);
+        let listeners = match self.events.load().get(&event_key) {
+            Some(listeners) => Arc::clone(listeners),
+            None => return,
+        };
+        let mut listeners_to_remove = Vec::new();
+
+        for (_, listener) in listeners.iter() {
+            let request = listener.on_event(event_identifier);
+
+            if matches!(
+                request,
+                Some(
+                    PriorityDispatcherResult::StopListening
+                        | PriorityDispatcherResult::StopListeningAndPropagation
+                )
+            ) {
+                listeners_to_remove.push(listener);
+            }
+
+            if matches!(
+                request,
+                Some(
+                    PriorityDispatcherResult::StopPropagation
+                        | PriorityDispatcherResult::StopListeningAndPropagation
+                )
+            ) {
+                break;
+            }
+        }
+
+        if !listeners_to_remove.is_empty() {
+            self.remove_listeners(&event_key, &listeners_to_remove);
+        }
+    }
+
+    /// Returns how many listeners listen for `event_key` across all
+    /// priority-levels.
+    #[must_use]
+    pub fn listener_count(&self, event_key: &T) -> usize {
+        self.events
+            .load()
+            .get(&X::extract(event_key))
+            .map_or(0, |listeners| {
+                listeners
+                    .iter()
+                    .filter(|(_, listener)| listener.is_alive())
+                    .count()
+            })
+    }
+
+    /// Returns how many listeners listen for any event-key.
+    #[must_use]
+    pub fn total_listeners(&self) -> usize {
+        self.events
+            .load()
+            .values()
+            .flat_map(|listeners| listeners.iter())
+            .filter(|(_, listener)| listener.is_alive())
+            .count()
+    }
+
+    /// Returns the event-keys at least one listener listens for.
+    #[must_use]
+    pub fn event_keys(&self) -> HashSet<X::Key> {
+        self.events
+            .load()
+            .iter()
+            .filter(|(_, listeners)| listeners.iter().any(|(_, listener)| listener.is_alive()))
+            .map(|(event_key, _)| event_key.clone())
+            .collect()
+    }
+
+    /// Returns whether no listener listens for any event-key.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        !self
+            .events
+            .load()
+            .values()
+            .flat_map(|listeners| listeners.iter())
+            .any(|(_, listener)| listener.is_alive())
+    }
+
+    /// Removes `removed` from the listeners of `event_key`.
+    fn remove_listeners(&self, event_key: &X::Key, removed: &[&SharedListener<T>]) {
+        let is_removed = |listener: &SharedListener<T>| {
+            removed
+                .iter()
+                .any(|removed| same_listener(removed, listener))
+        };
+
+        self.events.rcu(|events| {
+            let mut events = HashMap::clone(events);
+
+            if let Some(listeners) = events.get_mut(event_key) {
+                *listeners = listeners
+                    .iter()
+                    .filter(|(_, listener)| !is_removed(listener))
+                    .cloned()
+                    .collect();
+            }
+
+            events
+        });
+    }
+}
+
+impl<P, T, X> Default for CowDispatcher<P, T, X>
+where
+    P: Ord + Clone,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether `a` and `b` share the same allocation, ignoring vtables.
+fn same_listener<T>(a: &SharedListener<T>, b: &SharedListener<T>) -> bool {
+    Arc::as_ptr(a).cast::<()>() == Arc::as_ptr(b).cast::<()>()
+}
@@ -0,0 +1,171 @@
+use super::{
+    super::{
+        key::{Identity, KeyExtract},
+        panic_policy::PanicPolicy,
+    },
+    priority_dispatcher::PriorityListenerMap,
+    PriorityDispatcherResult, PriorityListener,
+};
+use std::{
+    borrow::Borrow,
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+};
+
+/// An immutable [`PriorityDispatcher`], dispatching without any locking.
+///
+/// Created via [`PriorityDispatcher::freeze`], no listeners can be added
+/// or removed afterwards.
+/// Cloning shares the listeners, dispatching takes `&self`, hence one
+/// frozen dispatcher can be dispatched on from many threads at once.
+///
+/// **Note**: As listeners cannot be removed, requests to stop listening
+/// are ignored, requests to stop propagation are honoured.
+///
+/// [`PriorityDispatcher`]: super::PriorityDispatcher
+/// [`PriorityDispatcher::freeze`]: super::PriorityDispatcher::freeze
+pub struct FrozenDispatcher<P, T, X = Identity>
+where
+    P: Ord,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    events: Arc<PriorityListenerMap<X::Key, P, T>>,
+    panic_policy: Arc<PanicPolicy>,
+}
+
+impl<P, T, X> FrozenDispatcher<P, T, X>
+where
+    P: Ord,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    /// Freezes `events`, handling panics of listeners per `panic_policy`.
+    pub(crate) fn new(
+        events: PriorityListenerMap<X::Key, P, T>,
+        panic_policy: PanicPolicy,
+    ) -> Self {
+        Self {
+            events: Arc::new(events),
+            panic_policy: Arc::new(panic_policy),
+        }
+    }
+
+    /// All [`PriorityListener`]s listening to `event_identifier` will
+    /// receive it, ordered by their priority-level.
+    pub fn dispatch_event(&self, event_identifier: &T) {
+        self.dispatch(&X::extract(event_identifier), || event_identifier);
+    }
+
+    /// All [`PriorityListener`]s listening to `event_key` will receive the
+    /// event constructed by `build_event`, ordered by their priority-level.
+    ///
+    /// `build_event` is only called if at least one [`PriorityListener`]
+    /// listens to `event_key`.
+    pub fn dispatch_with<F>(&self, event_key: &T, build_event: F)
+    where
+        F: FnOnce() -> T,
+    {
+        self.dispatch(&X::extract(event_key), build_event);
+    }
+
+    /// Returns how many listeners listen for `event_key` across all
+    /// priority-levels.
+    #[must_use]
+    pub fn listener_count(&self, event_key: &T) -> usize {
+        self.events
+            .get(&X::extract(event_key))
+            .into_iter()
+            .flat_map(BTreeMap::values)
+            .flatten()
+            .filter(|listener| listener.is_alive())
+            .count()
+    }
+
+    /// Returns how many listeners listen for any event-key.
+    #[must_use]
+    pub fn total_listeners(&self) -> usize {
+        self.events
+            .values()
+            .flat_map(BTreeMap::values)
+            .flatten()
+            .filter(|listener| listener.is_alive())
+            .count()
+    }
+
+    /// Returns the event-keys at least one listener listens for.
+    #[must_use]
+    pub fn event_keys(&self) -> HashSet<X::Key> {
+        self.events
+            .iter()
+            .filter(|(_, prioritised_listeners)| {
+                prioritised_listeners
+                    .values()
+                    .flatten()
+                    .any(PriorityListener::is_alive)
+            })
+            .map(|(event_key, _)| event_key.clone())
+            .collect()
+    }
+
+    /// Returns whether no listener listens for any event-key.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        !self
+            .events
+            .values()
+            .flat_map(BTreeMap::values)
+            .flatten()
+            .any(PriorityListener::is_alive)
+    }
+
+    /// Dispatches the event built by `build_event` to all listeners of
+    /// `event_key`, building it only if there is at least one listener.
+    fn dispatch<E, F>(&self, event_key: &X::Key, build_event: F)
+    where
+        E: Borrow<T>,
+        F: FnOnce() -> E,
+    {
+        let prioritised_listener_collection = match self.events.get(event_key) {
+            Some(collection) if collection.values().any(|listeners| !listeners.is_empty()) => {
+                collection
+            }
+            _ => return,
+        };
+
+        let event = build_event();
+        let event = event.borrow();
+
+        for listener in prioritised_listener_collection.values().flatten() {
+            let request = self.panic_policy.guard(
+                listener.name(),
+                PriorityDispatcherResult::StopListening,
+                || listener.on_event(event),
+            );
+
+            if matches!(
+                request,
+                Some(
+                    PriorityDispatcherResult::StopPropagation
+                        | PriorityDispatcherResult::StopListeningAndPropagation
+                )
+            ) {
+                return;
+            }
+        }
+    }
+}
+
+impl<P, T, X> Clone for FrozenDispatcher<P, T, X>
+where
+    P: Ord,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            events: Arc::clone(&self.events),
+            panic_policy: Arc::clone(&self.panic_policy),
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use super::{
+    super::handle::ListenerId, PriorityDispatcher, PriorityDispatcherResult, PriorityListener,
+};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    hash::Hash,
+    marker::PhantomData,
+};
+
+/// Identifies who registered a listener, e.g. a plugin, to remove all of
+/// its listeners at once.
+///
+/// Listeners are registered under an owner by wrapping them in an
+/// [`Owned`], see [`Hub::remove_owner`] and
+/// [`PriorityDispatcher::remove_owner`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Owner(u64);
+
+impl Owner {
+    /// Creates the owner identified by `id`.
+    #[must_use]
+    pub const fn new(id: u64) -> Self {
+        Self(id)
+    }
+
+    /// Returns the id identifying the owner.
+    #[must_use]
+    pub const fn id(self) -> u64 {
+        self.0
+    }
+}
+
+/// Wraps a listener registered under an [`Owner`].
+pub struct Owned<L> {
+    owner: Owner,
+    listener: L,
+}
+
+impl<L> Owned<L> {
+    /// Wraps `listener` to be registered under `owner`.
+    #[must_use]
+    pub const fn new(owner: Owner, listener: L) -> Self {
+        Self { owner, listener }
+    }
+}
+
+impl<T, L> PriorityListener<T> for Owned<L>
+where
+    T: Clone + Send + Sync + 'static,
+    L: PriorityListener<T>,
+{
+    fn on_event(&self, event: &T) -> Option<PriorityDispatcherResult> {
+        self.listener.on_event(event)
+    }
+
+    fn name(&self) -> &str {
+        self.listener.name()
+    }
+
+    fn requires_order(&self) -> bool {
+        self.listener.requires_order()
+    }
+
+    fn owner(&self) -> Option<Owner> {
+        Some(self.owner)
+    }
+
+    fn listener_id(&self) -> Option<ListenerId> {
+        self.listener.listener_id()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.listener.is_alive()
+    }
+}
+
+/// A [`PriorityDispatcher`] of any event type.
+trait AnyDispatcher: Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    fn remove_owner(&mut self, owner: Owner) -> usize;
+
+    fn remove_listener(&mut self, id: ListenerId) -> bool;
+}
+
+impl<P, T> AnyDispatcher for PriorityDispatcher<P, T>
+where
+    P: Ord + Clone + Send + Sync + 'static,
+    T: Clone + Eq + Hash + Send + Sync + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn remove_owner(&mut self, owner: Owner) -> usize {
+        Self::remove_owner(self, owner)
+    }
+
+    fn remove_listener(&mut self, id: ListenerId) -> bool {
+        Self::remove_listener(self, id)
+    }
+}
+
+/// Owns one [`PriorityDispatcher`] per event type, registering and
+/// dispatching events of any type through a single object.
+///
+/// Each event type gets its dispatcher on first use, `P` is the priority
+/// shared by all of them.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::sync::Hub;
+/// use std::sync::{
+///     atomic::{AtomicUsize, Ordering},
+///     Arc,
+/// };
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum InputEvent {
+///     KeyPressed,
+/// }
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum PhysicsEvent {
+///     Collision,
+/// }
+///
+/// let collisions = Arc::new(AtomicUsize::new(0));
+/// let mut hub: Hub<u32> = Hub::new();
+///
+/// hub.add_fn(InputEvent::KeyPressed, |_| None, 1);
+///
+/// let counter = Arc::clone(&collisions);
+/// hub.add_fn(
+///     PhysicsEvent::Collision,
+///     move |_| {
+///         counter.fetch_add(1, Ordering::SeqCst);
+///
+///         None
+///     },
+///     1,
+/// );
+///
+/// hub.dispatch_event(&PhysicsEvent::Collision);
+///
+/// assert_eq!(collisions.load(Ordering::SeqCst), 1);
+/// ```
+pub struct Hub<P>
+where
+    P: Ord + Clone + Send + Sync + 'static,
+{
+    dispatchers: HashMap<TypeId, Box<dyn AnyDispatcher>>,
+    _priority: PhantomData<fn() -> P>,
+}
+
+impl<P> Hub<P>
+where
+    P: Ord + Clone + Send + Sync + 'static,
+{
+    /// Creates a hub without any dispatchers.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            dispatchers: HashMap::new(),
+            _priority: PhantomData,
+        }
+    }
+
+    /// Returns the dispatcher of events of type `T`, if any listener of `T`
+    /// has been added yet.
+    #[must_use]
+    pub fn dispatcher<T>(&self) -> Option<&PriorityDispatcher<P, T>>
+    where
+        T: Clone + Eq + Hash + Send + Sync + 'static,
+    {
+        self.dispatchers
+            .get(&TypeId::of::<T>())
+            .and_then(|dispatcher| dispatcher.as_any().downcast_ref())
+    }
+
+    /// Returns the dispatcher of events of type `T`, creating it if
+    /// necessary, e.g. to configure it.
+    // Dispatchers are stored by the type-id of their event type, downcasting
+    // cannot fail.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn dispatcher_mut<T>(&mut self) -> &mut PriorityDispatcher<P, T>
+    where
+        T: Clone + Eq + Hash + Send + Sync + 'static,
+    {
+        self.dispatchers
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(PriorityDispatcher::<P, T>::default()))
+            .as_any_mut()
+            .downcast_mut()
+            .expect("Dispatchers are stored by the type-id of their event type")
+    }
+
+    /// Adds a [`PriorityListener`] to listen for `event_key` on the
+    /// dispatcher of `T`, see [`PriorityDispatcher::add_listener`].
+    pub fn add_listener<T>(
+        &mut self,
+        event_key: T,
+        listener: impl PriorityListener<T> + Send + Sync + 'static,
+        priority: P,
+    ) -> ListenerId
+    where
+        T: Clone + Eq + Hash + Send + Sync + 'static,
+    {
+        self.dispatcher_mut::<T>()
+            .add_listener(event_key, listener, priority)
+    }
+
+    /// Adds a closure to listen for `event_key` on the dispatcher of `T`,
+    /// see [`PriorityDispatcher::add_fn`].
+    pub fn add_fn<T, F>(&mut self, event_key: T, listener: F, priority: P) -> ListenerId
+    where
+        T: Clone + Eq + Hash + Send + Sync + 'static,
+        F: Fn(&T) -> Option<PriorityDispatcherResult> + Send + Sync + 'static,
+    {
+        self.dispatcher_mut::<T>()
+            .add_fn(event_key, listener, priority)
+    }
+
+    /// Adds a [`PriorityListener`] to listen for `event_key` on the
+    /// dispatcher of `T`, registered under `owner`.
+    pub fn add_owned_listener<T>(
+        &mut self,
+        owner: Owner,
+        event_key: T,
+        listener: impl PriorityListener<T> + Send + Sync + 'static,
+        priority: P,
+    ) -> ListenerId
+    where
+        T: Clone + Eq + Hash + Send + Sync + 'static,
+    {
+        self.add_listener(event_key, Owned::new(owner, listener), priority)
+    }
+
+    /// Removes the listener added under `id` from the dispatcher of
+    /// whichever event type it listens to, returns whether it has been
+    /// found.
+    pub fn remove_listener(&mut self, id: ListenerId) -> bool {
+        self.dispatchers
+            .values_mut()
+            .any(|dispatcher| dispatcher.remove_listener(id))
+    }
+
+    /// Removes all listeners registered under `owner` from the dispatchers
+    /// of all event types and returns how many have been removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{Hub, Owner, PriorityDispatcherResult, PriorityListener};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum InputEvent {
+    ///     KeyPressed,
+    /// }
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum PhysicsEvent {
+    ///     Collision,
+    /// }
+    ///
+    /// struct Plugin;
+    ///
+    /// impl PriorityListener<InputEvent> for Plugin {
+    ///     fn on_event(&self, _event: &InputEvent) -> Option<PriorityDispatcherResult> { None }
+    /// }
+    ///
+    /// impl PriorityListener<PhysicsEvent> for Plugin {
+    ///     fn on_event(&self, _event: &PhysicsEvent) -> Option<PriorityDispatcherResult> { None }
+    /// }
+    ///
+    /// let plugin = Owner::new(1);
+    /// let mut hub: Hub<u32> = Hub::new();
+    ///
+    /// hub.add_owned_listener(plugin, InputEvent::KeyPressed, Plugin, 1);
+    /// hub.add_owned_listener(plugin, PhysicsEvent::Collision, Plugin, 1);
+    ///
+    /// // Unloading the plugin.
+    /// assert_eq!(hub.remove_owner(plugin), 2);
+    /// ```
+    pub fn remove_owner(&mut self, owner: Owner) -> usize {
+        self.dispatchers
+            .values_mut()
+            .map(|dispatcher| dispatcher.remove_owner(owner))
+            .sum()
+    }
+
+    /// Dispatches `event_identifier` on the dispatcher of `T`, see
+    /// [`PriorityDispatcher::dispatch_event`].
+    ///
+    /// Events of a type without listeners are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as
+    /// [`PriorityDispatcher::dispatch_event`].
+    pub fn dispatch_event<T>(&mut self, event_identifier: &T)
+    where
+        T: Clone + Eq + Hash + Send + Sync + 'static,
+    {
+        if let Some(dispatcher) =
+            self.dispatchers
+                .get_mut(&TypeId::of::<T>())
+                .and_then(|dispatcher| {
+                    dispatcher
+                        .as_any_mut()
+                        .downcast_mut::<PriorityDispatcher<P, T>>()
+                })
+        {
+            dispatcher.dispatch_event(event_identifier);
+        }
+    }
+}
+
+impl<P> Default for Hub<P>
+where
+    P: Ord + Clone + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
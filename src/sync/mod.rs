@@ -1,25 +1,67 @@
+#[cfg(all(feature = "priority", feature = "parallel"))]
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 #[cfg(feature = "parallel")]
 use rayon::ThreadPool;
-use std::hash::Hash;
 
+#[cfg(any(feature = "async", feature = "parallel", feature = "priority"))]
+use super::handle::ListenerId;
+
+#[cfg(feature = "parallel")]
+/// This module contains listeners pinned to their owning thread.
+pub mod affinity;
 #[cfg(feature = "async")]
 /// This module contains the async dispatcher.
 pub mod async_dispatcher;
+#[cfg(feature = "priority")]
+/// This module contains the producer/consumer dispatcher.
+pub mod channel;
+#[cfg(feature = "parallel")]
+/// This module contains the correlation of events across follow-up events.
+pub mod correlation;
+#[cfg(all(feature = "priority", feature = "arc-swap"))]
+/// This module contains the copy-on-write dispatcher.
+pub mod cow_dispatcher;
+#[cfg(feature = "priority")]
+/// This module contains the frozen dispatcher.
+pub mod frozen_dispatcher;
+#[cfg(feature = "priority")]
+/// This module contains the hub owning a dispatcher per event type.
+pub mod hub;
 #[cfg(feature = "parallel")]
 /// This module contains the parallel dispatcher.
 pub mod parallel_dispatcher;
-#[cfg(feature = "parallel")]
+#[cfg(feature = "priority")]
 /// This module contains the priority dispatcher.
 pub mod priority_dispatcher;
+#[cfg(feature = "priority")]
+/// This module contains the listener registry.
+pub mod registry;
+#[cfg(feature = "priority")]
+/// This module contains the dispatch-only dispatcher view.
+pub mod view;
 
 #[cfg(feature = "async")]
-pub use async_dispatcher::AsyncDispatcher;
-#[cfg(feature = "parallel")]
-pub use parallel_dispatcher::ParallelDispatcher;
+pub use async_dispatcher::{AsyncDispatcher, DiscriminantAsyncDispatcher, DispatchHandle};
+#[cfg(all(feature = "priority", feature = "arc-swap"))]
+pub use cow_dispatcher::CowDispatcher;
+#[cfg(feature = "priority")]
+pub use frozen_dispatcher::FrozenDispatcher;
+#[cfg(feature = "priority")]
+pub use hub::{Hub, Owner};
 #[cfg(feature = "parallel")]
-pub use priority_dispatcher::PriorityDispatcher;
+pub use parallel_dispatcher::{
+    Completion, DiscriminantParallelDispatcher, DispatchSummary, Emitter, MainThreadQueue,
+    ParallelDispatcher, Saturation,
+};
+#[cfg(feature = "priority")]
+pub use priority_dispatcher::{DiscriminantPriorityDispatcher, PriorityDispatcher};
+#[cfg(feature = "priority")]
+pub use registry::ListenerRegistry;
+#[cfg(feature = "priority")]
+pub use view::DispatcherView;
 
 /// An `enum` returning a request from a listener to its `sync` event-dispatcher.
+///
 /// This `enum` is not restricted to dispatcher residing in the `sync`-module.
 /// A request will be processed by the event-dispatcher depending on the variant:
 ///
@@ -30,12 +72,12 @@ pub use priority_dispatcher::PriorityDispatcher;
 ///
 /// `StopListeningAndPropagation` a combination of first `StopListening`
 /// and then `StopPropagation`.
-#[cfg(feature = "parallel")]
+#[cfg(feature = "priority")]
 #[derive(Debug)]
 // Clippy complains that all variants have the same prefix.
 // However, the term `Stop` is an essential component for the meaning of each
 // variant's name.
-#[allow(clippy::pub_enum_variant_names)]
+#[allow(clippy::enum_variant_names)]
 pub enum PriorityDispatcherResult {
     /// Stops listening to the dispatcher.
     StopListening,
@@ -50,7 +92,7 @@ pub enum PriorityDispatcherResult {
 /// this `enum` informs on whether the return is early
 /// and thus forcefully stopped or finished on its own.
 #[derive(Debug)]
-#[cfg(feature = "parallel")]
+#[cfg(feature = "priority")]
 pub(crate) enum ExecuteRequestsResult {
     Finished,
     Stopped,
@@ -62,7 +104,7 @@ pub(crate) enum ExecuteRequestsResult {
 #[cfg(feature = "parallel")]
 pub trait Listener<T>
 where
-    T: PartialEq + Eq + Hash + Clone + 'static,
+    T: Clone + 'static,
 {
     /// This function will be called once a listened
     /// event-type `T` has been dispatched.
@@ -80,8 +122,7 @@ where
 /// then execute `StopPropagation`.
 ///
 /// **Note**: When `StopListening` is being executed,
-/// removal of items from `vec` will swap elements,
-/// resulting in a different order.
+/// removal of items from `vec` keeps the order of the remaining items.
 ///
 /// **Note**: Unlike [`retain`], `execute_sync_dispatcher_requests`
 /// can stop the current iteration and is able to match [`ParallelDispatchResult`]
@@ -89,7 +130,7 @@ where
 ///
 /// [`retain`]: https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.retain
 /// [`ParallelDispatchResult`]: enum.ParallelDispatchResult.html
-#[cfg(feature = "parallel")]
+#[cfg(feature = "priority")]
 pub(crate) fn execute_sync_dispatcher_requests<T, F>(
     vec: &mut Vec<T>,
     mut function: F,
@@ -104,13 +145,13 @@ where
             match function(&vec[index]) {
                 None => index += 1,
                 Some(PriorityDispatcherResult::StopListening) => {
-                    vec.swap_remove(index);
+                    vec.remove(index);
                 }
                 Some(PriorityDispatcherResult::StopPropagation) => {
                     return ExecuteRequestsResult::Stopped
                 }
                 Some(PriorityDispatcherResult::StopListeningAndPropagation) => {
-                    vec.swap_remove(index);
+                    vec.remove(index);
                     return ExecuteRequestsResult::Stopped;
                 }
             }
@@ -120,7 +161,47 @@ where
     }
 }
 
-#[cfg(test)]
+/// Applies `function` to every element of `vec` in parallel, then executes
+/// the returned requests like [`execute_sync_dispatcher_requests`].
+///
+/// **Note**: As all elements have been called already, `StopPropagation`
+/// merely reports the iteration as stopped.
+#[cfg(all(feature = "priority", feature = "parallel"))]
+pub(crate) fn execute_parallel_dispatcher_requests<T, F>(
+    vec: &mut Vec<T>,
+    function: F,
+) -> ExecuteRequestsResult
+where
+    T: Sync,
+    F: Fn(&T) -> Option<PriorityDispatcherResult> + Send + Sync,
+{
+    let requests: Vec<_> = vec.par_iter().map(function).collect();
+    let mut requests = requests.into_iter();
+    let mut is_stopped = false;
+
+    vec.retain(|_| match requests.next().flatten() {
+        None => true,
+        Some(PriorityDispatcherResult::StopListening) => false,
+        Some(PriorityDispatcherResult::StopPropagation) => {
+            is_stopped = true;
+
+            true
+        }
+        Some(PriorityDispatcherResult::StopListeningAndPropagation) => {
+            is_stopped = true;
+
+            false
+        }
+    });
+
+    if is_stopped {
+        ExecuteRequestsResult::Stopped
+    } else {
+        ExecuteRequestsResult::Finished
+    }
+}
+
+#[cfg(all(test, feature = "priority"))]
 mod tests {
     use super::*;
 
@@ -144,7 +225,7 @@ mod tests {
             let mut vec = vec![0, 0, 0, 1, 1, 1, 1];
             execute_sync_dispatcher_requests(&mut vec, map_usize_to_request);
 
-            assert_eq!(vec, [1, 0, 0, 1, 1, 1]);
+            assert_eq!(vec, [1, 1, 1, 1]);
         }
 
         #[test]
@@ -201,6 +282,21 @@ pub enum AsyncDispatchResult {
     StopListening,
 }
 
+/// Events implementing this trait name their own priority, deciding the
+/// order buffered events are dispatched in.
+///
+/// Like listeners of a `PriorityDispatcher`, events with a lower
+/// priority-level are dispatched first, see
+/// [`ParallelDispatcher::prioritise_emitted`].
+#[cfg(feature = "parallel")]
+pub trait EventPriority<P>
+where
+    P: Ord,
+{
+    /// Returns the priority-level of the event.
+    fn priority(&self) -> P;
+}
+
 /// Every event-receiver needs to implement this trait
 /// in order to receive dispatched events.
 /// `T` being the type you use for events, e.g. an `Enum`.
@@ -208,13 +304,64 @@ pub enum AsyncDispatchResult {
 #[async_trait::async_trait]
 pub trait AsyncListener<T>
 where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    T: Clone + Send + Sync + 'static,
 {
     /// This function will be called once a listened
     /// event-type `T` has been dispatched.
     /// If you want to mutate the listener, consider wrapping it behind an
     /// `RwLock` or `Mutex`.
     async fn on_event(&self, event: &T) -> Option<AsyncDispatchResult>;
+
+    /// Returns the listener's name, used to describe the listener
+    /// in a [`Snapshot`](crate::snapshot::Snapshot).
+    ///
+    /// Defaults to the listener's type name.
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Returns the [`ListenerId`] the listener has been registered under
+    /// by the dispatcher it has been added to.
+    ///
+    /// Only meant for dispatchers, which assign ids by wrapping the
+    /// listeners they are given, overriding it has no effect.
+    #[doc(hidden)]
+    fn listener_id(&self) -> Option<ListenerId> {
+        None
+    }
+
+    /// Returns whether the listener can still receive events, listeners
+    /// that are no longer alive are purged by their dispatcher's
+    /// `purge_dead` and whenever a listener is added to their event-key.
+    ///
+    /// Defaults to `true`, see [`weakly`](crate::fns::weakly).
+    fn is_alive(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<T, L> AsyncListener<T> for Box<L>
+where
+    T: Clone + Send + Sync + 'static,
+    L: AsyncListener<T> + Send + Sync + ?Sized,
+{
+    async fn on_event(&self, event: &T) -> Option<AsyncDispatchResult> {
+        (**self).on_event(event).await
+    }
+
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn listener_id(&self) -> Option<ListenerId> {
+        (**self).listener_id()
+    }
+
+    fn is_alive(&self) -> bool {
+        (**self).is_alive()
+    }
 }
 
 /// Every event-receiver needs to implement this trait
@@ -223,26 +370,151 @@ where
 #[cfg(feature = "parallel")]
 pub trait ParallelListener<T>
 where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    T: Clone + Send + Sync + 'static,
 {
     /// This function will be called once a listened
     /// event-type `T` has been dispatched.
     /// If you want to mutate the listener, consider wrapping it behind an
     /// `RwLock` or `Mutex`.
     fn on_event(&self, event: &T) -> Option<ParallelDispatchResult>;
+
+    /// Returns the listener's name, used to describe the listener
+    /// in a [`Snapshot`](crate::snapshot::Snapshot).
+    ///
+    /// Defaults to the listener's type name.
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Returns the [`ListenerId`] the listener has been registered under
+    /// by the dispatcher it has been added to.
+    ///
+    /// Only meant for dispatchers, which assign ids by wrapping the
+    /// listeners they are given, overriding it has no effect.
+    #[doc(hidden)]
+    fn listener_id(&self) -> Option<ListenerId> {
+        None
+    }
+
+    /// Returns whether the listener can still receive events, listeners
+    /// that are no longer alive are purged by their dispatcher's
+    /// `purge_dead` and whenever a listener is added to their event-key.
+    ///
+    /// Defaults to `true`, see [`weakly`](crate::fns::weakly).
+    fn is_alive(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T, L> ParallelListener<T> for Box<L>
+where
+    T: Clone + Send + Sync + 'static,
+    L: ParallelListener<T> + ?Sized,
+{
+    fn on_event(&self, event: &T) -> Option<ParallelDispatchResult> {
+        (**self).on_event(event)
+    }
+
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn listener_id(&self) -> Option<ListenerId> {
+        (**self).listener_id()
+    }
+
+    fn is_alive(&self) -> bool {
+        (**self).is_alive()
+    }
 }
 
 /// Every event-receiver needs to implement this trait
 /// in order to receive dispatched events.
 /// `T` being the type you use for events, e.g. an `Enum`.
-#[cfg(feature = "parallel")]
+#[cfg(feature = "priority")]
 pub trait PriorityListener<T>
 where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    T: Clone + Send + Sync + 'static,
 {
     /// This function will be called once a listened
     /// event-type `T` has been dispatched.
     /// If you want to mutate the listener, consider wrapping it behind an
     /// `RwLock` or `Mutex`.
     fn on_event(&self, event: &T) -> Option<PriorityDispatcherResult>;
+
+    /// Returns the listener's name, used to describe the listener
+    /// in a [`Snapshot`](crate::snapshot::Snapshot).
+    ///
+    /// Defaults to the listener's type name.
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Returns whether the listener relies on its tier being dispatched to
+    /// one listener after another, see
+    /// [`Ordered`](crate::order::Ordered).
+    ///
+    /// Defaults to `false`.
+    fn requires_order(&self) -> bool {
+        false
+    }
+
+    /// Returns the [`Owner`] the listener has been registered under, see
+    /// [`Owned`](hub::Owned).
+    ///
+    /// Defaults to `None`.
+    fn owner(&self) -> Option<Owner> {
+        None
+    }
+
+    /// Returns the [`ListenerId`] the listener has been registered under
+    /// by the dispatcher it has been added to.
+    ///
+    /// Only meant for dispatchers, which assign ids by wrapping the
+    /// listeners they are given, overriding it has no effect.
+    #[doc(hidden)]
+    fn listener_id(&self) -> Option<ListenerId> {
+        None
+    }
+
+    /// Returns whether the listener can still receive events, listeners
+    /// that are no longer alive are purged by their dispatcher's
+    /// `purge_dead` and whenever a listener is added to their event-key.
+    ///
+    /// Defaults to `true`, see [`weakly`](crate::fns::weakly).
+    fn is_alive(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "priority")]
+impl<T, L> PriorityListener<T> for Box<L>
+where
+    T: Clone + Send + Sync + 'static,
+    L: PriorityListener<T> + ?Sized,
+{
+    fn on_event(&self, event: &T) -> Option<PriorityDispatcherResult> {
+        (**self).on_event(event)
+    }
+
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn requires_order(&self) -> bool {
+        (**self).requires_order()
+    }
+
+    fn owner(&self) -> Option<Owner> {
+        (**self).owner()
+    }
+
+    fn listener_id(&self) -> Option<ListenerId> {
+        (**self).listener_id()
+    }
+
+    fn is_alive(&self) -> bool {
+        (**self).is_alive()
+    }
 }
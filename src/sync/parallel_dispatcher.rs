@@ -1,25 +1,217 @@
 use super::{
-    super::{Error, Mutex},
-    ParallelDispatchResult, ParallelListener, ThreadPool,
+    super::{
+        fns::FnListener,
+        handle::ListenerId,
+        hooks::{self, ListenerHooks, ListenerInfo},
+        key::{ByDiscriminant, Identity, KeyExtract, KeyId},
+        scope::{Scoped, SubscriptionGuard},
+        snapshot::{ListenerEntry, Snapshot},
+        type_map::TypeMap,
+        Error, Mutex,
+    },
+    correlation::Correlation,
+    EventPriority, ParallelDispatchResult, ParallelListener, ThreadPool,
 };
+#[cfg(feature = "priority")]
+use super::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener};
 use rayon::{
-    prelude::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator},
+    prelude::{
+        IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelIterator,
+    },
     ThreadPoolBuilder,
 };
-use std::{collections::HashMap, hash::Hash};
+use std::{
+    borrow::Borrow,
+    collections::{BTreeMap, HashMap, HashSet},
+    hash::Hash,
+    iter, mem,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Arc, Barrier,
+    },
+};
+
+type EventListener<T> = Box<dyn ParallelListener<T> + Send + Sync + 'static>;
+type StagedListenerMap<K, T> = HashMap<K, BTreeMap<usize, Vec<EventListener<T>>>>;
+type MainThreadListener<T> = Box<dyn ParallelListener<T> + 'static>;
+type SharedListenerHooks<T> = Arc<ListenerHooks<T, ParallelDispatchResult>>;
+/// An event queued for a [`MainThreadQueue`], paired with its key, its
+/// correlation and the dispatcher's listener-hooks.
+type MainThreadWork<K, T> = (K, T, Correlation, Option<SharedListenerHooks<T>>);
+type OutputListener<T, R> = Box<dyn Fn(&T) -> R + Send + Sync + 'static>;
+type StartHandler = Arc<dyn Fn(usize) + Send + Sync + 'static>;
+/// An emitted event, paired with the sender of its [`Completion`] and the
+/// correlation of the event whose listener emitted it, if any.
+type FollowUp<T> = (T, Option<Sender<DispatchSummary>>, Option<Correlation>);
+/// Orders emitted events before dispatching them.
+type FollowUpOrder<T> = fn(&mut [FollowUp<T>]);
+
+/// The stage-index of listeners added without a stage.
+const DEFAULT_STAGE: usize = 0;
+
+/// How a [`ParallelDispatcher`] dispatches to a stage with more listeners
+/// than its thread-pool has threads.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Saturation {
+    /// The dispatching thread waits while the thread-pool works through
+    /// all listeners, listeners exceeding the threads wait for a thread to
+    /// become free, the default.
+    Block,
+    /// The dispatching thread joins the thread-pool's threads, each of
+    /// them calls the next listener yet to be called once free.
+    Inline,
+}
+
+impl Default for Saturation {
+    fn default() -> Self {
+        Self::Block
+    }
+}
+
+/// Queues follow-up events on a [`ParallelDispatcher`], created via
+/// [`ParallelDispatcher::emitter`].
+///
+/// Emitters are [`Send`] and [`Sync`], hence listeners can capture them
+/// and emit events while being dispatched to in parallel.
+pub struct Emitter<T> {
+    follow_ups: Arc<Mutex<Vec<FollowUp<T>>>>,
+}
+
+impl<T> Emitter<T> {
+    /// Queues `event` to be dispatched once the current dispatch is done.
+    pub fn emit(&self, event: T) {
+        self.follow_ups
+            .lock()
+            .push((event, None, Correlation::current()));
+    }
+
+    /// Queues `event` like [`emit`](Self::emit), returning a [`Completion`]
+    /// completing once `event` has been dispatched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::ParallelDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Save,
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+    ///     .expect("Failed to build threadpool");
+    /// dispatcher.add_fn(Event::Save, |_event| None);
+    ///
+    /// let completion = dispatcher.emitter().emit_tracked(Event::Save);
+    /// assert!(completion.try_summary().is_none());
+    ///
+    /// dispatcher.dispatch_emitted();
+    /// assert_eq!(completion.wait().map(|summary| summary.listeners), Some(1));
+    /// ```
+    #[must_use]
+    pub fn emit_tracked(&self, event: T) -> Completion {
+        let (sender, receiver) = mpsc::channel();
+
+        self.follow_ups
+            .lock()
+            .push((event, Some(sender), Correlation::current()));
+
+        Completion { receiver }
+    }
+}
+
+impl<T> Clone for Emitter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            follow_ups: Arc::clone(&self.follow_ups),
+        }
+    }
+}
+
+/// Describes the dispatch of a single event.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct DispatchSummary {
+    /// How many listeners received the event, excluding the listeners of
+    /// [`MainThreadQueue`]s, which receive it via
+    /// [`MainThreadQueue::run_main_thread_work`].
+    pub listeners: usize,
+    /// How many of them stopped listening.
+    pub stopped_listening: usize,
+}
+
+/// Completes once an event emitted via [`Emitter::emit_tracked`] has been
+/// dispatched, carrying the [`DispatchSummary`] of its dispatch.
+///
+/// **Note**: Emitted events are dispatched after the current dispatch,
+/// waiting from within a listener therefore never completes.
+pub struct Completion {
+    receiver: Receiver<DispatchSummary>,
+}
+
+impl Completion {
+    /// Blocks until the event has been dispatched and returns the summary.
+    ///
+    /// Returns `None` if the event will never be dispatched, as the
+    /// dispatcher and all its emitters have been dropped.
+    #[must_use]
+    pub fn wait(self) -> Option<DispatchSummary> {
+        self.receiver.recv().ok()
+    }
+
+    /// Returns the summary if the event has been dispatched already,
+    /// without blocking.
+    #[must_use]
+    pub fn try_summary(&self) -> Option<DispatchSummary> {
+        self.receiver.try_recv().ok()
+    }
+}
 
 /// In charge of parallel dispatching to all listeners.
-pub struct ParallelDispatcher<T>
+///
+/// Listeners can be grouped into named stages, see
+/// [`add_stage`](Self::add_stage).
+/// Stages are dispatched one after another, each stage acting as a barrier,
+/// while listeners inside a stage are dispatched in parallel.
+///
+/// `X` is the [`KeyExtract`]-strategy deriving the key listeners are
+/// registered by from events, by default the event itself.
+pub struct ParallelDispatcher<T, X = Identity>
 where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
 {
-    events: HashMap<T, Vec<Box<dyn ParallelListener<T> + Send + Sync + 'static>>>,
+    events: StagedListenerMap<X::Key, T>,
+    stages: Vec<String>,
     thread_pool: ThreadPool,
+    start_handler: Option<StartHandler>,
+    saturation: Saturation,
+    /// Reused between dispatches to avoid allocating per stage.
+    listeners_to_remove: Mutex<Vec<usize>>,
+    /// Sends events to the [`MainThreadQueue`]s created via
+    /// [`main_thread_queue`](Self::main_thread_queue).
+    main_thread_queues: Vec<MainThreadSender<X::Key, T>>,
+    /// Stores the `OutputListener<T, R>`s of each event-key by their output
+    /// type.
+    output_listeners: TypeMap<X::Key>,
+    follow_ups: Arc<Mutex<Vec<FollowUp<T>>>>,
+    follow_up_order: Option<FollowUpOrder<T>>,
+    listener_hooks: Option<SharedListenerHooks<T>>,
 }
 
-impl<T> ParallelDispatcher<T>
+/// A [`ParallelDispatcher`] registering listeners by the variant of
+/// `Enum`-events.
+///
+/// Events carrying fields work without implementing [`Hash`]- and
+/// [`PartialEq`]-traits ignoring those fields.
+///
+/// [`Hash`]: std::hash::Hash
+pub type DiscriminantParallelDispatcher<T> = ParallelDispatcher<T, ByDiscriminant>;
+
+impl<T, X> ParallelDispatcher<T, X>
 where
-    T: PartialEq + Eq + Hash + Clone + Send + Sized + Sync + 'static,
+    T: Clone + Send + Sized + Sync + 'static,
+    X: KeyExtract<T>,
 {
     /// Creates a parallel dispatcher with `num_threads` amount of threads.
     ///
@@ -28,15 +220,26 @@ where
     pub fn new(num_threads: usize) -> Result<Self, Error> {
         Ok(Self {
             events: HashMap::new(),
-            thread_pool: rayon::ThreadPoolBuilder::new()
-                .num_threads(num_threads)
-                .build()?,
+            stages: Vec::new(),
+            thread_pool: build_thread_pool(num_threads, None)?,
+            start_handler: None,
+            saturation: Saturation::default(),
+            listeners_to_remove: Mutex::new(Vec::new()),
+            main_thread_queues: Vec::new(),
+            output_listeners: TypeMap::new(),
+            follow_ups: Arc::new(Mutex::new(Vec::new())),
+            follow_up_order: None,
+            listener_hooks: None,
         })
     }
 
     /// Adds a [`ParallelListener`] to listen for an `event_key`.
     ///
-    /// **Note**: If your `Enum` owns fields you need to consider implementing
+    /// Returns the [`ListenerId`] removing the listener via
+    /// [`remove_listener`](Self::remove_listener).
+    ///
+    /// **Note**: If your `Enum` owns fields you need to consider a
+    /// [`KeyExtract`]-strategy or implementing
     /// the [`Hash`]- and [`PartialEq`]-trait if you want to ignore fields,
     /// see second example for an implementation-suggestion.
     ///
@@ -102,13 +305,305 @@ where
         &mut self,
         event_key: T,
         listener: D,
-    ) {
-        let listener = Box::new(listener);
+    ) -> ListenerId {
+        self.insert_listener(X::extract(&event_key), DEFAULT_STAGE, listener)
+    }
 
-        self.events
-            .entry(event_key)
-            .or_insert_with(Vec::new)
-            .push(listener as Box<(dyn ParallelListener<T> + Send + Sync + 'static)>);
+    /// Adds the closure `listener` to listen for an `event_key`, like
+    /// [`add_listener`](Self::add_listener) does for [`ParallelListener`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::ParallelDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// dispatcher.add_fn(Event::EventType, |_event| None);
+    /// ```
+    pub fn add_fn<F>(&mut self, event_key: T, listener: F) -> ListenerId
+    where
+        F: Fn(&T) -> Option<ParallelDispatchResult> + Send + Sync + 'static,
+    {
+        self.add_listener(event_key, FnListener(listener))
+    }
+
+    /// Adds a [`ParallelListener`] to listen for an `event_key` until the
+    /// returned [`SubscriptionGuard`] is dropped.
+    pub fn subscribe_scoped<D: ParallelListener<T> + Send + Sync + Sized + 'static>(
+        &mut self,
+        event_key: T,
+        listener: D,
+    ) -> SubscriptionGuard {
+        let (listener, guard) = Scoped::new(listener);
+
+        self.add_listener(event_key, listener);
+
+        guard
+    }
+
+    /// Adds the closure `listener` returning an output of type `R` for an
+    /// `event_key`, outputs are combined via
+    /// [`dispatch_reduce`](Self::dispatch_reduce).
+    ///
+    /// Output-listeners are kept apart from [`ParallelListener`]s, other
+    /// dispatches do not reach them. They are counted and removed like any
+    /// other listener, but neither [`take_listeners`](Self::take_listeners)
+    /// nor [`drain`](Self::drain) returns them.
+    pub fn add_output_fn<R, F>(&mut self, event_key: T, listener: F)
+    where
+        R: Send + 'static,
+        F: Fn(&T) -> R + Send + Sync + 'static,
+    {
+        self.output_listeners
+            .push::<OutputListener<T, R>>(X::extract(&event_key), Box::new(listener));
+    }
+
+    /// Appends a stage named `name` after all previously added stages.
+    ///
+    /// Stages are dispatched in the order they have been added, a stage
+    /// starts once all listeners of the prior stage have finished.
+    /// Listeners added via [`add_listener`] belong to an unnamed stage
+    /// that is always dispatched first.
+    ///
+    /// Adding a stage with an already existing name keeps the stage at its
+    /// original position.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// struct Physics;
+    /// struct Audio;
+    ///
+    /// impl ParallelListener<Event> for Physics {
+    ///     fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> { None }
+    /// }
+    ///
+    /// impl ParallelListener<Event> for Audio {
+    ///     fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> { None }
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(2)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// dispatcher.add_stage("physics");
+    /// dispatcher.add_stage("audio");
+    ///
+    /// // `Audio` will only be dispatched to once `Physics` is done.
+    /// dispatcher.add_listener_to_stage(Event::Tick, "audio", Audio)
+    ///     .expect("Stage does not exist");
+    /// dispatcher.add_listener_to_stage(Event::Tick, "physics", Physics)
+    ///     .expect("Stage does not exist");
+    /// ```
+    ///
+    /// [`add_listener`]: Self::add_listener
+    pub fn add_stage(&mut self, name: impl Into<String>) {
+        let name = name.into();
+
+        if !self.stages.contains(&name) {
+            self.stages.push(name);
+        }
+    }
+
+    /// Adds a [`ParallelListener`] to listen for an `event_key` inside
+    /// the stage called `stage`.
+    ///
+    /// # Errors
+    /// Fails with [`Error::UnknownStage`] if no stage called `stage` has been
+    /// added via [`add_stage`].
+    ///
+    /// [`add_stage`]: Self::add_stage
+    pub fn add_listener_to_stage<D: ParallelListener<T> + Send + Sync + Sized + 'static>(
+        &mut self,
+        event_key: T,
+        stage: &str,
+        listener: D,
+    ) -> Result<ListenerId, Error> {
+        let stage_index = self
+            .stages
+            .iter()
+            .position(|name| name == stage)
+            .ok_or_else(|| Error::UnknownStage(stage.to_string()))?;
+
+        // The default stage occupies the first index.
+        Ok(self.insert_listener(X::extract(&event_key), stage_index + 1, listener))
+    }
+
+    /// Inserts `listener` for `event_key` into the stage at `stage_index`
+    /// and returns the id it has been registered under.
+    fn insert_listener<D: ParallelListener<T> + Send + Sync + 'static>(
+        &mut self,
+        event_key: X::Key,
+        stage_index: usize,
+        listener: D,
+    ) -> ListenerId {
+        let id = ListenerId::next();
+        let staged_listeners = self.events.entry(event_key).or_default();
+
+        for listener_collection in staged_listeners.values_mut() {
+            listener_collection.retain(ParallelListener::is_alive);
+        }
+
+        staged_listeners
+            .entry(stage_index)
+            .or_default()
+            .push(Box::new(Registered { id, listener }));
+
+        id
+    }
+
+    /// Removes the listener added under `id`, including main-thread
+    /// listeners, returns whether it has been found.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::ParallelDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// let id = dispatcher.add_fn(Event::Tick, |_event| None);
+    /// dispatcher.add_fn(Event::Tick, |_event| None);
+    ///
+    /// assert!(dispatcher.remove_listener(id));
+    /// assert!(!dispatcher.remove_listener(id));
+    /// assert_eq!(dispatcher.snapshot().listeners.len(), 1);
+    /// ```
+    pub fn remove_listener(&mut self, id: ListenerId) -> bool {
+        let mut removed = false;
+
+        for listener_collection in self.events.values_mut().flat_map(BTreeMap::values_mut) {
+            let listeners = listener_collection.len();
+
+            listener_collection.retain(|listener| listener.listener_id() != Some(id));
+            removed |= listeners != listener_collection.len();
+        }
+
+        removed
+    }
+
+    /// Removes all listeners that are no longer alive, e.g. listeners
+    /// created via [`weakly`](crate::fns::weakly) whose shared state has
+    /// been dropped, and returns how many have been removed.
+    ///
+    /// Adding a listener purges the dead listeners of its event-key,
+    /// calling this periodically bounds the memory of event-keys that
+    /// rarely see new listeners or events.
+    pub fn purge_dead(&mut self) -> usize {
+        let mut removed = 0;
+
+        for listener_collection in self.events.values_mut().flat_map(BTreeMap::values_mut) {
+            let listeners = listener_collection.len();
+
+            listener_collection.retain(ParallelListener::is_alive);
+            removed += listeners - listener_collection.len();
+        }
+
+        removed
+    }
+
+    /// Creates a [`MainThreadQueue`] whose listeners receive the events
+    /// dispatched by this dispatcher on the queue's thread, instead of on
+    /// the thread-pool.
+    ///
+    /// The queue is neither [`Send`] nor [`Sync`], hence neither are its
+    /// listeners required to be, e.g. handlers keeping their state in an
+    /// [`Rc`]. The dispatcher merely sends events to the queue, which
+    /// dispatches them once [`run_main_thread_work`] is called.
+    ///
+    /// Each queue receives the events its own listeners listen for, a
+    /// dropped queue stops receiving events.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener};
+    /// use std::{cell::RefCell, rc::Rc};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Redraw,
+    /// }
+    ///
+    /// struct Window {
+    ///     redraws: Rc<RefCell<u32>>,
+    /// }
+    ///
+    /// impl ParallelListener<Event> for Window {
+    ///     fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> {
+    ///         *self.redraws.borrow_mut() += 1;
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(2)
+    ///     .expect("Failed to build threadpool");
+    /// let mut main_thread = dispatcher.main_thread_queue();
+    /// let redraws = Rc::new(RefCell::new(0));
+    ///
+    /// main_thread.add_listener(Event::Redraw, Window { redraws: Rc::clone(&redraws) });
+    /// dispatcher.dispatch_event(&Event::Redraw);
+    ///
+    /// // The window is redrawn here, on this thread.
+    /// main_thread.run_main_thread_work();
+    /// assert_eq!(*redraws.borrow(), 1);
+    /// ```
+    ///
+    /// [`run_main_thread_work`]: MainThreadQueue::run_main_thread_work
+    /// [`Rc`]: std::rc::Rc
+    pub fn main_thread_queue(&mut self) -> MainThreadQueue<T, X> {
+        let (sender, receiver) = mpsc::channel();
+        let event_keys = Arc::new(Mutex::new(HashSet::new()));
+
+        self.main_thread_queues.push(MainThreadSender {
+            sender: Mutex::new(sender),
+            event_keys: Arc::clone(&event_keys),
+        });
+
+        MainThreadQueue {
+            listeners: HashMap::new(),
+            event_keys,
+            receiver,
+        }
+    }
+
+    /// Sends `event` to the main-thread queues listening to `event_key`,
+    /// dropping the senders of dropped queues.
+    fn queue_main_thread_work(&mut self, event_key: &X::Key, event: &T, correlation: Correlation) {
+        send_main_thread_work(
+            &mut self.main_thread_queues,
+            self.listener_hooks.as_ref(),
+            event_key,
+            event,
+            correlation,
+        );
+    }
+
+    /// Whether any [`MainThreadQueue`] has listeners for `event_key`.
+    fn has_main_thread_listeners(&self, event_key: &X::Key) -> bool {
+        self.main_thread_queues
+            .iter()
+            .any(|queue| queue.listens_to(event_key))
     }
 
     /// Immediately after calling this method,
@@ -124,43 +619,1293 @@ where
     ///
     /// [`Error::ThreadPoolBuilder`]: Error::ThreadPoolBuilder
     pub fn num_threads(&mut self, num: usize) -> Result<(), Error> {
-        self.thread_pool = ThreadPoolBuilder::new().num_threads(num).build()?;
+        self.thread_pool = build_thread_pool(num, self.start_handler.as_ref())?;
 
         Ok(())
     }
 
-    /// All [`ParallelListener`]s listening to a passed `event_identifier`
-    /// will be called via their implemented [`on_event`]-method.
-    /// [`ParallelListener`]s returning an [`Option`] wrapping [`ParallelDispatchResult`]
-    /// with `ParallelDispatchResult::StopListening` will cause them
-    /// to be removed from the event-dispatcher.
+    /// Rebuilds the thread-pool, calling `pin` on each of its threads with
+    /// the thread's index before it runs any listener, e.g. to pin the
+    /// thread to a core.
     ///
-    /// [`ParallelListener`]: ParallelListener
-    /// [`on_event`]: ParallelListener::on_event
-    /// [`ParallelDispatchResult`]: ParallelDispatchResult
-    /// [`Option`]: std::option::Option
-    pub fn dispatch_event(&mut self, event_identifier: &T) {
-        if let Some(listener_collection) = self.events.get_mut(event_identifier) {
-            let listeners_to_remove = Mutex::new(Vec::new());
-
-            self.thread_pool.install(|| {
-                listener_collection
-                    .par_iter()
-                    .enumerate()
-                    .for_each(|(index, listener)| {
-                        if let Some(instruction) = listener.on_event(event_identifier) {
-                            match instruction {
-                                ParallelDispatchResult::StopListening => {
-                                    listeners_to_remove.lock().push(index)
-                                }
-                            }
-                        }
-                    })
-            });
+    /// `pin` is kept for thread-pools built via
+    /// [`num_threads`](Self::num_threads) later on.
+    /// The rebuilt thread-pool is warmed up, see
+    /// [`warm_up`](Self::warm_up).
+    ///
+    /// # Errors
+    /// If internals fail to build, [`Error::ThreadPoolBuilder`] is returned.
+    /// Failing to build the thread-pool will result
+    /// in keeping the prior thread-pool.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::ParallelDispatcher;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// static PINNED: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(2)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// // Pinning to the core of the same index would happen here.
+    /// dispatcher
+    ///     .pin_threads(|_index| {
+    ///         PINNED.fetch_add(1, Ordering::SeqCst);
+    ///     })
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// assert_eq!(PINNED.load(Ordering::SeqCst), 2);
+    /// ```
+    pub fn pin_threads<F>(&mut self, pin: F) -> Result<(), Error>
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        let start_handler: StartHandler = Arc::new(pin);
 
-            listeners_to_remove.lock().iter().for_each(|index| {
-                listener_collection.swap_remove(*index);
-            });
+        self.thread_pool =
+            build_thread_pool(self.thread_pool.current_num_threads(), Some(&start_handler))?;
+        self.start_handler = Some(start_handler);
+        self.warm_up();
+
+        Ok(())
+    }
+
+    /// Blocks until every thread of the thread-pool has been started and
+    /// run a task, so the first dispatch does not pay for threads
+    /// spinning up.
+    pub fn warm_up(&self) {
+        let num_threads = self.thread_pool.current_num_threads();
+        let barrier = Barrier::new(num_threads);
+
+        // Every task blocks its thread until all threads run one.
+        self.thread_pool.scope(|scope| {
+            for _ in 0..num_threads {
+                scope.spawn(|_| {
+                    barrier.wait();
+                });
+            }
+        });
+    }
+
+    /// Sets how stages with more listeners than the thread-pool has
+    /// threads are dispatched to, see [`Saturation`].
+    ///
+    /// Either way, dispatching returns once all listeners have been called.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{ParallelDispatcher, Saturation};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(2)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// // The dispatching thread helps out once both threads are busy.
+    /// dispatcher.saturation(Saturation::Inline);
+    /// ```
+    pub fn saturation(&mut self, saturation: Saturation) {
+        self.saturation = saturation;
+    }
+
+    /// Creates an [`Emitter`] queueing follow-up events on this dispatcher.
+    ///
+    /// Listeners cannot dispatch on the dispatcher dispatching to them,
+    /// instead they emit events via a captured emitter.
+    /// Emitted events are dispatched in the order they have been emitted,
+    /// once the dispatch during which they have been emitted is done.
+    /// Events emitted while dispatching follow-up events are dispatched
+    /// afterwards as well, until no more events are emitted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{ParallelDispatcher, ParallelDispatchResult};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Explosion,
+    ///     Damage,
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(2)
+    ///     .expect("Failed to build threadpool");
+    /// let emitter = dispatcher.emitter();
+    ///
+    /// dispatcher.add_fn(Event::Explosion, move |_event| {
+    ///     emitter.emit(Event::Damage);
+    ///
+    ///     None
+    /// });
+    ///
+    /// // Dispatches `Event::Damage` after all listeners of the explosion.
+    /// dispatcher.dispatch_event(&Event::Explosion);
+    /// ```
+    #[must_use]
+    pub fn emitter(&self) -> Emitter<T> {
+        Emitter {
+            follow_ups: Arc::clone(&self.follow_ups),
         }
     }
+
+    /// Dispatches emitted events by their [`EventPriority`], events with a
+    /// lower priority-level first, instead of the order they have been
+    /// emitted in.
+    ///
+    /// Events sharing a priority-level keep the order they have been
+    /// emitted in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{EventPriority, ParallelDispatcher};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Render,
+    ///     Input,
+    /// }
+    ///
+    /// impl EventPriority<u32> for Event {
+    ///     fn priority(&self) -> u32 {
+    ///         match self {
+    ///             Event::Input => 0,
+    ///             Event::Render => 1,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+    ///     .expect("Failed to build threadpool");
+    /// dispatcher.prioritise_emitted::<u32>();
+    ///
+    /// let emitter = dispatcher.emitter();
+    /// emitter.emit(Event::Render);
+    /// emitter.emit(Event::Input);
+    ///
+    /// // Dispatches `Event::Input` before `Event::Render`.
+    /// dispatcher.dispatch_emitted();
+    /// ```
+    pub fn prioritise_emitted<P>(&mut self)
+    where
+        T: EventPriority<P>,
+        P: Ord,
+    {
+        self.follow_up_order = Some(|follow_ups| {
+            follow_ups.sort_by_key(|(event, ..)| event.priority());
+        });
+    }
+
+    /// Sets hooks called right before and after each listener receives an
+    /// event, `after` receives the listener's request.
+    ///
+    /// Replaces previously set hooks, see the [`hooks`] module. Hooks are
+    /// called on the listener's thread, concurrently for listeners of the
+    /// same stage.
+    pub fn set_listener_hooks<B, A>(&mut self, before: B, after: A)
+    where
+        B: Fn(&T, ListenerInfo<'_>) + Send + Sync + 'static,
+        A: Fn(&T, ListenerInfo<'_>, &Option<ParallelDispatchResult>) + Send + Sync + 'static,
+    {
+        self.listener_hooks = Some(Arc::new(ListenerHooks::new(before, after)));
+    }
+
+    /// Dispatches the events emitted since the last dispatch, e.g. by
+    /// producers outside of any listener.
+    ///
+    /// Dispatching any event dispatches emitted events afterwards as well.
+    pub fn dispatch_emitted(&mut self) {
+        self.dispatch_follow_ups();
+    }
+
+    /// Returns how many listeners listen for `event_key` across all stages,
+    /// including output-listeners.
+    #[must_use]
+    pub fn listener_count(&self, event_key: &T) -> usize {
+        let event_key = X::extract(event_key);
+        let staged_listener_count = self
+            .events
+            .get(&event_key)
+            .into_iter()
+            .flat_map(BTreeMap::values)
+            .flatten()
+            .filter(|listener| listener.is_alive())
+            .count();
+
+        staged_listener_count + self.output_listeners.len(&event_key)
+    }
+
+    /// Returns how many listeners listen for any event-key, including
+    /// output-listeners.
+    #[must_use]
+    pub fn total_listeners(&self) -> usize {
+        let staged_listener_count = self
+            .events
+            .values()
+            .flat_map(BTreeMap::values)
+            .flatten()
+            .filter(|listener| listener.is_alive())
+            .count();
+
+        staged_listener_count + self.output_listeners.total_len()
+    }
+
+    /// Returns the event-keys at least one listener listens for, including
+    /// output-listeners.
+    #[must_use]
+    pub fn event_keys(&self) -> HashSet<X::Key> {
+        let mut event_keys: HashSet<_> = self
+            .events
+            .iter()
+            .filter(|(_, staged_listeners)| {
+                staged_listeners
+                    .values()
+                    .flatten()
+                    .any(ParallelListener::is_alive)
+            })
+            .map(|(event_key, _)| event_key.clone())
+            .collect();
+
+        event_keys.extend(
+            self.output_listeners
+                .iter()
+                .filter(|(_, _, count)| *count > 0)
+                .map(|(event_key, _, _)| event_key.clone()),
+        );
+
+        event_keys
+    }
+
+    /// Returns whether no listener listens for any event-key, including
+    /// output-listeners.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.total_listeners() == 0
+    }
+
+    /// Takes a [`Snapshot`] of all registered listeners.
+    ///
+    /// The priority of each listener is the name of its stage, `None` for
+    /// listeners added via [`add_listener`](Self::add_listener).
+    ///
+    /// [`Snapshot`]: crate::snapshot::Snapshot
+    #[must_use]
+    pub fn snapshot(&self) -> Snapshot<X::Key, Option<String>> {
+        let listeners = self
+            .events
+            .iter()
+            .flat_map(|(event_key, staged_listeners)| {
+                staged_listeners
+                    .iter()
+                    .flat_map(move |(stage_index, listeners)| {
+                        // The default stage occupies the first index.
+                        let stage = stage_index
+                            .checked_sub(1)
+                            .map(|stage_index| self.stages[stage_index].clone());
+
+                        listeners.iter().filter(|listener| listener.is_alive()).map(
+                            move |listener| ListenerEntry {
+                                event_key: event_key.clone(),
+                                priority: stage.clone(),
+                                name: listener.name().to_string(),
+                            },
+                        )
+                    })
+            })
+            .chain(
+                self.output_listeners
+                    .iter()
+                    .flat_map(|(event_key, name, count)| {
+                        iter::repeat_with(move || ListenerEntry {
+                            event_key: event_key.clone(),
+                            priority: None,
+                            name: name.to_string(),
+                        })
+                        .take(count)
+                    }),
+            )
+            .collect();
+
+        Snapshot { listeners }
+    }
+
+    /// Removes all listeners of `event_key` and returns them alongside the
+    /// name of their stage, `None` for the unnamed stage.
+    ///
+    /// Boxed listeners implement [`ParallelListener`] themselves, hence can
+    /// be added to another dispatcher. Output-listeners are removed as well,
+    /// but not returned.
+    pub fn take_listeners(
+        &mut self,
+        event_key: &T,
+    ) -> Vec<(
+        Option<String>,
+        Box<dyn ParallelListener<T> + Send + Sync + 'static>,
+    )> {
+        let event_key = X::extract(event_key);
+        let stages = &self.stages;
+
+        self.output_listeners.remove(&event_key);
+        self.events
+            .remove(&event_key)
+            .into_iter()
+            .flat_map(|staged_listeners| unstage(stages, staged_listeners))
+            .collect()
+    }
+
+    /// Removes all listeners and returns them alongside the key they have
+    /// been registered by and the name of their stage.
+    ///
+    /// Another dispatcher adds the listeners via its [`Extend`]-implementation.
+    /// Output-listeners are removed as well, but not returned.
+    #[allow(clippy::type_complexity)]
+    pub fn drain(
+        &mut self,
+    ) -> Vec<(
+        X::Key,
+        Option<String>,
+        Box<dyn ParallelListener<T> + Send + Sync + 'static>,
+    )> {
+        let stages = &self.stages;
+
+        self.output_listeners.clear();
+        self.events
+            .drain()
+            .flat_map(|(event_key, staged_listeners)| {
+                unstage(stages, staged_listeners)
+                    .map(move |(stage, listener)| (event_key.clone(), stage, listener))
+            })
+            .collect()
+    }
+
+    /// Removes all listeners of `event_key` across all stages, including
+    /// output-listeners, and returns how many live listeners have been
+    /// removed.
+    pub fn remove_all_listeners(&mut self, event_key: &T) -> usize {
+        let event_key = X::extract(event_key);
+        let staged_listener_count = self
+            .events
+            .remove(&event_key)
+            .map_or(0, |staged_listeners| {
+                staged_listeners
+                    .values()
+                    .flatten()
+                    .filter(|listener| listener.is_alive())
+                    .count()
+            });
+
+        staged_listener_count + self.output_listeners.remove(&event_key)
+    }
+
+    /// Removes all listeners of all event-keys, including output-listeners,
+    /// keeping the dispatcher's settings, e.g. its stages and thread-pool.
+    pub fn clear(&mut self) {
+        self.events.clear();
+        self.output_listeners.clear();
+    }
+
+    /// All [`ParallelListener`]s listening to a passed `event_identifier`
+    /// will be called via their implemented [`on_event`]-method.
+    /// [`ParallelListener`]s returning an [`Option`] wrapping [`ParallelDispatchResult`]
+    /// with `ParallelDispatchResult::StopListening` will cause them
+    /// to be removed from the event-dispatcher.
+    ///
+    /// The calling thread is blocked until all listeners have been called,
+    /// listeners exceeding the thread-pool's threads are handled as set via
+    /// [`saturation`](Self::saturation).
+    /// Stages with a single listener call it directly on the calling thread.
+    ///
+    /// [`ParallelListener`]: ParallelListener
+    /// [`on_event`]: ParallelListener::on_event
+    /// [`ParallelDispatchResult`]: ParallelDispatchResult
+    /// [`Option`]: std::option::Option
+    pub fn dispatch_event(&mut self, event_identifier: &T) {
+        self.dispatch(
+            &X::extract(event_identifier),
+            None,
+            Correlation::caused_by(Correlation::current()),
+            || event_identifier,
+        );
+        self.dispatch_follow_ups();
+    }
+
+    /// Dispatches `event_identifier` like [`dispatch_event`], using at most
+    /// `max_threads` of the thread-pool's threads.
+    ///
+    /// Low-priority bulk events thus leave cores to latency-critical work
+    /// running at the same time, e.g. on other dispatchers.
+    /// Events emitted meanwhile are dispatched using all threads.
+    /// A `max_threads` of `0` is treated as `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::ParallelDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Reindex,
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(4)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// for _ in 0..8 {
+    ///     dispatcher.add_fn(Event::Reindex, |_event| None);
+    /// }
+    ///
+    /// // Leaves three threads to other work.
+    /// dispatcher.dispatch_event_with_threads(&Event::Reindex, 1);
+    /// ```
+    ///
+    /// [`dispatch_event`]: Self::dispatch_event
+    pub fn dispatch_event_with_threads(&mut self, event_identifier: &T, max_threads: usize) {
+        self.dispatch(
+            &X::extract(event_identifier),
+            Some(max_threads),
+            Correlation::caused_by(Correlation::current()),
+            || event_identifier,
+        );
+        self.dispatch_follow_ups();
+    }
+
+    /// Dispatches all `events`, dispatching events of different event-keys
+    /// in parallel to each other.
+    ///
+    /// Events sharing an event-key are dispatched one after another in the
+    /// order they appear in `events`, as with [`dispatch_event`].
+    /// Batches of mostly distinct events thus use threads otherwise idle
+    /// due to event-keys having few listeners.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Input,
+    ///     Network,
+    /// }
+    ///
+    /// struct Handler;
+    ///
+    /// impl ParallelListener<Event> for Handler {
+    ///     fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> { None }
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(2)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// dispatcher.add_listener(Event::Input, Handler);
+    /// dispatcher.add_listener(Event::Network, Handler);
+    ///
+    /// // Both listeners are dispatched to at the same time.
+    /// dispatcher.dispatch_events(&[Event::Input, Event::Network]);
+    /// ```
+    ///
+    /// [`dispatch_event`]: Self::dispatch_event
+    pub fn dispatch_events(&mut self, events: &[T]) {
+        let mut batches: HashMap<X::Key, Vec<(&T, Correlation)>> = HashMap::new();
+        let cause = Correlation::current();
+
+        for event in events {
+            let event_key = X::extract(event);
+            let correlation = Correlation::caused_by(cause);
+
+            self.queue_main_thread_work(&event_key, event, correlation);
+            batches
+                .entry(event_key)
+                .or_default()
+                .push((event, correlation));
+        }
+
+        let Self {
+            events: staged_listener_collections,
+            thread_pool,
+            saturation,
+            listener_hooks,
+            ..
+        } = self;
+        let thread_pool = &*thread_pool;
+        let saturation = *saturation;
+        let listener_hooks = listener_hooks.as_deref();
+        let batches: Vec<_> = staged_listener_collections
+            .iter_mut()
+            .filter_map(|(event_key, staged_listener_collection)| {
+                batches
+                    .remove(event_key)
+                    .map(|batch| (staged_listener_collection, batch))
+            })
+            .collect();
+
+        thread_pool.install(|| {
+            batches
+                .into_par_iter()
+                .for_each(|(staged_listener_collection, batch)| {
+                    // Each event-key is dispatched on its own, sharing the
+                    // dispatcher's buffer would serialise them again.
+                    let mut listeners_to_remove = Mutex::new(Vec::new());
+
+                    for (event, correlation) in batch {
+                        let call = ListenerCall {
+                            listener_hooks,
+                            correlation,
+                        };
+
+                        for listener_collection in staged_listener_collection.values_mut() {
+                            dispatch_stage(
+                                thread_pool,
+                                saturation,
+                                None,
+                                &call,
+                                listener_collection,
+                                &mut listeners_to_remove,
+                                event,
+                            );
+                        }
+                    }
+                });
+        });
+
+        self.dispatch_follow_ups();
+    }
+
+    /// All [`ParallelListener`]s listening to `event_key` will receive the
+    /// event constructed by `build_event`.
+    ///
+    /// `build_event` is only called if at least one [`ParallelListener`]
+    /// listens to `event_key`, expensive events will not be built for nobody.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::ParallelDispatcher;
+    /// use std::hash::{Hash, Hasher};
+    /// use std::mem::discriminant;
+    ///
+    /// #[derive(Clone)]
+    /// enum Event {
+    ///     Loaded(Vec<u8>),
+    /// }
+    ///
+    /// impl Hash for Event {
+    ///     fn hash<H: Hasher>(&self, _state: &mut H) {}
+    /// }
+    ///
+    /// impl PartialEq for Event {
+    ///     fn eq(&self, other: &Event) -> bool {
+    ///         discriminant(self) == discriminant(other)
+    ///     }
+    /// }
+    ///
+    /// impl Eq for Event {}
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(1)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// // Nobody listens, the closure will not be called.
+    /// dispatcher.dispatch_with(&Event::Loaded(Vec::new()), || unreachable!());
+    /// ```
+    ///
+    /// [`ParallelListener`]: ParallelListener
+    pub fn dispatch_with<F>(&mut self, event_key: &T, build_event: F)
+    where
+        F: FnOnce() -> T,
+    {
+        self.dispatch(
+            &X::extract(event_key),
+            None,
+            Correlation::caused_by(Correlation::current()),
+            build_event,
+        );
+        self.dispatch_follow_ups();
+    }
+
+    /// Dispatches `event` to the listeners registered by `id`, without
+    /// deriving the key from `event`, e.g. for callers caching the id of an
+    /// [`Interned`](crate::key::Interned) name.
+    pub fn dispatch_by_id(&mut self, id: KeyId, event: &T)
+    where
+        X: KeyExtract<T, Key = KeyId>,
+    {
+        self.dispatch(
+            &id,
+            None,
+            Correlation::caused_by(Correlation::current()),
+            || event,
+        );
+        self.dispatch_follow_ups();
+    }
+
+    /// Dispatches `event` to all output-listeners of its key returning an
+    /// `R`, added via [`add_output_fn`](Self::add_output_fn), and reduces
+    /// their outputs in parallel.
+    ///
+    /// Like Rayon's `reduce`, `identity` creates the starting value of each
+    /// parallel split and `reduce` combines two values in no particular
+    /// order, it should be associative.
+    /// Without output-listeners, the value created by `identity` is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::ParallelDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Collision,
+    /// }
+    ///
+    /// let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::new(2)
+    ///     .expect("Failed to build threadpool");
+    ///
+    /// // Each body contributes an impulse.
+    /// dispatcher.add_output_fn(Event::Collision, |_event| 2.5_f32);
+    /// dispatcher.add_output_fn(Event::Collision, |_event| -1.0_f32);
+    ///
+    /// let impulse = dispatcher.dispatch_reduce(&Event::Collision, || 0.0_f32, |a, b| a + b);
+    ///
+    /// assert!((impulse - 1.5).abs() < f32::EPSILON);
+    /// ```
+    pub fn dispatch_reduce<R, I, F>(&self, event: &T, identity: I, reduce: F) -> R
+    where
+        R: Send + 'static,
+        I: Fn() -> R + Send + Sync,
+        F: Fn(R, R) -> R + Send + Sync,
+    {
+        let listeners = self
+            .output_listeners
+            .get::<OutputListener<T, R>>(X::extract(event));
+
+        if listeners.is_empty() {
+            return identity();
+        }
+
+        self.thread_pool.install(|| {
+            listeners
+                .par_iter()
+                .map(|listener| listener(event))
+                .reduce(identity, reduce)
+        })
+    }
+
+    /// Dispatches events emitted via [`Emitter`]s one after another, until
+    /// no more events are emitted.
+    fn dispatch_follow_ups(&mut self) {
+        loop {
+            let mut follow_ups = mem::take(&mut *self.follow_ups.lock());
+
+            if follow_ups.is_empty() {
+                return;
+            }
+
+            if let Some(follow_up_order) = self.follow_up_order {
+                follow_up_order(&mut follow_ups);
+            }
+
+            for (event, completion, cause) in follow_ups {
+                let summary = self.dispatch(
+                    &X::extract(&event),
+                    None,
+                    Correlation::caused_by(cause),
+                    || &event,
+                );
+
+                if let Some(completion) = completion {
+                    // The completion may have been dropped, nobody waits then.
+                    let _ = completion.send(summary);
+                }
+            }
+        }
+    }
+
+    /// Dispatches the event built by `build_event` to all listeners of
+    /// `event_key`, building it only if there is at least one listener.
+    fn dispatch<E, F>(
+        &mut self,
+        event_key: &X::Key,
+        max_threads: Option<usize>,
+        correlation: Correlation,
+        build_event: F,
+    ) -> DispatchSummary
+    where
+        E: Borrow<T>,
+        F: FnOnce() -> E,
+    {
+        let has_main_thread_listeners = self.has_main_thread_listeners(event_key);
+        let Self {
+            events,
+            thread_pool,
+            saturation,
+            listeners_to_remove,
+            main_thread_queues,
+            listener_hooks,
+            ..
+        } = self;
+        let staged_listener_collection =
+            events
+                .get_mut(event_key)
+                .filter(|staged_listener_collection| {
+                    !staged_listener_collection.values().all(Vec::is_empty)
+                });
+
+        let mut summary = DispatchSummary::default();
+
+        if staged_listener_collection.is_none() && !has_main_thread_listeners {
+            return summary;
+        }
+
+        let event = build_event();
+        let event = event.borrow();
+
+        if has_main_thread_listeners {
+            send_main_thread_work(
+                main_thread_queues,
+                listener_hooks.as_ref(),
+                event_key,
+                event,
+                correlation,
+            );
+        }
+
+        if let Some(staged_listener_collection) = staged_listener_collection {
+            let call = ListenerCall {
+                listener_hooks: listener_hooks.as_deref(),
+                correlation,
+            };
+
+            for listener_collection in staged_listener_collection.values_mut() {
+                let listeners = listener_collection.len();
+
+                dispatch_stage(
+                    thread_pool,
+                    *saturation,
+                    max_threads,
+                    &call,
+                    listener_collection,
+                    listeners_to_remove,
+                    event,
+                );
+
+                summary.listeners += listeners;
+                summary.stopped_listening += listeners - listener_collection.len();
+            }
+        }
+
+        summary
+    }
+}
+
+/// Adds listeners returned by [`ParallelDispatcher::drain`] by the key they
+/// have been registered by, migrating them between dispatchers keyed by
+/// other means than the event itself.
+///
+/// Listeners of stages this dispatcher lacks are added to a new stage of
+/// that name, after the existing ones.
+impl<T, X> Extend<(X::Key, Option<String>, EventListener<T>)> for ParallelDispatcher<T, X>
+where
+    T: Clone + Send + Sized + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    fn extend<I>(&mut self, listeners: I)
+    where
+        I: IntoIterator<Item = (X::Key, Option<String>, EventListener<T>)>,
+    {
+        for (event_key, stage, listener) in listeners {
+            let stage_index = stage.map_or(DEFAULT_STAGE, |stage| {
+                self.add_stage(stage.as_str());
+
+                // The default stage occupies the first index.
+                self.stages
+                    .iter()
+                    .position(|name| *name == stage)
+                    .unwrap_or_default()
+                    + 1
+            });
+
+            self.insert_listener(event_key, stage_index, listener);
+        }
+    }
+}
+
+#[cfg(feature = "priority")]
+impl<T, X> ParallelDispatcher<T, X>
+where
+    T: Clone + Send + Sized + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    /// Moves all listeners of `dispatcher` into the unnamed stage, switching
+    /// them from prioritised to parallel dispatch without registering each
+    /// listener anew.
+    ///
+    /// Listeners are added ordered by their priority-level, but are called
+    /// in parallel nonetheless.
+    /// As there is nothing to stop propagating to, `StopPropagation` is
+    /// ignored and `StopListeningAndPropagation` stops listening.
+    /// Parent-dispatcher, guards and panic policy of `dispatcher` are
+    /// dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{
+    ///     ParallelDispatcher, PriorityDispatcher, PriorityDispatcherResult, PriorityListener,
+    /// };
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// struct Physics;
+    ///
+    /// impl PriorityListener<Event> for Physics {
+    ///     fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> { None }
+    /// }
+    ///
+    /// let mut sequential: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    /// sequential.add_listener(Event::Tick, Physics, 1);
+    ///
+    /// let mut parallel: ParallelDispatcher<Event> = ParallelDispatcher::new(2)
+    ///     .expect("Failed to build threadpool");
+    /// parallel.extend_from_priority(sequential);
+    ///
+    /// assert_eq!(parallel.snapshot().listeners.len(), 1);
+    /// ```
+    pub fn extend_from_priority<P>(&mut self, mut dispatcher: PriorityDispatcher<P, T, X>)
+    where
+        P: Ord + Clone,
+    {
+        for (event_key, _, listener) in dispatcher.drain() {
+            self.insert_listener(event_key, DEFAULT_STAGE, FromPriority(listener));
+        }
+    }
+}
+
+/// Dispatches the events of a [`ParallelDispatcher`] to listeners on the
+/// thread owning the queue, created via
+/// [`ParallelDispatcher::main_thread_queue`].
+///
+/// The queue owns its listeners and is neither [`Send`] nor [`Sync`], hence
+/// its listeners need not be either.
+pub struct MainThreadQueue<T, X = Identity>
+where
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    listeners: HashMap<X::Key, Vec<MainThreadListener<T>>>,
+    /// The event-keys `listeners` listen for, shared with the dispatcher to
+    /// only send events listened for.
+    event_keys: Arc<Mutex<HashSet<X::Key>>>,
+    receiver: Receiver<MainThreadWork<X::Key, T>>,
+}
+
+impl<T, X> MainThreadQueue<T, X>
+where
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    /// Adds a [`ParallelListener`] to listen for an `event_key`, receiving
+    /// events once [`run_main_thread_work`](Self::run_main_thread_work) is
+    /// called.
+    ///
+    /// Returns the [`ListenerId`] removing the listener via
+    /// [`remove_listener`](Self::remove_listener).
+    ///
+    /// Adding a listener purges the dead listeners of its event-key.
+    pub fn add_listener<D: ParallelListener<T> + Sized + 'static>(
+        &mut self,
+        event_key: T,
+        listener: D,
+    ) -> ListenerId {
+        let event_key = X::extract(&event_key);
+        let id = ListenerId::next();
+
+        self.event_keys.lock().insert(event_key.clone());
+
+        let listeners = self.listeners.entry(event_key).or_default();
+
+        listeners.retain(ParallelListener::is_alive);
+        listeners.push(Box::new(Registered { id, listener }));
+
+        id
+    }
+
+    /// Removes the listener added under `id`, returns whether it has been
+    /// found.
+    pub fn remove_listener(&mut self, id: ListenerId) -> bool {
+        let mut removed = false;
+
+        for listeners in self.listeners.values_mut() {
+            let listener_count = listeners.len();
+
+            listeners.retain(|listener| listener.listener_id() != Some(id));
+            removed |= listener_count != listeners.len();
+        }
+
+        self.forget_unheard_keys();
+
+        removed
+    }
+
+    /// Dispatches all events sent to the queue since the last call to its
+    /// listeners, on the calling thread.
+    ///
+    /// Events are dispatched in the order they have been dispatched to the
+    /// dispatcher, listeners no longer alive are skipped and removed.
+    pub fn run_main_thread_work(&mut self) {
+        for (event_key, event, correlation, listener_hooks) in self.receiver.try_iter() {
+            if let Some(listeners) = self.listeners.get_mut(&event_key) {
+                let call = ListenerCall {
+                    listener_hooks: listener_hooks.as_deref(),
+                    correlation,
+                };
+
+                listeners.retain(|listener| {
+                    listener.is_alive()
+                        && !matches!(
+                            call.call(&**listener, &event),
+                            Some(ParallelDispatchResult::StopListening)
+                        )
+                });
+            }
+        }
+
+        self.forget_unheard_keys();
+    }
+
+    /// Removes all listeners that are no longer alive, e.g. listeners
+    /// created via [`weakly`](crate::fns::weakly) whose shared state has
+    /// been dropped, and returns how many have been removed.
+    ///
+    /// Event-keys left without listeners are no longer sent by the
+    /// dispatcher.
+    pub fn purge_dead(&mut self) -> usize {
+        let mut removed = 0;
+
+        for listeners in self.listeners.values_mut() {
+            let listener_count = listeners.len();
+
+            listeners.retain(ParallelListener::is_alive);
+            removed += listener_count - listeners.len();
+        }
+
+        self.forget_unheard_keys();
+
+        removed
+    }
+
+    /// Returns how many listeners listen for `event_key`.
+    #[must_use]
+    pub fn listener_count(&self, event_key: &T) -> usize {
+        self.listeners
+            .get(&X::extract(event_key))
+            .into_iter()
+            .flatten()
+            .filter(|listener| listener.is_alive())
+            .count()
+    }
+
+    /// Returns whether no listener listens for any event-key.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        !self
+            .listeners
+            .values()
+            .flatten()
+            .any(ParallelListener::is_alive)
+    }
+
+    /// Stops the dispatcher from sending events of keys no listener listens
+    /// for anymore.
+    fn forget_unheard_keys(&mut self) {
+        self.listeners.retain(|_, listeners| !listeners.is_empty());
+
+        let listeners = &self.listeners;
+
+        self.event_keys
+            .lock()
+            .retain(|event_key| listeners.contains_key(event_key));
+    }
+}
+
+/// The dispatcher's end of a [`MainThreadQueue`].
+struct MainThreadSender<K, T> {
+    /// Only ever accessed via `get_mut`, the lock merely makes the
+    /// dispatcher `Sync`.
+    sender: Mutex<Sender<MainThreadWork<K, T>>>,
+    event_keys: Arc<Mutex<HashSet<K>>>,
+}
+
+impl<K, T> MainThreadSender<K, T>
+where
+    K: Eq + Hash,
+{
+    /// Whether the queue has listeners for `event_key`.
+    fn listens_to(&self, event_key: &K) -> bool {
+        self.event_keys.lock().contains(event_key)
+    }
+}
+
+/// Sends `event` to the `queues` listening to `event_key`, dropping the
+/// senders of dropped queues.
+fn send_main_thread_work<K, T>(
+    queues: &mut Vec<MainThreadSender<K, T>>,
+    listener_hooks: Option<&SharedListenerHooks<T>>,
+    event_key: &K,
+    event: &T,
+    correlation: Correlation,
+) where
+    K: Clone + Eq + Hash,
+    T: Clone,
+{
+    queues.retain(|queue| {
+        // The queue has been dropped if the sender holds its only reference.
+        if Arc::strong_count(&queue.event_keys) == 1 {
+            return false;
+        }
+
+        !queue.listens_to(event_key)
+            || queue
+                .sender
+                .lock()
+                .send((
+                    event_key.clone(),
+                    event.clone(),
+                    correlation,
+                    listener_hooks.cloned(),
+                ))
+                .is_ok()
+    });
+}
+
+/// Wraps a listener added to a [`ParallelDispatcher`], carrying the id it
+/// has been added under.
+struct Registered<L> {
+    id: ListenerId,
+    listener: L,
+}
+
+impl<T, L> ParallelListener<T> for Registered<L>
+where
+    T: Clone + Send + Sync + 'static,
+    L: ParallelListener<T>,
+{
+    fn on_event(&self, event: &T) -> Option<ParallelDispatchResult> {
+        self.listener.on_event(event)
+    }
+
+    fn name(&self) -> &str {
+        self.listener.name()
+    }
+
+    fn listener_id(&self) -> Option<ListenerId> {
+        Some(self.id)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.listener.is_alive()
+    }
+}
+
+/// A [`PriorityListener`] moved to a [`ParallelDispatcher`].
+#[cfg(feature = "priority")]
+struct FromPriority<L>(L);
+
+#[cfg(feature = "priority")]
+impl<T, L> ParallelListener<T> for FromPriority<L>
+where
+    T: Clone + Send + Sync + 'static,
+    L: PriorityListener<T>,
+{
+    fn on_event(&self, event: &T) -> Option<ParallelDispatchResult> {
+        match self.0.on_event(event)? {
+            PriorityDispatcherResult::StopListening
+            | PriorityDispatcherResult::StopListeningAndPropagation => {
+                Some(ParallelDispatchResult::StopListening)
+            }
+            PriorityDispatcherResult::StopPropagation => None,
+        }
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.0.is_alive()
+    }
+}
+
+/// Flattens `staged_listeners` into listeners paired with the name of their
+/// stage.
+fn unstage<T>(
+    stages: &[String],
+    staged_listeners: BTreeMap<usize, Vec<EventListener<T>>>,
+) -> impl Iterator<Item = (Option<String>, EventListener<T>)> + '_
+where
+    T: Clone + Send + Sync + 'static,
+{
+    staged_listeners
+        .into_iter()
+        .flat_map(move |(stage_index, listeners)| {
+            // The default stage occupies the first index.
+            let stage = stage_index
+                .checked_sub(1)
+                .map(|stage_index| stages[stage_index].clone());
+
+            listeners
+                .into_iter()
+                .map(move |listener| (stage.clone(), listener))
+        })
+}
+
+/// Calls listeners surrounded by the dispatcher's hooks, within the
+/// correlation of the event they receive.
+struct ListenerCall<'a, T> {
+    listener_hooks: Option<&'a ListenerHooks<T, ParallelDispatchResult>>,
+    correlation: Correlation,
+}
+
+impl<T> ListenerCall<'_, T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Calls `listener` with `event`.
+    fn call<L>(&self, listener: &L, event: &T) -> Option<ParallelDispatchResult>
+    where
+        L: ParallelListener<T> + ?Sized,
+    {
+        let _entered = self.correlation.enter();
+
+        hooks::surround(self.listener_hooks, event, listener.name(), || {
+            listener.on_event(event)
+        })
+    }
+}
+
+/// Dispatches `event` in parallel to all listeners of a single stage and
+/// removes those requesting to stop listening.
+///
+/// At most `max_threads` of the thread-pool's threads are used, all of them
+/// if `None`.
+fn dispatch_stage<T>(
+    thread_pool: &ThreadPool,
+    saturation: Saturation,
+    max_threads: Option<usize>,
+    listener_call: &ListenerCall<'_, T>,
+    listener_collection: &mut Vec<EventListener<T>>,
+    listeners_to_remove: &mut Mutex<Vec<usize>>,
+    event: &T,
+) where
+    T: Clone + Send + Sync + 'static,
+{
+    // A single listener gains nothing from the thread-pool, calling it
+    // directly skips handing the stage over and collecting requests.
+    if let [listener] = listener_collection.as_slice() {
+        if matches!(
+            listener_call.call(&**listener, event),
+            Some(ParallelDispatchResult::StopListening)
+        ) {
+            listener_collection.clear();
+        }
+
+        return;
+    }
+
+    let removal_requests = &*listeners_to_remove;
+    let call = |index: usize, listener: &EventListener<T>| {
+        if let Some(instruction) = listener_call.call(&**listener, event) {
+            match instruction {
+                ParallelDispatchResult::StopListening => {
+                    removal_requests.lock().push(index);
+                }
+            }
+        }
+    };
+    let pool_threads = thread_pool.current_num_threads();
+    let num_threads = max_threads.map_or(pool_threads, |max_threads| {
+        max_threads.clamp(1, pool_threads)
+    });
+
+    if saturation == Saturation::Inline && listener_collection.len() > num_threads {
+        // The dispatching thread cannot steal work from the thread-pool,
+        // instead every thread takes the next listener from a shared index
+        // once free, balancing the load however long listeners take.
+        let next_index = AtomicUsize::new(0);
+        let listeners = listener_collection.as_slice();
+        let work = || {
+            let mut index = next_index.fetch_add(1, Ordering::Relaxed);
+
+            while let Some(listener) = listeners.get(index) {
+                call(index, listener);
+                index = next_index.fetch_add(1, Ordering::Relaxed);
+            }
+        };
+        let work = &work;
+
+        thread_pool.in_place_scope(|scope| {
+            for _ in 0..num_threads {
+                scope.spawn(move |_| work());
+            }
+
+            work();
+        });
+    } else {
+        // Splitting into at most `num_threads` pieces keeps the remaining
+        // threads free.
+        let min_len = if num_threads < pool_threads {
+            (listener_collection.len() + num_threads - 1) / num_threads
+        } else {
+            1
+        };
+
+        thread_pool.install(|| {
+            listener_collection
+                .par_iter()
+                .with_min_len(min_len)
+                .enumerate()
+                .for_each(|(index, listener)| call(index, listener));
+        });
+    }
+
+    let listeners_to_remove = listeners_to_remove.get_mut();
+
+    // Removing from the highest index downwards ensures a swap never moves
+    // a listener that is yet to be removed.
+    listeners_to_remove.sort_unstable_by(|a, b| b.cmp(a));
+
+    // Draining keeps the buffer's allocation for the next stage.
+    for index in listeners_to_remove.drain(..) {
+        listener_collection.swap_remove(index);
+    }
+}
+
+/// Builds a thread-pool with `num_threads` threads, each calling
+/// `start_handler` with its index upon starting.
+fn build_thread_pool(
+    num_threads: usize,
+    start_handler: Option<&StartHandler>,
+) -> Result<ThreadPool, Error> {
+    let mut builder = ThreadPoolBuilder::new().num_threads(num_threads);
+
+    if let Some(start_handler) = start_handler {
+        let start_handler = Arc::clone(start_handler);
+        builder = builder.start_handler(move |index| start_handler(index));
+    }
+
+    Ok(builder.build()?)
 }
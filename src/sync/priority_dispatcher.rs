@@ -1,13 +1,43 @@
-use super::{execute_sync_dispatcher_requests, ExecuteRequestsResult, PriorityListener};
+#[cfg(feature = "parallel")]
+use super::execute_parallel_dispatcher_requests;
+use super::{
+    super::{
+        audit::{DropHook, DropReason},
+        cursor::{Budget, DispatchCursor, Resumable},
+        fns::FnListener,
+        handle::ListenerId,
+        hooks::{self, ListenerHooks, ListenerInfo},
+        key::{ByDiscriminant, Identity, KeyExtract, KeyId},
+        panic_policy::PanicPolicy,
+        scope::{Scoped, SubscriptionGuard},
+        snapshot::{ListenerEntry, Snapshot},
+        type_map::TypeMap,
+        typed::{EventResult, TypedListener},
+        Error, Mutex,
+    },
+    execute_sync_dispatcher_requests, ExecuteRequestsResult, FrozenDispatcher, Owner,
+    PriorityDispatcherResult, PriorityListener,
+};
 use std::{
+    borrow::Borrow,
+    cell::RefCell,
     collections::{
         btree_map::Entry as BTreeMapEntry, hash_map::Entry as HashMapEntry, BTreeMap, HashMap,
+        HashSet,
     },
-    hash::Hash,
+    ops::{Bound, RangeBounds},
+    sync::{Arc, Weak},
 };
 
 type EventListener<T> = Box<dyn PriorityListener<T> + Send + Sync + 'static>;
-type PriorityListenerMap<P, T> = HashMap<T, BTreeMap<P, Vec<EventListener<T>>>>;
+pub(super) type PriorityListenerMap<K, P, T> = HashMap<K, BTreeMap<P, Vec<EventListener<T>>>>;
+type Guard<T> = Box<dyn Fn(&T) -> bool + Send + Sync + 'static>;
+type EventDropHook<T> = Box<dyn DropHook<T> + Send + Sync + 'static>;
+
+thread_local! {
+    /// Addresses of the priority-dispatchers dispatching on this thread.
+    static DISPATCHING: RefCell<Vec<usize>> = RefCell::new(Vec::new());
+}
 
 /// In charge of prioritised sync dispatching to all listeners.
 /// Opposed to [`EventListener`], this structure utilises one [`BTreeMap`] per
@@ -16,41 +46,200 @@ type PriorityListenerMap<P, T> = HashMap<T, BTreeMap<P, Vec<EventListener<T>>>>;
 /// **Note**: Consider implementing your own [`Ord`]-trait, if you
 /// want a different priority.
 ///
+/// `X` is the [`KeyExtract`]-strategy deriving the key listeners are
+/// registered by from events, by default the event itself.
+///
 /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
 /// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
 /// [`Ord`]: https://doc.rust-lang.org/std/cmp/trait.Ord.html
 /// [`EventListener`]: struct.Dispatcher.html
 /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
-pub struct PriorityDispatcher<P, T>
+pub struct PriorityDispatcher<P, T, X = Identity>
 where
     P: Ord,
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
 {
-    events: PriorityListenerMap<P, T>,
+    events: PriorityListenerMap<X::Key, P, T>,
+    parent: Option<Weak<Mutex<Self>>>,
+    panic_policy: PanicPolicy,
+    #[cfg(feature = "parallel")]
+    parallel_tier_size: Option<usize>,
+    guards: HashMap<X::Key, Guard<T>>,
+    disabled_keys: HashSet<X::Key>,
+    skipped_dispatches: HashMap<X::Key, usize>,
+    drop_hook: Option<EventDropHook<T>>,
+    listener_hooks: Option<ListenerHooks<T, PriorityDispatcherResult>>,
+    stages: Vec<String>,
+    /// Stores the `TypedListener<T>`s of each event-key, `T` implementing
+    /// [`EventResult`].
+    typed_listeners: TypeMap<X::Key>,
 }
 
-impl<P, T> Default for PriorityDispatcher<P, T>
+/// A [`PriorityDispatcher`] registering listeners by the variant of
+/// `Enum`-events.
+///
+/// Events carrying fields work without implementing [`Hash`]- and
+/// [`PartialEq`]-traits ignoring those fields.
+///
+/// [`Hash`]: std::hash::Hash
+pub type DiscriminantPriorityDispatcher<P, T> = PriorityDispatcher<P, T, ByDiscriminant>;
+
+impl<P, T, X> Default for PriorityDispatcher<P, T, X>
 where
     P: Ord + Clone,
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
 {
     fn default() -> Self {
         Self {
             events: PriorityListenerMap::new(),
+            parent: None,
+            panic_policy: PanicPolicy::default(),
+            #[cfg(feature = "parallel")]
+            parallel_tier_size: None,
+            guards: HashMap::new(),
+            disabled_keys: HashSet::new(),
+            skipped_dispatches: HashMap::new(),
+            drop_hook: None,
+            listener_hooks: None,
+            stages: Vec::new(),
+            typed_listeners: TypeMap::new(),
         }
     }
 }
 
-impl<P, T> PriorityDispatcher<P, T>
+impl<P, T, X> PriorityDispatcher<P, T, X>
 where
     P: Ord + Clone,
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
 {
+    /// Sets how panics of listeners are handled, they are propagated by
+    /// default.
+    ///
+    /// A [`PanicPolicy::RemoveListener`] removes the panicking listener,
+    /// the event continues to reach the remaining listeners.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{panic_policy::PanicPolicy, sync::PriorityDispatcher};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// let dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default()
+    ///     .panic_policy(PanicPolicy::hook(|listener, _payload| {
+    ///         eprintln!("{} panicked.", listener);
+    ///     }));
+    /// ```
+    #[must_use]
+    pub fn panic_policy(mut self, panic_policy: PanicPolicy) -> Self {
+        self.panic_policy = panic_policy;
+
+        self
+    }
+
+    /// Sets hooks called right before and after each listener receives an
+    /// event, `after` receives the listener's request.
+    ///
+    /// Replaces previously set hooks, see the [`hooks`] module. Within a
+    /// parallel tier, hooks are called on the listener's thread.
+    pub fn set_listener_hooks<B, A>(&mut self, before: B, after: A)
+    where
+        B: Fn(&T, ListenerInfo<'_>) + Send + Sync + 'static,
+        A: Fn(&T, ListenerInfo<'_>, &Option<PriorityDispatcherResult>) + Send + Sync + 'static,
+    {
+        self.listener_hooks = Some(ListenerHooks::new(before, after));
+    }
+
+    /// Dispatches to tiers of at least `min_listeners` listeners in
+    /// parallel via Rayon's thread-pool, smaller tiers are dispatched to
+    /// one listener after another.
+    ///
+    /// Tiers are still dispatched to one after another, ordered by their
+    /// priority-level.
+    /// Within a parallel tier, listeners are called in no particular order
+    /// and a request to stop propagation takes effect once the whole tier
+    /// has been dispatched to.
+    /// Tiers containing an [`Ordered`](crate::order::Ordered) listener are
+    /// always dispatched to one listener after another.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::PriorityDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// // Hundreds of particles share one priority-level.
+    /// let dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default()
+    ///     .parallel_tiers(64);
+    /// ```
+    #[cfg(feature = "parallel")]
+    #[must_use]
+    pub const fn parallel_tiers(mut self, min_listeners: usize) -> Self {
+        self.parallel_tier_size = Some(min_listeners);
+
+        self
+    }
+
+    /// Sets `parent` as parent-dispatcher.
+    ///
+    /// Every event whose propagation has not been stopped by this
+    /// dispatcher's listeners will be dispatched by `parent` afterwards,
+    /// even if this dispatcher has no listener for the event.
+    ///
+    /// Only a weak reference to `parent` is kept, once `parent` is dropped,
+    /// events will no longer be forwarded.
+    ///
+    /// **Note**: Dispatching on the child from within one of `parent`'s
+    /// listeners cannot forward to `parent`, as it is already locked, see
+    /// [`try_dispatch_event`](Self::try_dispatch_event).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{sync::PriorityDispatcher, Mutex};
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// let world = Arc::new(Mutex::new(PriorityDispatcher::<u32, Event>::default()));
+    /// let mut scene = PriorityDispatcher::default();
+    ///
+    /// scene.set_parent(&world);
+    ///
+    /// // Dispatched to the scene's listeners and then to the world's.
+    /// scene.dispatch_event(&Event::EventType);
+    /// ```
+    pub fn set_parent(&mut self, parent: &Arc<Mutex<Self>>) {
+        self.parent = Some(Arc::downgrade(parent));
+    }
+
     /// Adds a [`Listener`] to listen for an `event_identifier`, considering
     /// a given `priority` implementing the [`Ord`]-trait, to sort dispatch-order.
     /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
     ///
-    /// **Note**: If your `Enum` owns fields you need to consider implementing
+    /// Listeners of equal priority-level are dispatched to in the order they
+    /// have been added, even after others of them stopped listening, unless
+    /// their tier is dispatched to in parallel, see
+    /// [`parallel_tiers`](Self::parallel_tiers).
+    ///
+    /// Returns the [`ListenerId`] removing the listener via
+    /// [`remove_listener`](Self::remove_listener).
+    ///
+    /// **Note**: If your `Enum` owns fields you need to consider a
+    /// [`KeyExtract`]-strategy or implementing
     /// the [`Hash`]- and [`PartialEq`]-trait if you want to ignore fields,
     /// see second example for an implementation-suggestion.
     ///
@@ -120,9 +309,20 @@ where
         event_key: T,
         listener: D,
         priority: P,
-    ) {
-        let listener = Box::new(listener);
-        let listener = listener as Box<(dyn PriorityListener<T> + Send + Sync + 'static)>;
+    ) -> ListenerId {
+        self.register(X::extract(&event_key), listener, priority)
+    }
+
+    /// Adds `listener` for `event_key` at `priority` and returns its id.
+    fn register<D: PriorityListener<T> + Send + Sync + 'static>(
+        &mut self,
+        event_key: X::Key,
+        listener: D,
+        priority: P,
+    ) -> ListenerId {
+        let id = ListenerId::next();
+        let listener = Box::new(Registered { id, listener });
+        let listener = listener as Box<dyn PriorityListener<T> + Send + Sync + 'static>;
 
         match self.events.entry(event_key) {
             HashMapEntry::Vacant(vacant_entry) => {
@@ -133,6 +333,10 @@ where
                 vacant_entry.insert(map);
             }
             HashMapEntry::Occupied(mut occupied_entry) => {
+                for listener_collection in occupied_entry.get_mut().values_mut() {
+                    listener_collection.retain(PriorityListener::is_alive);
+                }
+
                 match occupied_entry.get_mut().entry(priority) {
                     BTreeMapEntry::Vacant(vacant_entry) => {
                         vacant_entry.insert(vec![listener]);
@@ -143,31 +347,1426 @@ where
                 }
             }
         }
+
+        id
     }
 
-    /// All [`Listener`]s listening to a passed `event_identifier`
-    /// will be called via their implemented [`on_event`]-method.
-    /// [`Fn`]s returning [`Result`] with `Ok(())` will be retained
-    /// and `Err(SyncDispatchResult::StopListening)` will cause them to
-    /// be removed from the event-dispatcher.
+    /// Adds the closure `listener` to listen for an `event_key`, considering
+    /// a given `priority`, like [`add_listener`](Self::add_listener) does for
+    /// [`PriorityListener`]s.
     ///
-    /// **Notice**: [`Listener`]s will called ordered by their priority-level.
+    /// # Examples
     ///
-    /// [`Listener`]: trait.Listener.html
-    /// [`on_event`]: trait.Listener.html#tymethod.on_event
-    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
-    /// [`Result`]: https://doc.rust-lang.org/std/result/enum.Result.html
-    pub fn dispatch_event(&mut self, event_identifier: &T) {
-        if let Some(prioritised_listener_collection) = self.events.get_mut(event_identifier) {
-            for (_, mut listener_collection) in prioritised_listener_collection.iter_mut() {
-                if let ExecuteRequestsResult::Stopped =
-                    execute_sync_dispatcher_requests(&mut listener_collection, |listener| {
-                        listener.on_event(event_identifier)
+    /// ```rust
+    /// use hey_listen::sync::PriorityDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///
+    /// dispatcher.add_fn(Event::EventType, |_event| None, 1);
+    /// ```
+    pub fn add_fn<F>(&mut self, event_key: T, listener: F, priority: P) -> ListenerId
+    where
+        F: Fn(&T) -> Option<PriorityDispatcherResult> + Send + Sync + 'static,
+    {
+        self.add_listener(event_key, FnListener(listener), priority)
+    }
+
+    /// Adds a [`PriorityListener`] to listen for an `event_key`, considering
+    /// a given `priority`, until the returned [`SubscriptionGuard`] is
+    /// dropped.
+    pub fn subscribe_scoped<D: PriorityListener<T> + Send + Sync + 'static>(
+        &mut self,
+        event_key: T,
+        listener: D,
+        priority: P,
+    ) -> SubscriptionGuard {
+        let (listener, guard) = Scoped::new(listener);
+
+        self.add_listener(event_key, listener, priority);
+
+        guard
+    }
+
+    /// Adds the closure `listener` returning an output of the event's
+    /// [`EventResult`] for an `event_key`, outputs are combined via
+    /// [`dispatch_typed`](Self::dispatch_typed).
+    ///
+    /// Typed listeners are kept apart from [`PriorityListener`]s, other
+    /// dispatches do not reach them. They are counted and removed like any
+    /// other listener, but neither taken, drained, listed in snapshots nor
+    /// frozen, lacking a priority-level.
+    // Taking `event_key` by value mirrors `add_fn`.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn add_typed_fn<F>(&mut self, event_key: T, listener: F)
+    where
+        T: EventResult,
+        F: Fn(&T) -> T::Output + Send + Sync + 'static,
+    {
+        self.typed_listeners
+            .push::<TypedListener<T>>(X::extract(&event_key), Box::new(listener));
+    }
+
+    /// Sets `guard` to decide whether events of `event_key` are dispatched,
+    /// replacing a previously set guard.
+    ///
+    /// The guard is called with each event before any listener receives it.
+    /// If it returns `false`, the dispatch is skipped entirely, the event
+    /// is neither dispatched to listeners nor forwarded to the
+    /// parent-dispatcher, and counted by [`skipped_dispatches`].
+    /// A [`DropHook`] set via [`set_drop_hook`] receives the skipped event.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener};
+    /// use std::sync::{
+    ///     atomic::{AtomicBool, Ordering},
+    ///     Arc,
+    /// };
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Play,
+    /// }
+    ///
+    /// struct Audio;
+    ///
+    /// impl PriorityListener<Event> for Audio {
+    ///     fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> {
+    ///         unreachable!()
+    ///     }
+    /// }
+    ///
+    /// let audio_enabled = Arc::new(AtomicBool::new(false));
+    /// let is_enabled = Arc::clone(&audio_enabled);
+    /// let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///
+    /// dispatcher.add_listener(Event::Play, Audio, 1);
+    /// dispatcher.set_guard(
+    ///     Event::Play,
+    ///     Box::new(move |_| is_enabled.load(Ordering::Relaxed)),
+    /// );
+    ///
+    /// // Audio is disabled, `Audio` does not receive the event.
+    /// dispatcher.dispatch_event(&Event::Play);
+    /// assert_eq!(dispatcher.skipped_dispatches(&Event::Play), 1);
+    /// ```
+    ///
+    /// [`skipped_dispatches`]: Self::skipped_dispatches
+    /// [`set_drop_hook`]: Self::set_drop_hook
+    pub fn set_guard(&mut self, event_key: T, guard: Guard<T>) {
+        self.guards.insert(X::extract(&event_key), guard);
+    }
+
+    /// Removes the guard of `event_key` and returns it, events of
+    /// `event_key` are dispatched unconditionally again.
+    pub fn remove_guard(&mut self, event_key: &T) -> Option<Guard<T>> {
+        self.guards.remove(&X::extract(event_key))
+    }
+
+    /// Sets `drop_hook` to receive every event a guard rejected, with
+    /// [`DropReason::Guarded`], replacing a previously set hook.
+    pub fn set_drop_hook<H>(&mut self, drop_hook: H)
+    where
+        H: DropHook<T> + Send + Sync + 'static,
+    {
+        self.drop_hook = Some(Box::new(drop_hook));
+    }
+
+    /// Disables `event_key`, dispatches of it are skipped until it is
+    /// enabled again via [`enable_key`].
+    ///
+    /// Listeners of `event_key` stay registered, skipped dispatches are
+    /// counted by [`skipped_dispatches`], e.g. to mute a noisy subsystem or
+    /// to toggle a feature at runtime.
+    /// Events are not built when skipped, hence a drop-hook receives none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::PriorityDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Telemetry,
+    /// }
+    ///
+    /// let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    /// dispatcher.add_fn(Event::Telemetry, |_event| unreachable!(), 1);
+    ///
+    /// dispatcher.disable_key(Event::Telemetry);
+    /// dispatcher.dispatch_event(&Event::Telemetry);
+    ///
+    /// assert!(!dispatcher.is_key_enabled(&Event::Telemetry));
+    /// assert_eq!(dispatcher.skipped_dispatches(&Event::Telemetry), 1);
+    /// ```
+    ///
+    /// [`enable_key`]: Self::enable_key
+    /// [`skipped_dispatches`]: Self::skipped_dispatches
+    pub fn disable_key(&mut self, event_key: T) {
+        self.disabled_keys.insert(X::extract(&event_key));
+    }
+
+    /// Enables `event_key` disabled via [`disable_key`], returns whether it
+    /// has been disabled.
+    ///
+    /// [`disable_key`]: Self::disable_key
+    pub fn enable_key(&mut self, event_key: &T) -> bool {
+        self.disabled_keys.remove(&X::extract(event_key))
+    }
+
+    /// Returns whether dispatches of `event_key` reach its listeners, as
+    /// it has not been disabled via [`disable_key`].
+    ///
+    /// [`disable_key`]: Self::disable_key
+    #[must_use]
+    pub fn is_key_enabled(&self, event_key: &T) -> bool {
+        !self.disabled_keys.contains(&X::extract(event_key))
+    }
+
+    /// Returns how many dispatches of `event_key` have been skipped, as its
+    /// guard returned `false` or it has been disabled.
+    #[must_use]
+    pub fn skipped_dispatches(&self, event_key: &T) -> usize {
+        self.skipped_dispatches
+            .get(&X::extract(event_key))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns how many listeners listen for `event_key` across all
+    /// priority-levels, including typed listeners.
+    #[must_use]
+    pub fn listener_count(&self, event_key: &T) -> usize {
+        let event_key = X::extract(event_key);
+        let prioritised_listener_count = self
+            .events
+            .get(&event_key)
+            .into_iter()
+            .flat_map(BTreeMap::values)
+            .flatten()
+            .filter(|listener| listener.is_alive())
+            .count();
+
+        prioritised_listener_count + self.typed_listeners.len(&event_key)
+    }
+
+    /// Returns how many listeners listen for any event-key, including typed
+    /// listeners.
+    #[must_use]
+    pub fn total_listeners(&self) -> usize {
+        let prioritised_listener_count = self
+            .events
+            .values()
+            .flat_map(BTreeMap::values)
+            .flatten()
+            .filter(|listener| listener.is_alive())
+            .count();
+
+        prioritised_listener_count + self.typed_listeners.total_len()
+    }
+
+    /// Returns the event-keys at least one listener listens for, including
+    /// typed listeners.
+    #[must_use]
+    pub fn event_keys(&self) -> HashSet<X::Key> {
+        let mut event_keys: HashSet<_> = self
+            .events
+            .iter()
+            .filter(|(_, prioritised_listeners)| {
+                prioritised_listeners
+                    .values()
+                    .flatten()
+                    .any(PriorityListener::is_alive)
+            })
+            .map(|(event_key, _)| event_key.clone())
+            .collect();
+
+        event_keys.extend(
+            self.typed_listeners
+                .iter()
+                .filter(|(_, _, count)| *count > 0)
+                .map(|(event_key, _, _)| event_key.clone()),
+        );
+
+        event_keys
+    }
+
+    /// Returns whether no listener listens for any event-key, including
+    /// typed listeners.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.total_listeners() == 0
+    }
+
+    /// Takes a [`Snapshot`] of all registered listeners, except typed
+    /// listeners.
+    ///
+    /// [`Snapshot`]: crate::snapshot::Snapshot
+    #[must_use]
+    pub fn snapshot(&self) -> Snapshot<X::Key, P> {
+        let listeners = self
+            .events
+            .iter()
+            .flat_map(|(event_key, prioritised_listeners)| {
+                prioritised_listeners
+                    .iter()
+                    .flat_map(move |(priority, listeners)| {
+                        listeners.iter().filter(|listener| listener.is_alive()).map(
+                            move |listener| ListenerEntry {
+                                event_key: event_key.clone(),
+                                priority: priority.clone(),
+                                name: listener.name().to_string(),
+                            },
+                        )
                     })
-                {
-                    break;
-                }
-            }
-        }
+            })
+            .collect();
+
+        Snapshot { listeners }
+    }
+
+    /// Removes all listeners of `event_key` and returns them alongside
+    /// their priority, ordered by priority-level.
+    ///
+    /// Boxed listeners implement [`PriorityListener`] themselves, hence can
+    /// be added to another dispatcher. Typed listeners are removed as well,
+    /// but not returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// struct Physics;
+    ///
+    /// impl PriorityListener<Event> for Physics {
+    ///     fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> { None }
+    /// }
+    ///
+    /// let mut old_world: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    /// let mut new_world: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///
+    /// old_world.add_listener(Event::Tick, Physics, 1);
+    ///
+    /// for (priority, listener) in old_world.take_listeners(&Event::Tick) {
+    ///     new_world.add_listener(Event::Tick, listener, priority);
+    /// }
+    ///
+    /// assert!(old_world.snapshot().listeners.is_empty());
+    /// assert_eq!(new_world.snapshot().listeners.len(), 1);
+    /// ```
+    pub fn take_listeners(
+        &mut self,
+        event_key: &T,
+    ) -> Vec<(P, Box<dyn PriorityListener<T> + Send + Sync + 'static>)> {
+        let event_key = X::extract(event_key);
+
+        self.typed_listeners.remove(&event_key);
+        self.events
+            .remove(&event_key)
+            .into_iter()
+            .flat_map(unprioritise)
+            .collect()
+    }
+
+    /// Removes all listeners and returns them alongside the key they have
+    /// been registered by and their priority, typed listeners are removed
+    /// but not returned.
+    ///
+    /// Another dispatcher adds the listeners via its [`Extend`]-implementation.
+    #[allow(clippy::type_complexity)]
+    pub fn drain(
+        &mut self,
+    ) -> Vec<(
+        X::Key,
+        P,
+        Box<dyn PriorityListener<T> + Send + Sync + 'static>,
+    )> {
+        self.typed_listeners.clear();
+        self.events
+            .drain()
+            .flat_map(|(event_key, prioritised_listeners)| {
+                unprioritise(prioritised_listeners)
+                    .map(move |(priority, listener)| (event_key.clone(), priority, listener))
+            })
+            .collect()
+    }
+
+    /// Removes all listeners of `event_key` across all priority-levels,
+    /// including typed listeners, and returns how many live listeners have
+    /// been removed.
+    pub fn remove_all_listeners(&mut self, event_key: &T) -> usize {
+        let event_key = X::extract(event_key);
+        let prioritised_listener_count =
+            self.events
+                .remove(&event_key)
+                .map_or(0, |prioritised_listeners| {
+                    prioritised_listeners
+                        .values()
+                        .flatten()
+                        .filter(|listener| listener.is_alive())
+                        .count()
+                });
+
+        prioritised_listener_count + self.typed_listeners.remove(&event_key)
+    }
+
+    /// Removes all listeners of all event-keys, including typed listeners,
+    /// keeping the dispatcher's settings, e.g. its guards, disabled
+    /// event-keys, and stages.
+    pub fn clear(&mut self) {
+        self.events.clear();
+        self.typed_listeners.clear();
+    }
+
+    /// Lists the priority-levels of `event_key`'s live listeners, ordered by
+    /// priority-level, alongside the amount of live listeners per level.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// struct System;
+    ///
+    /// impl PriorityListener<Event> for System {
+    ///     fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> { None }
+    /// }
+    ///
+    /// let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///
+    /// dispatcher.add_listener(Event::Tick, System, 2);
+    /// dispatcher.add_listener(Event::Tick, System, 1);
+    /// dispatcher.add_listener(Event::Tick, System, 2);
+    ///
+    /// let tiers: Vec<_> = dispatcher.tiers(&Event::Tick).collect();
+    /// assert_eq!(tiers, [(&1, 1), (&2, 2)]);
+    ///
+    /// dispatcher.remove_tier(&Event::Tick, &2);
+    /// assert_eq!(dispatcher.tiers(&Event::Tick).count(), 1);
+    /// ```
+    pub fn tiers(&self, event_key: &T) -> impl Iterator<Item = (&P, usize)> {
+        self.events
+            .get(&X::extract(event_key))
+            .into_iter()
+            .flat_map(|prioritised_listeners| {
+                prioritised_listeners
+                    .iter()
+                    .map(|(priority, listeners)| {
+                        let alive = listeners
+                            .iter()
+                            .filter(|listener| listener.is_alive())
+                            .count();
+
+                        (priority, alive)
+                    })
+                    .filter(|(_, alive)| *alive > 0)
+            })
+    }
+
+    /// Removes all listeners of `event_key` with the priority-level
+    /// `priority` and returns them.
+    pub fn remove_tier(
+        &mut self,
+        event_key: &T,
+        priority: &P,
+    ) -> Vec<Box<dyn PriorityListener<T> + Send + Sync + 'static>> {
+        self.events
+            .get_mut(&X::extract(event_key))
+            .and_then(|prioritised_listeners| prioritised_listeners.remove(priority))
+            .unwrap_or_default()
+    }
+
+    /// Removes all listeners registered under `owner` across all
+    /// event-keys and returns how many have been removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{
+    ///     hub::Owned, Owner, PriorityDispatcher, PriorityDispatcherResult, PriorityListener,
+    /// };
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    ///     Render,
+    /// }
+    ///
+    /// struct System;
+    ///
+    /// impl PriorityListener<Event> for System {
+    ///     fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> { None }
+    /// }
+    ///
+    /// let plugin = Owner::new(7);
+    /// let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///
+    /// dispatcher.add_listener(Event::Tick, Owned::new(plugin, System), 1);
+    /// dispatcher.add_listener(Event::Render, Owned::new(plugin, System), 1);
+    /// dispatcher.add_listener(Event::Render, System, 2);
+    ///
+    /// // Unloading the plugin.
+    /// assert_eq!(dispatcher.remove_owner(plugin), 2);
+    /// assert_eq!(dispatcher.snapshot().listeners.len(), 1);
+    /// ```
+    pub fn remove_owner(&mut self, owner: Owner) -> usize {
+        self.retain_listeners(|listener| listener.owner() != Some(owner))
     }
+
+    /// Removes the listener added under `id`, returns whether it has been
+    /// found.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::PriorityDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///
+    /// let id = dispatcher.add_fn(Event::Tick, |_event| None, 1);
+    /// dispatcher.add_fn(Event::Tick, |_event| None, 1);
+    ///
+    /// assert!(dispatcher.remove_listener(id));
+    /// assert!(!dispatcher.remove_listener(id));
+    /// assert_eq!(dispatcher.snapshot().listeners.len(), 1);
+    /// ```
+    pub fn remove_listener(&mut self, id: ListenerId) -> bool {
+        self.retain_listeners(|listener| listener.listener_id() != Some(id)) > 0
+    }
+
+    /// Removes all listeners that are no longer alive, e.g. listeners
+    /// created via [`weakly`](crate::fns::weakly) whose shared state has
+    /// been dropped, and returns how many have been removed.
+    ///
+    /// Adding a listener purges the dead listeners of its event-key,
+    /// calling this periodically bounds the memory of event-keys that
+    /// rarely see new listeners or events.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{fns::weakly, sync::PriorityDispatcher};
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// let particle = Arc::new(());
+    /// let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///
+    /// dispatcher.add_listener(Event::Tick, weakly(&particle, |_, _| None), 1);
+    /// drop(particle);
+    ///
+    /// assert_eq!(dispatcher.purge_dead(), 1);
+    /// assert!(dispatcher.snapshot().listeners.is_empty());
+    /// ```
+    pub fn purge_dead(&mut self) -> usize {
+        self.retain_listeners(PriorityListener::is_alive)
+    }
+
+    /// Retains only the listeners `keep` returns `true` for across all
+    /// event-keys and returns how many have been removed.
+    fn retain_listeners<F>(&mut self, mut keep: F) -> usize
+    where
+        F: FnMut(&EventListener<T>) -> bool,
+    {
+        let mut removed = 0;
+
+        for listener_collection in self.events.values_mut().flat_map(BTreeMap::values_mut) {
+            let listeners = listener_collection.len();
+
+            listener_collection.retain(|listener| keep(listener));
+            removed += listeners - listener_collection.len();
+        }
+
+        removed
+    }
+
+    /// Freezes the dispatcher into a [`FrozenDispatcher`], dispatching
+    /// without locking but no longer accepting listeners.
+    ///
+    /// The parent-dispatcher is dropped, as forwarding to it would require
+    /// locking, guards, disabled keys, the drop-hook and listener hooks are
+    /// dropped as well.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener};
+    /// use std::thread;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// struct Physics;
+    ///
+    /// impl PriorityListener<Event> for Physics {
+    ///     fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> { None }
+    /// }
+    ///
+    /// let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    /// dispatcher.add_listener(Event::Tick, Physics, 1);
+    ///
+    /// let frozen = dispatcher.freeze();
+    /// let shared = frozen.clone();
+    ///
+    /// thread::spawn(move || shared.dispatch_event(&Event::Tick))
+    ///     .join()
+    ///     .unwrap();
+    /// frozen.dispatch_event(&Event::Tick);
+    /// ```
+    #[must_use]
+    pub fn freeze(self) -> FrozenDispatcher<P, T, X> {
+        FrozenDispatcher::new(self.events, self.panic_policy)
+    }
+
+    /// All [`Listener`]s listening to a passed `event_identifier`
+    /// will be called via their implemented [`on_event`]-method.
+    /// [`Fn`]s returning [`Result`] with `Ok(())` will be retained
+    /// and `Err(SyncDispatchResult::StopListening)` will cause them to
+    /// be removed from the event-dispatcher.
+    ///
+    /// **Notice**: [`Listener`]s will called ordered by their priority-level.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`on_event`]: trait.Listener.html#tymethod.on_event
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Result`]: https://doc.rust-lang.org/std/result/enum.Result.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent-dispatcher is already dispatching on this
+    /// thread, see [`try_dispatch_event`](Self::try_dispatch_event).
+    pub fn dispatch_event(&mut self, event_identifier: &T) {
+        self.try_dispatch_event(event_identifier)
+            .expect("Parent-dispatcher is already dispatching on this thread");
+    }
+
+    /// Dispatches `event_identifier` like [`dispatch_event`], but fails
+    /// instead of deadlocking when forwarding re-enters a dispatcher.
+    ///
+    /// This happens when a listener of the parent-dispatcher dispatches on
+    /// this dispatcher, while the parent is still locked by its own
+    /// dispatch.
+    ///
+    /// Only the `try_`-methods report re-entrant dispatches,
+    /// [`dispatch_event`], [`dispatch_with`](Self::dispatch_with),
+    /// [`dispatch_inherited`](Self::dispatch_inherited), and
+    /// [`dispatch_event_between`](Self::dispatch_event_between) panic
+    /// instead.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::ReentrantDispatch`] if the parent-dispatcher is
+    /// already dispatching on this thread.
+    /// The event has reached this dispatcher's listeners nonetheless.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{
+    ///     sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener},
+    ///     Error, Mutex,
+    /// };
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct Echo(Arc<Mutex<PriorityDispatcher<u32, Event>>>);
+    ///
+    /// impl PriorityListener<Event> for Echo {
+    ///     fn on_event(&self, event: &Event) -> Option<PriorityDispatcherResult> {
+    ///         let result = self.0.lock().try_dispatch_event(event);
+    ///         assert!(matches!(result, Err(Error::ReentrantDispatch)));
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// let world = Arc::new(Mutex::new(PriorityDispatcher::<u32, Event>::default()));
+    /// let scene = Arc::new(Mutex::new(PriorityDispatcher::default()));
+    ///
+    /// scene.lock().set_parent(&world);
+    /// world.lock().add_listener(Event::EventType, Echo(Arc::clone(&scene)), 1);
+    ///
+    /// // The echo dispatches on the scene, which would deadlock forwarding
+    /// // back to the world.
+    /// world.lock().dispatch_event(&Event::EventType);
+    /// ```
+    ///
+    /// [`dispatch_event`]: Self::dispatch_event
+    pub fn try_dispatch_event(&mut self, event_identifier: &T) -> Result<(), Error> {
+        self.dispatch(&X::extract(event_identifier), .., || event_identifier)
+    }
+
+    /// Locks `dispatcher` and dispatches `event_identifier` like
+    /// [`try_dispatch_event`](Self::try_dispatch_event), unless `dispatcher`
+    /// is already dispatching on this thread.
+    ///
+    /// Listeners holding their own dispatcher dispatch on it via this
+    /// function, as locking it directly would deadlock.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::ReentrantDispatch`] if `dispatcher` is already
+    /// dispatching on this thread, including the threads a
+    /// [parallel tier](Self::parallel_tiers) is dispatched to on, the event
+    /// has not been dispatched.
+    /// Fails like [`try_dispatch_event`](Self::try_dispatch_event)
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{
+    ///     sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener},
+    ///     Error, Mutex,
+    /// };
+    /// use std::sync::{Arc, Weak};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct Echo(Weak<Mutex<PriorityDispatcher<u32, Event>>>);
+    ///
+    /// impl PriorityListener<Event> for Echo {
+    ///     fn on_event(&self, event: &Event) -> Option<PriorityDispatcherResult> {
+    ///         let dispatcher = self.0.upgrade()?;
+    ///         let result = PriorityDispatcher::try_dispatch_locked(&dispatcher, event);
+    ///         assert!(matches!(result, Err(Error::ReentrantDispatch)));
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// let dispatcher = Arc::new(Mutex::new(PriorityDispatcher::<u32, Event>::default()));
+    ///
+    /// dispatcher
+    ///     .lock()
+    ///     .add_listener(Event::EventType, Echo(Arc::downgrade(&dispatcher)), 1);
+    ///
+    /// assert!(PriorityDispatcher::try_dispatch_locked(&dispatcher, &Event::EventType).is_ok());
+    /// ```
+    pub fn try_dispatch_locked(
+        dispatcher: &Mutex<Self>,
+        event_identifier: &T,
+    ) -> Result<(), Error> {
+        if Dispatching::contains(dispatcher.data_ptr() as usize) {
+            return Err(Error::ReentrantDispatch);
+        }
+
+        dispatcher.lock().try_dispatch_event(event_identifier)
+    }
+
+    /// Dispatches `event_identifier` like [`dispatch_event`], but as
+    /// continuation of a dispatch that already reached `inherited_priority`,
+    /// e.g. when a listener of another dispatcher forwards its event.
+    ///
+    /// Listeners with a priority-level ordered before `inherited_priority`
+    /// are skipped, the others are called ordered by their priority-level.
+    /// Passing `None` dispatches to all listeners.
+    ///
+    /// Events forwarded to the parent-dispatcher do not inherit the
+    /// priority, the parent dispatches them to all of its listeners.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct Early;
+    ///
+    /// impl PriorityListener<Event> for Early {
+    ///     fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> {
+    ///         unreachable!()
+    ///     }
+    /// }
+    ///
+    /// let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///
+    /// dispatcher.add_listener(Event::EventType, Early, 1);
+    ///
+    /// // The event already passed priority-level 2, `Early` is skipped.
+    /// dispatcher.dispatch_inherited(&Event::EventType, Some(&2));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent-dispatcher is already dispatching on this
+    /// thread, see [`try_dispatch_event`](Self::try_dispatch_event).
+    ///
+    /// [`dispatch_event`]: Self::dispatch_event
+    pub fn dispatch_inherited(&mut self, event_identifier: &T, inherited_priority: Option<&P>) {
+        let priorities = (
+            inherited_priority.map_or(Bound::Unbounded, Bound::Included),
+            Bound::Unbounded,
+        );
+
+        self.dispatch(&X::extract(event_identifier), priorities, || {
+            event_identifier
+        })
+        .expect("Parent-dispatcher is already dispatching on this thread");
+    }
+
+    /// Dispatches `event_identifier` like [`dispatch_event`], but only to
+    /// listeners whose priority-level lies within `priorities`, e.g. to
+    /// update a single subsystem.
+    ///
+    /// Events forwarded to the parent-dispatcher are not restricted, the
+    /// parent dispatches them to all of its listeners.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Redraw,
+    /// }
+    ///
+    /// struct Layer;
+    /// struct Background;
+    ///
+    /// impl PriorityListener<Event> for Layer {
+    ///     fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> { None }
+    /// }
+    ///
+    /// impl PriorityListener<Event> for Background {
+    ///     fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> {
+    ///         unreachable!()
+    ///     }
+    /// }
+    ///
+    /// let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///
+    /// dispatcher.add_listener(Event::Redraw, Layer, 1);
+    /// dispatcher.add_listener(Event::Redraw, Layer, 2);
+    /// dispatcher.add_listener(Event::Redraw, Background, 10);
+    ///
+    /// // Only the layers are redrawn.
+    /// dispatcher.dispatch_event_between(&Event::Redraw, 1..=2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent-dispatcher is already dispatching on this
+    /// thread, see [`try_dispatch_event`](Self::try_dispatch_event).
+    ///
+    /// [`dispatch_event`]: Self::dispatch_event
+    pub fn dispatch_event_between<R>(&mut self, event_identifier: &T, priorities: R)
+    where
+        R: RangeBounds<P>,
+    {
+        self.dispatch(&X::extract(event_identifier), priorities, || {
+            event_identifier
+        })
+        .expect("Parent-dispatcher is already dispatching on this thread");
+    }
+
+    /// All [`PriorityListener`]s listening to `event_key` will receive the
+    /// event constructed by `build_event`, ordered by their priority-level.
+    ///
+    /// `build_event` is only called if at least one [`PriorityListener`]
+    /// listens to `event_key`, expensive events will not be built for nobody.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::PriorityDispatcher;
+    /// use std::hash::{Hash, Hasher};
+    /// use std::mem::discriminant;
+    ///
+    /// #[derive(Clone)]
+    /// enum Event {
+    ///     Loaded(Vec<u8>),
+    /// }
+    ///
+    /// impl Hash for Event {
+    ///     fn hash<H: Hasher>(&self, _state: &mut H) {}
+    /// }
+    ///
+    /// impl PartialEq for Event {
+    ///     fn eq(&self, other: &Event) -> bool {
+    ///         discriminant(self) == discriminant(other)
+    ///     }
+    /// }
+    ///
+    /// impl Eq for Event {}
+    ///
+    /// let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///
+    /// // Nobody listens, the closure will not be called.
+    /// dispatcher.dispatch_with(&Event::Loaded(Vec::new()), || unreachable!());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent-dispatcher is already dispatching on this
+    /// thread, see [`try_dispatch_event`](Self::try_dispatch_event).
+    ///
+    /// [`PriorityListener`]: super::PriorityListener
+    pub fn dispatch_with<F>(&mut self, event_key: &T, build_event: F)
+    where
+        F: FnOnce() -> T,
+    {
+        self.dispatch(&X::extract(event_key), .., build_event)
+            .expect("Parent-dispatcher is already dispatching on this thread");
+    }
+
+    /// Dispatches `event` to the listeners registered by `id`, without
+    /// deriving the key from `event`, e.g. for callers caching the id of an
+    /// [`Interned`](crate::key::Interned) name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent-dispatcher is already dispatching on this
+    /// thread, see [`try_dispatch_event`](Self::try_dispatch_event).
+    pub fn dispatch_by_id(&mut self, id: KeyId, event: &T)
+    where
+        X: KeyExtract<T, Key = KeyId>,
+    {
+        self.dispatch(&id, .., || event)
+            .expect("Parent-dispatcher is already dispatching on this thread");
+    }
+
+    /// Dispatches `event` to the typed listeners of its key and returns
+    /// their outputs combined via [`EventResult::combine`], in the order the
+    /// listeners have been added in.
+    ///
+    /// Returns the [`Default`] output if no typed listener listens for the
+    /// key. Other listeners, guards, and parent-dispatchers are not
+    /// involved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{sync::PriorityDispatcher, typed::EventResult};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Damage,
+    ///     Heal,
+    /// }
+    ///
+    /// impl EventResult for Event {
+    ///     type Output = i32;
+    ///
+    ///     fn combine(total: &mut i32, modifier: i32) {
+    ///         *total += modifier;
+    ///     }
+    /// }
+    ///
+    /// let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///
+    /// dispatcher.add_typed_fn(Event::Damage, |_event| 10);
+    /// dispatcher.add_typed_fn(Event::Damage, |_event| -2);
+    ///
+    /// assert_eq!(dispatcher.dispatch_typed(&Event::Damage), 8);
+    /// assert_eq!(dispatcher.dispatch_typed(&Event::Heal), 0);
+    /// ```
+    pub fn dispatch_typed(&self, event: &T) -> T::Output
+    where
+        T: EventResult,
+    {
+        let mut total = T::Output::default();
+
+        for listener in self
+            .typed_listeners
+            .get::<TypedListener<T>>(X::extract(event))
+        {
+            T::combine(&mut total, listener(event));
+        }
+
+        total
+    }
+
+    /// Dispatches `event` to as many listeners as fit in `budget`, ordered
+    /// by their priority-level like [`dispatch_event`](Self::dispatch_event).
+    ///
+    /// Returns a [`DispatchCursor`] if listeners are left, passing it to
+    /// [`resume_dispatch`](Self::resume_dispatch) continues with them,
+    /// e.g. during the next frame.
+    /// At least one listener is dispatched to per call, even if it exceeds
+    /// `budget`.
+    /// Tiers are dispatched to one listener after another, even if
+    /// [`parallel_tiers`](Self::parallel_tiers) has been set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent-dispatcher is already dispatching on this
+    /// thread, once all listeners have been dispatched to.
+    pub fn dispatch_event_budgeted(
+        &mut self,
+        event: &T,
+        budget: impl Into<Budget>,
+    ) -> Option<DispatchCursor<T, P>> {
+        self.resume_dispatch(DispatchCursor::new(event.clone()), budget)
+    }
+
+    /// Continues the dispatch of `cursor` started via
+    /// [`dispatch_event_budgeted`](Self::dispatch_event_budgeted) with as
+    /// many of the remaining listeners as fit in `budget`.
+    ///
+    /// Returns the cursor if listeners are still left, once all have been
+    /// dispatched to the event is forwarded to the parent-dispatcher.
+    /// Cursors created via [`DispatchCursor::new`] are checked against
+    /// disabled event-keys and guards when first resumed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent-dispatcher is already dispatching on this
+    /// thread, once all listeners have been dispatched to.
+    pub fn resume_dispatch(
+        &mut self,
+        mut cursor: DispatchCursor<T, P>,
+        budget: impl Into<Budget>,
+    ) -> Option<DispatchCursor<T, P>> {
+        let mut budget = budget.into().start();
+        let _dispatching = Dispatching::enter(self as *const Self as usize);
+        let event_key = X::extract(&cursor.event);
+
+        if cursor.tier.is_none() {
+            let is_guarded = self
+                .guards
+                .get(&event_key)
+                .map_or(false, |guard| !guard(&cursor.event));
+
+            if is_guarded || self.disabled_keys.contains(&event_key) {
+                if is_guarded {
+                    if let Some(drop_hook) = &self.drop_hook {
+                        drop_hook.on_dropped(&cursor.event, DropReason::Guarded);
+                    }
+                }
+
+                *self.skipped_dispatches.entry(event_key).or_default() += 1;
+
+                return None;
+            }
+        }
+
+        let panic_policy = &self.panic_policy;
+        let listener_hooks = self.listener_hooks.as_ref();
+
+        if let Some(prioritised_listener_collection) = self.events.get_mut(&event_key) {
+            let tiers = match cursor.tier.clone() {
+                Some(tier) => prioritised_listener_collection.range_mut(tier..),
+                None => prioritised_listener_collection.range_mut(..),
+            };
+
+            for (priority, listener_collection) in tiers {
+                let mut index = match (&cursor.tier, cursor.last_listener) {
+                    (Some(tier), Some(last_listener)) if tier == priority => listener_collection
+                        .partition_point(|listener| listener.listener_id() <= Some(last_listener)),
+                    _ => 0,
+                };
+
+                while let Some(listener) = listener_collection.get(index) {
+                    if !budget.spend() {
+                        return Some(cursor);
+                    }
+
+                    cursor.tier = Some(priority.clone());
+                    cursor.last_listener = listener.listener_id();
+
+                    let event = &cursor.event;
+                    let request = hooks::surround(listener_hooks, event, listener.name(), || {
+                        panic_policy.guard(
+                            listener.name(),
+                            PriorityDispatcherResult::StopListening,
+                            || listener.on_event(event),
+                        )
+                    });
+
+                    match request {
+                        None => index += 1,
+                        Some(PriorityDispatcherResult::StopListening) => {
+                            listener_collection.remove(index);
+                        }
+                        Some(PriorityDispatcherResult::StopPropagation) => return None,
+                        Some(PriorityDispatcherResult::StopListeningAndPropagation) => {
+                            listener_collection.remove(index);
+
+                            return None;
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(parent) = self.parent.as_ref().and_then(Weak::upgrade) {
+            assert!(
+                !Dispatching::contains(parent.data_ptr() as usize),
+                "Parent-dispatcher is already dispatching on this thread"
+            );
+
+            parent
+                .lock()
+                .dispatch_forwarded(&event_key, &cursor.event)
+                .expect("Parent-dispatcher is already dispatching on this thread");
+        }
+
+        None
+    }
+
+    /// Dispatches the event built by `build_event` to all listeners of
+    /// `event_key` whose priority-level lies within `priorities`, building it
+    /// only if there is at least one such listener.
+    fn dispatch<E, F, R>(
+        &mut self,
+        event_key: &X::Key,
+        priorities: R,
+        build_event: F,
+    ) -> Result<(), Error>
+    where
+        E: Borrow<T>,
+        F: FnOnce() -> E,
+        R: RangeBounds<P>,
+    {
+        if self.disabled_keys.contains(event_key) {
+            *self
+                .skipped_dispatches
+                .entry(event_key.clone())
+                .or_default() += 1;
+
+            return Ok(());
+        }
+
+        let address = self as *const Self as usize;
+        let _dispatching = Dispatching::enter(address);
+        let is_skipped = |priority: &P| !priorities.contains(priority);
+        let panic_policy = &self.panic_policy;
+        let listener_hooks = self.listener_hooks.as_ref();
+        #[cfg(feature = "parallel")]
+        let parallel_tier_size = self.parallel_tier_size;
+        let prioritised_listener_collection =
+            self.events
+                .get_mut(event_key)
+                .filter(|prioritised_listener_collection| {
+                    !prioritised_listener_collection
+                        .iter()
+                        .all(|(priority, listeners)| is_skipped(priority) || listeners.is_empty())
+                });
+        let parent = self.parent.as_ref().and_then(Weak::upgrade);
+
+        if prioritised_listener_collection.is_none() && parent.is_none() {
+            return Ok(());
+        }
+
+        let event = build_event();
+        let event = event.borrow();
+
+        if let Some(guard) = self.guards.get(event_key) {
+            if !guard(event) {
+                *self
+                    .skipped_dispatches
+                    .entry(event_key.clone())
+                    .or_default() += 1;
+
+                if let Some(drop_hook) = &self.drop_hook {
+                    drop_hook.on_dropped(event, DropReason::Guarded);
+                }
+
+                return Ok(());
+            }
+        }
+
+        if let Some(prioritised_listener_collection) = prioritised_listener_collection {
+            for (priority, listener_collection) in prioritised_listener_collection.iter_mut() {
+                if is_skipped(priority) {
+                    continue;
+                }
+
+                let call = |listener: &EventListener<T>| {
+                    hooks::surround(listener_hooks, event, listener.name(), || {
+                        panic_policy.guard(
+                            listener.name(),
+                            PriorityDispatcherResult::StopListening,
+                            || listener.on_event(event),
+                        )
+                    })
+                };
+                #[cfg(feature = "parallel")]
+                let result = if parallel_tier_size.map_or(false, |min_listeners| {
+                    listener_collection.len() >= min_listeners
+                }) && !listener_collection
+                    .iter()
+                    .any(PriorityListener::requires_order)
+                {
+                    execute_parallel_dispatcher_requests(listener_collection, |listener| {
+                        // The workers would block on the dispatcher's lock as
+                        // well, hence they are marked as dispatching too.
+                        let _dispatching = Dispatching::enter(address);
+
+                        call(listener)
+                    })
+                } else {
+                    execute_sync_dispatcher_requests(listener_collection, call)
+                };
+                #[cfg(not(feature = "parallel"))]
+                let result = execute_sync_dispatcher_requests(listener_collection, call);
+
+                if matches!(result, ExecuteRequestsResult::Stopped) {
+                    return Ok(());
+                }
+            }
+        }
+
+        if let Some(parent) = parent {
+            // Locking a parent dispatching on this thread would deadlock.
+            if Dispatching::contains(parent.data_ptr() as usize) {
+                return Err(Error::ReentrantDispatch);
+            }
+
+            parent.lock().dispatch_forwarded(event_key, event)?;
+        }
+
+        Ok(())
+    }
+
+    /// Dispatches an `event` forwarded by a child-dispatcher.
+    fn dispatch_forwarded(&mut self, event_key: &X::Key, event: &T) -> Result<(), Error> {
+        self.dispatch(event_key, .., || event)
+    }
+}
+
+/// Adds listeners returned by [`PriorityDispatcher::drain`] by the key they
+/// have been registered by and their priority, migrating them between
+/// dispatchers keyed by other means than the event itself.
+impl<P, T, X>
+    Extend<(
+        X::Key,
+        P,
+        Box<dyn PriorityListener<T> + Send + Sync + 'static>,
+    )> for PriorityDispatcher<P, T, X>
+where
+    P: Ord + Clone,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    fn extend<I>(&mut self, listeners: I)
+    where
+        I: IntoIterator<
+            Item = (
+                X::Key,
+                P,
+                Box<dyn PriorityListener<T> + Send + Sync + 'static>,
+            ),
+        >,
+    {
+        for (event_key, priority, listener) in listeners {
+            self.register(event_key, listener, priority);
+        }
+    }
+}
+
+impl<P, T, X> Resumable<T, P> for PriorityDispatcher<P, T, X>
+where
+    P: Ord + Clone,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    fn resume_dispatch(
+        &mut self,
+        cursor: DispatchCursor<T, P>,
+        budget: Budget,
+    ) -> Option<DispatchCursor<T, P>> {
+        Self::resume_dispatch(self, cursor, budget)
+    }
+}
+
+impl<T, X> PriorityDispatcher<usize, T, X>
+where
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    /// Appends the named `stages` in the given order, listeners of an
+    /// earlier stage are dispatched to before listeners of a later one.
+    ///
+    /// Each stage's priority-level is its position, stages defined by later
+    /// calls follow those defined earlier.
+    /// Defining a stage with an already existing name keeps the stage at
+    /// its original position.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// struct Renderer;
+    ///
+    /// impl PriorityListener<Event> for Renderer {
+    ///     fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> { None }
+    /// }
+    ///
+    /// let mut dispatcher: PriorityDispatcher<usize, Event> = PriorityDispatcher::default();
+    ///
+    /// dispatcher.define_stages(["input", "logic", "render"]);
+    ///
+    /// dispatcher.add_listener_to_stage(Event::Tick, "render", Renderer)
+    ///     .expect("Stage does not exist");
+    /// assert!(dispatcher.add_listener_to_stage(Event::Tick, "audio", Renderer).is_err());
+    /// ```
+    pub fn define_stages<I, S>(&mut self, stages: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for stage in stages {
+            let stage = stage.as_ref();
+
+            if !self.stages.iter().any(|name| name == stage) {
+                self.stages.push(stage.to_string());
+            }
+        }
+    }
+
+    /// Adds a [`PriorityListener`] to listen for an `event_key` inside the
+    /// stage called `stage`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::UnknownStage`] if no stage called `stage` has
+    /// been defined via [`define_stages`].
+    ///
+    /// [`define_stages`]: Self::define_stages
+    pub fn add_listener_to_stage<D: PriorityListener<T> + Send + Sync + 'static>(
+        &mut self,
+        event_key: T,
+        stage: &str,
+        listener: D,
+    ) -> Result<ListenerId, Error> {
+        let priority = self
+            .stages
+            .iter()
+            .position(|name| name == stage)
+            .ok_or_else(|| Error::UnknownStage(stage.to_string()))?;
+
+        Ok(self.add_listener(event_key, listener, priority))
+    }
+}
+
+/// Wraps a listener added to a [`PriorityDispatcher`], carrying the id it
+/// has been added under.
+struct Registered<L> {
+    id: ListenerId,
+    listener: L,
+}
+
+impl<T, L> PriorityListener<T> for Registered<L>
+where
+    T: Clone + Send + Sync + 'static,
+    L: PriorityListener<T>,
+{
+    fn on_event(&self, event: &T) -> Option<PriorityDispatcherResult> {
+        self.listener.on_event(event)
+    }
+
+    fn name(&self) -> &str {
+        self.listener.name()
+    }
+
+    fn requires_order(&self) -> bool {
+        self.listener.requires_order()
+    }
+
+    fn owner(&self) -> Option<Owner> {
+        self.listener.owner()
+    }
+
+    fn listener_id(&self) -> Option<ListenerId> {
+        Some(self.id)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.listener.is_alive()
+    }
+}
+
+/// Marks a priority-dispatcher as dispatching on this thread while alive.
+struct Dispatching(usize);
+
+impl Dispatching {
+    /// Marks the dispatcher at `address` as dispatching.
+    fn enter(address: usize) -> Self {
+        DISPATCHING.with(|dispatching| dispatching.borrow_mut().push(address));
+
+        Self(address)
+    }
+
+    /// Whether the dispatcher at `address` is dispatching on this thread.
+    fn contains(address: usize) -> bool {
+        DISPATCHING.with(|dispatching| dispatching.borrow().contains(&address))
+    }
+}
+
+impl Drop for Dispatching {
+    fn drop(&mut self) {
+        DISPATCHING.with(|dispatching| {
+            let mut dispatching = dispatching.borrow_mut();
+
+            if let Some(index) = dispatching.iter().rposition(|address| *address == self.0) {
+                dispatching.remove(index);
+            }
+        });
+    }
+}
+
+/// Flattens `prioritised_listeners` into listeners paired with their
+/// priority.
+fn unprioritise<P, T>(
+    prioritised_listeners: BTreeMap<P, Vec<EventListener<T>>>,
+) -> impl Iterator<Item = (P, EventListener<T>)>
+where
+    P: Clone,
+    T: Clone + Send + Sync + 'static,
+{
+    prioritised_listeners
+        .into_iter()
+        .flat_map(|(priority, listeners)| {
+            listeners
+                .into_iter()
+                .map(move |listener| (priority.clone(), listener))
+        })
 }
@@ -0,0 +1,149 @@
+use super::{
+    super::{key::KeyExtract, snapshot::Snapshot, Error},
+    PriorityDispatcher, PriorityDispatcherResult, PriorityListener,
+};
+use std::collections::HashMap;
+
+type ListenerFactory<T> = Box<dyn Fn() -> EventListener<T> + Send + Sync + 'static>;
+type EventListener<T> = Box<dyn PriorityListener<T> + Send + Sync + 'static>;
+
+/// Instantiates listeners by name to wire them into a [`PriorityDispatcher`].
+///
+/// Which listeners to register for which event-key and priority is
+/// described by a [`Snapshot`], with the `serde`-feature enabled it can be
+/// loaded from a configuration file.
+/// Snapshots of wired dispatchers report listeners by their registered name.
+///
+/// [`Snapshot`]: crate::snapshot::Snapshot
+pub struct ListenerRegistry<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    factories: HashMap<String, ListenerFactory<T>>,
+}
+
+impl<T> ListenerRegistry<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Create a new, empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            factories: HashMap::new(),
+        }
+    }
+
+    /// Registers `factory` to create the listener called `name`.
+    ///
+    /// Registering a name twice replaces the earlier factory.
+    pub fn register<D, F>(&mut self, name: impl Into<String>, factory: F)
+    where
+        D: PriorityListener<T> + Send + Sync + 'static,
+        F: Fn() -> D + Send + Sync + 'static,
+    {
+        let factory = move || Box::new(factory()) as EventListener<T>;
+
+        self.factories.insert(name.into(), Box::new(factory));
+    }
+
+    /// Instantiates every listener described by `wiring` and adds it to
+    /// `dispatcher` for its event-key and priority.
+    ///
+    /// # Errors
+    ///
+    /// Fails if a described listener has not been registered, no listener
+    /// is added in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{
+    ///     snapshot::{ListenerEntry, Snapshot},
+    ///     sync::{ListenerRegistry, PriorityDispatcher, PriorityDispatcherResult, PriorityListener},
+    /// };
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Damage,
+    /// }
+    ///
+    /// struct Health;
+    ///
+    /// impl PriorityListener<Event> for Health {
+    ///     fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> { None }
+    /// }
+    ///
+    /// let mut registry = ListenerRegistry::new();
+    /// registry.register("health", || Health);
+    ///
+    /// // Usually deserialised from a configuration file.
+    /// let wiring = Snapshot {
+    ///     listeners: vec![ListenerEntry {
+    ///         event_key: Event::Damage,
+    ///         priority: 1,
+    ///         name: "health".to_string(),
+    ///     }],
+    /// };
+    ///
+    /// let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///
+    /// registry.wire(&wiring, &mut dispatcher).expect("Unknown listener");
+    /// ```
+    pub fn wire<P, X>(
+        &self,
+        wiring: &Snapshot<T, P>,
+        dispatcher: &mut PriorityDispatcher<P, T, X>,
+    ) -> Result<(), Error>
+    where
+        P: Ord + Clone,
+        X: KeyExtract<T>,
+    {
+        if let Some(unknown) = wiring
+            .listeners
+            .iter()
+            .find(|entry| !self.factories.contains_key(&entry.name))
+        {
+            return Err(Error::UnknownListener(unknown.name.clone()));
+        }
+
+        for entry in &wiring.listeners {
+            let listener = Registered {
+                name: entry.name.clone(),
+                listener: (self.factories[&entry.name])(),
+            };
+
+            dispatcher.add_listener(entry.event_key.clone(), listener, entry.priority.clone());
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Default for ListenerRegistry<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A listener instantiated by a [`ListenerRegistry`], named as registered.
+struct Registered<T> {
+    name: String,
+    listener: EventListener<T>,
+}
+
+impl<T> PriorityListener<T> for Registered<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    fn on_event(&self, event: &T) -> Option<PriorityDispatcherResult> {
+        self.listener.on_event(event)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,148 @@
+use super::{
+    super::{
+        key::{Identity, KeyExtract},
+        Error, Mutex,
+    },
+    PriorityDispatcher,
+};
+use std::sync::Arc;
+
+/// A dispatch-only handle to a shared [`PriorityDispatcher`].
+///
+/// Subsystems owning a view can raise events but never add or remove
+/// listeners, as the view exposes nothing but dispatching.
+/// Cloning shares the dispatcher.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::{
+///     sync::{DispatcherView, PriorityDispatcher},
+///     Mutex,
+/// };
+/// use std::sync::{
+///     atomic::{AtomicUsize, Ordering},
+///     Arc,
+/// };
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Collision,
+/// }
+///
+/// let collisions = Arc::new(AtomicUsize::new(0));
+/// let dispatcher = Arc::new(Mutex::new(PriorityDispatcher::<u32, Event>::default()));
+///
+/// let counter = Arc::clone(&collisions);
+/// dispatcher.lock().add_fn(
+///     Event::Collision,
+///     move |_| {
+///         counter.fetch_add(1, Ordering::SeqCst);
+///
+///         None
+///     },
+///     1,
+/// );
+///
+/// // The physics-subsystem may only raise events.
+/// let physics = DispatcherView::new(&dispatcher);
+/// physics.dispatch_event(&Event::Collision);
+///
+/// assert_eq!(collisions.load(Ordering::SeqCst), 1);
+/// ```
+///
+/// [`PriorityDispatcher`]: super::PriorityDispatcher
+pub struct DispatcherView<P, T, X = Identity>
+where
+    P: Ord + Clone,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    dispatcher: Arc<Mutex<PriorityDispatcher<P, T, X>>>,
+}
+
+impl<P, T, X> DispatcherView<P, T, X>
+where
+    P: Ord + Clone,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    /// Creates a view dispatching on `dispatcher`.
+    #[must_use]
+    pub fn new(dispatcher: &Arc<Mutex<PriorityDispatcher<P, T, X>>>) -> Self {
+        Self {
+            dispatcher: Arc::clone(dispatcher),
+        }
+    }
+
+    /// Locks the dispatcher and dispatches `event_identifier`, see
+    /// [`PriorityDispatcher::dispatch_event`].
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as
+    /// [`PriorityDispatcher::dispatch_event`].
+    ///
+    /// [`PriorityDispatcher::dispatch_event`]: super::PriorityDispatcher::dispatch_event
+    pub fn dispatch_event(&self, event_identifier: &T) {
+        self.dispatcher.lock().dispatch_event(event_identifier);
+    }
+
+    /// Dispatches `event_identifier` like [`dispatch_event`], but fails
+    /// instead of waiting while the dispatcher is locked, e.g. by a dispatch
+    /// on another thread.
+    ///
+    /// Lets real-time threads skip processing an event rather than stall.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::WouldBlock`] if the dispatcher is locked, the
+    /// event has not been dispatched.
+    /// Fails like [`PriorityDispatcher::try_dispatch_event`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{
+    ///     sync::{DispatcherView, PriorityDispatcher},
+    ///     Error, Mutex,
+    /// };
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Frame,
+    /// }
+    ///
+    /// let dispatcher = Arc::new(Mutex::new(PriorityDispatcher::<u32, Event>::default()));
+    /// let renderer = DispatcherView::new(&dispatcher);
+    ///
+    /// let locked = dispatcher.lock();
+    /// assert!(matches!(renderer.try_dispatch_event(&Event::Frame), Err(Error::WouldBlock)));
+    ///
+    /// drop(locked);
+    /// assert!(renderer.try_dispatch_event(&Event::Frame).is_ok());
+    /// ```
+    ///
+    /// [`dispatch_event`]: Self::dispatch_event
+    /// [`PriorityDispatcher::try_dispatch_event`]: super::PriorityDispatcher::try_dispatch_event
+    pub fn try_dispatch_event(&self, event_identifier: &T) -> Result<(), Error> {
+        self.dispatcher
+            .try_lock()
+            .ok_or(Error::WouldBlock)?
+            .try_dispatch_event(event_identifier)
+    }
+}
+
+impl<P, T, X> Clone for DispatcherView<P, T, X>
+where
+    P: Ord + Clone,
+    T: Clone + Send + Sync + 'static,
+    X: KeyExtract<T>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            dispatcher: Arc::clone(&self.dispatcher),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Storage for listeners of differing types, kept apart by type.
+
+use std::{
+    any::{type_name, Any, TypeId},
+    collections::HashMap,
+    hash::Hash,
+};
+
+/// A `Vec` whose element type has been erased.
+trait AnyVec: Send + Sync {
+    /// Returns how many elements the `Vec` holds.
+    #[cfg(any(feature = "blocking", feature = "priority", feature = "parallel"))]
+    fn len(&self) -> usize;
+
+    /// Returns the `Vec` to be downcast.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Returns the `Vec` to be downcast mutably.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<V> AnyVec for Vec<V>
+where
+    V: Send + Sync + 'static,
+{
+    #[cfg(any(feature = "blocking", feature = "priority", feature = "parallel"))]
+    fn len(&self) -> usize {
+        Self::len(self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Values of differing types, each stored in a `Vec` by a key and the
+/// type-id of its values.
+///
+/// Values are only ever downcast to the type whose type-id they are stored
+/// by, hence downcasting never fails.
+pub struct TypeMap<K> {
+    /// Maps a key and a value type's id to the values of the type.
+    vecs: HashMap<(K, TypeId), Values>,
+}
+
+/// The values of one type stored by a key.
+struct Values {
+    /// The name of the values' type.
+    #[cfg_attr(not(feature = "parallel"), allow(dead_code))]
+    type_name: &'static str,
+    /// The `Vec` of the values.
+    vec: Box<dyn AnyVec>,
+}
+
+impl<K> TypeMap<K>
+where
+    K: Eq + Hash,
+{
+    /// Creates a map without any values.
+    pub fn new() -> Self {
+        Self {
+            vecs: HashMap::new(),
+        }
+    }
+
+    /// Appends `value` to the values of type `V` stored by `key`.
+    pub fn push<V>(&mut self, key: K, value: V)
+    where
+        V: Send + Sync + 'static,
+    {
+        let values = self
+            .vecs
+            .entry((key, TypeId::of::<V>()))
+            .or_insert_with(|| Values {
+                type_name: type_name::<V>(),
+                vec: Box::new(Vec::<V>::new()),
+            });
+
+        if let Some(vec) = values.vec.as_any_mut().downcast_mut::<Vec<V>>() {
+            vec.push(value);
+        }
+    }
+
+    /// Returns the values of type `V` stored by `key`.
+    pub fn get<V>(&self, key: K) -> &[V]
+    where
+        V: 'static,
+    {
+        self.vecs
+            .get(&(key, TypeId::of::<V>()))
+            .and_then(|values| values.vec.as_any().downcast_ref::<Vec<V>>())
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+#[cfg(any(feature = "blocking", feature = "priority", feature = "parallel"))]
+impl<K> TypeMap<K>
+where
+    K: Eq + Hash,
+{
+    /// Returns how many values of any type are stored by `key`.
+    pub fn len(&self, key: &K) -> usize {
+        self.vecs
+            .iter()
+            .filter(|((stored_key, _), _)| stored_key == key)
+            .map(|(_, values)| values.vec.len())
+            .sum()
+    }
+
+    /// Returns how many values of any type are stored by any key.
+    pub fn total_len(&self) -> usize {
+        self.vecs.values().map(|values| values.vec.len()).sum()
+    }
+
+    /// Returns every key alongside the type name and count of the values of
+    /// each type stored by it.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &'static str, usize)> + '_ {
+        self.vecs
+            .iter()
+            .map(|((key, _), values)| (key, values.type_name, values.vec.len()))
+    }
+
+    /// Removes the values of any type stored by `key` and returns how many
+    /// have been removed.
+    pub fn remove(&mut self, key: &K) -> usize {
+        let mut removed = 0;
+
+        self.vecs.retain(|(stored_key, _), values| {
+            let is_kept = stored_key != key;
+
+            if !is_kept {
+                removed += values.vec.len();
+            }
+
+            is_kept
+        });
+
+        removed
+    }
+
+    /// Removes all values and returns how many have been removed.
+    pub fn clear(&mut self) -> usize {
+        let removed = self.total_len();
+
+        self.vecs.clear();
+
+        removed
+    }
+}
+
+impl<K> Default for TypeMap<K>
+where
+    K: Eq + Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,129 @@
+//! Typed results aggregated from listeners, keyed by event type.
+//!
+//! Each event type names the [`Output`](crate::typed::EventResult::Output)
+//! its listeners return and how outputs are combined via
+//! [`EventResult`](crate::typed::EventResult).
+//! A [`ResultAggregator`](crate::typed::ResultAggregator) registers closures
+//! by event type, dispatching an event returns the combined outputs of its
+//! closures, e.g. the sum of all damage modifiers.
+//!
+//! One aggregator serves any number of event types. Events implementing
+//! [`EventResult`](crate::typed::EventResult) themselves are combined per
+//! event-key by dispatchers instead, via `add_typed_fn` and
+//! `dispatch_typed` of `PriorityDispatcher` and `rc::Dispatcher`.
+//! Outputs of a `ParallelDispatcher`'s listeners are reduced via its
+//! `dispatch_reduce`.
+
+use crate::type_map::TypeMap;
+
+/// Associates an event type with the result its listeners produce.
+///
+/// The result of a dispatch starts as [`Default`] and combines each
+/// listener's output via [`combine`](Self::combine), in the order the
+/// listeners have been added in.
+pub trait EventResult: 'static {
+    /// The type each listener returns and a dispatch results in.
+    type Output: Default;
+
+    /// Combines `output` of a listener into the `total` of the dispatch.
+    fn combine(total: &mut Self::Output, output: Self::Output);
+}
+
+pub(crate) type TypedListener<E> =
+    Box<dyn Fn(&E) -> <E as EventResult>::Output + Send + Sync + 'static>;
+
+/// Registers closures by their event type and returns the combined
+/// outputs of all closures of a dispatched event.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::typed::{EventResult, ResultAggregator};
+///
+/// struct Damage {
+///     amount: i32,
+/// }
+///
+/// impl EventResult for Damage {
+///     type Output = i32;
+///
+///     fn combine(total: &mut i32, modifier: i32) {
+///         *total += modifier;
+///     }
+/// }
+///
+/// struct Heal;
+///
+/// impl EventResult for Heal {
+///     type Output = bool;
+///
+///     fn combine(is_vetoed: &mut bool, veto: bool) {
+///         *is_vetoed |= veto;
+///     }
+/// }
+///
+/// let mut aggregator = ResultAggregator::new();
+///
+/// aggregator.add_fn(|damage: &Damage| damage.amount);
+/// aggregator.add_fn(|_: &Damage| -2);
+/// aggregator.add_fn(|_: &Heal| true);
+///
+/// assert_eq!(aggregator.dispatch_event(&Damage { amount: 10 }), 8);
+/// assert!(aggregator.dispatch_event(&Heal));
+/// ```
+#[derive(Default)]
+pub struct ResultAggregator {
+    /// Stores the `TypedListener<E>`s of each event type `E`.
+    listeners: TypeMap<()>,
+}
+
+impl ResultAggregator {
+    /// Creates an aggregator without any listeners.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the closure `listener` to listen for events of type `E`.
+    pub fn add_fn<E, F>(&mut self, listener: F)
+    where
+        E: EventResult,
+        F: Fn(&E) -> E::Output + Send + Sync + 'static,
+    {
+        let listener: TypedListener<E> = Box::new(listener);
+
+        self.listeners.push((), listener);
+    }
+
+    /// Returns how many listeners listen for events of type `E`.
+    #[must_use]
+    pub fn listener_count<E>(&self) -> usize
+    where
+        E: EventResult,
+    {
+        self.listeners_of::<E>().len()
+    }
+
+    /// Dispatches `event` to all listeners of its type and returns their
+    /// combined outputs, the [`Default`] output if there are none.
+    pub fn dispatch_event<E>(&self, event: &E) -> E::Output
+    where
+        E: EventResult,
+    {
+        let mut total = E::Output::default();
+
+        for listener in self.listeners_of::<E>() {
+            E::combine(&mut total, listener(event));
+        }
+
+        total
+    }
+
+    /// Returns the listeners of events of type `E`.
+    fn listeners_of<E>(&self) -> &[TypedListener<E>]
+    where
+        E: EventResult,
+    {
+        self.listeners.get(())
+    }
+}
@@ -0,0 +1,121 @@
+//! Feeds [`winit`](::winit) events into dispatchers.
+//!
+//! [`WinitDispatcher`](crate::winit::WinitDispatcher) is implemented for every sequential and parallel
+//! dispatcher whose event-type can be created from winit's events.
+//! Call it inside your [`ApplicationHandler`] to use a dispatcher as
+//! input-event bus.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "priority")] {
+//! use hey_listen::{sync::PriorityDispatcher, winit::WinitDispatcher};
+//! use winit::{
+//!     application::ApplicationHandler,
+//!     event::WindowEvent,
+//!     event_loop::ActiveEventLoop,
+//!     window::WindowId,
+//! };
+//!
+//! #[derive(Clone, Eq, Hash, PartialEq)]
+//! enum Input {
+//!     CloseRequested,
+//!     Other,
+//! }
+//!
+//! impl From<WindowEvent> for Input {
+//!     fn from(event: WindowEvent) -> Self {
+//!         match event {
+//!             WindowEvent::CloseRequested => Self::CloseRequested,
+//!             _ => Self::Other,
+//!         }
+//!     }
+//! }
+//!
+//! struct App {
+//!     dispatcher: PriorityDispatcher<u32, Input>,
+//! }
+//!
+//! impl ApplicationHandler for App {
+//!     fn resumed(&mut self, _event_loop: &ActiveEventLoop) {}
+//!
+//!     fn window_event(
+//!         &mut self,
+//!         _event_loop: &ActiveEventLoop,
+//!         _window_id: WindowId,
+//!         event: WindowEvent,
+//!     ) {
+//!         self.dispatcher.dispatch_window_event(event);
+//!     }
+//! }
+//! # }
+//! ```
+//!
+//! [`ApplicationHandler`]: ::winit::application::ApplicationHandler
+use crate::key::KeyExtract;
+#[cfg(feature = "blocking")]
+use crate::rc::Dispatcher;
+#[cfg(feature = "parallel")]
+use crate::sync::ParallelDispatcher;
+#[cfg(feature = "priority")]
+use crate::sync::PriorityDispatcher;
+use ::winit::event::{DeviceEvent, WindowEvent};
+
+/// Converts [`winit`] events into the dispatcher's event-type `T` and
+/// dispatches them.
+pub trait WinitDispatcher<T> {
+    /// Converts `event` into `T` and dispatches it.
+    fn dispatch_window_event(&mut self, event: WindowEvent)
+    where
+        T: From<WindowEvent>;
+
+    /// Converts `event` into `T` and dispatches it.
+    fn dispatch_device_event(&mut self, event: DeviceEvent)
+    where
+        T: From<DeviceEvent>;
+}
+
+/// Implements [`WinitDispatcher`] for a dispatcher.
+macro_rules! impl_winit_dispatcher {
+    ($dispatcher:ty, [$($param:ident),*], [$($bounds:tt)*]) => {
+        impl<$($param),*> WinitDispatcher<T> for $dispatcher
+        where
+            $($bounds)*
+        {
+            fn dispatch_window_event(&mut self, event: WindowEvent)
+            where
+                T: From<WindowEvent>,
+            {
+                self.dispatch_event(&T::from(event));
+            }
+
+            fn dispatch_device_event(&mut self, event: DeviceEvent)
+            where
+                T: From<DeviceEvent>,
+            {
+                self.dispatch_event(&T::from(event));
+            }
+        }
+    };
+}
+
+#[cfg(feature = "blocking")]
+impl_winit_dispatcher!(
+    Dispatcher<T, X>,
+    [T, X],
+    [T: Clone + 'static, X: KeyExtract<T>]
+);
+
+#[cfg(feature = "parallel")]
+impl_winit_dispatcher!(
+    ParallelDispatcher<T, X>,
+    [T, X],
+    [T: Clone + Send + Sync + 'static, X: KeyExtract<T>]
+);
+
+#[cfg(feature = "priority")]
+impl_winit_dispatcher!(
+    PriorityDispatcher<P, T, X>,
+    [P, T, X],
+    [P: Ord + Clone, T: Clone + Send + Sync + 'static, X: KeyExtract<T>]
+);
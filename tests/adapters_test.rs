@@ -0,0 +1,451 @@
+#![cfg(feature = "priority")]
+
+use hey_listen::{
+    adapters::{
+        Aggregator, Correlate, Join, Keyed, Lazy, ListenerExt, Pool, Sampled, Sharded, Throttled,
+    },
+    audit::{DropAudit, DropReason},
+    clock::ManualClock,
+    fns::weakly,
+    key::{ByDiscriminant, KeyExtract},
+    sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener},
+    Mutex,
+};
+use std::{sync::Arc, time::Duration};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Event {
+    Damage(u32),
+}
+
+struct RecordingListener {
+    record: Arc<Mutex<Vec<u32>>>,
+}
+
+impl PriorityListener<u32> for RecordingListener {
+    fn on_event(&self, amount: &u32) -> Option<PriorityDispatcherResult> {
+        self.record.lock().push(*amount);
+
+        None
+    }
+}
+
+#[test]
+fn chained_adapters_filter_map_and_inspect() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let inspected = Arc::new(Mutex::new(0));
+    let inspected_by_listener = Arc::clone(&inspected);
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    let listener = RecordingListener {
+        record: Arc::clone(&record),
+    }
+    .map_event(|Event::Damage(amount): &Event| amount * 2)
+    .filter(|Event::Damage(amount): &Event| *amount > 1)
+    .inspect(move |_: &Event| *inspected_by_listener.lock() += 1);
+
+    dispatcher.add_listener(Event::Damage(0), listener, 0);
+
+    for amount in 1..=3 {
+        dispatcher.dispatch_with(&Event::Damage(0), || Event::Damage(amount));
+    }
+
+    assert_eq!(*inspected.lock(), 3);
+    assert_eq!(*record.lock(), [4, 6]);
+}
+
+#[test]
+fn throttled_drops_events_within_interval() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, u32>::default();
+
+    dispatcher.add_listener(
+        0,
+        Throttled::new(
+            RecordingListener {
+                record: Arc::clone(&record),
+            },
+            Duration::from_secs(3600),
+        ),
+        0,
+    );
+    dispatcher.add_listener(
+        0,
+        RecordingListener {
+            record: Arc::clone(&record),
+        }
+        .throttled(Duration::from_secs(0)),
+        1,
+    );
+
+    dispatcher.dispatch_event(&0);
+    dispatcher.dispatch_event(&0);
+
+    assert_eq!(*record.lock(), [0, 0, 0]);
+}
+
+#[test]
+fn manual_clock_advances_time_based_adapters() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let clock = ManualClock::new();
+    let mut dispatcher = PriorityDispatcher::<u32, u32>::default();
+
+    dispatcher.add_listener(
+        0,
+        RecordingListener {
+            record: Arc::clone(&record),
+        }
+        .throttled(Duration::from_secs(60))
+        .clock(clock.clone()),
+        0,
+    );
+
+    let batched = RecordingListener {
+        record: Arc::clone(&record),
+    }
+    .map_event(|batch: &Vec<u32>| 100 + batch.len() as u32);
+
+    dispatcher.add_listener(
+        0,
+        Aggregator::new(batched, 10)
+            .max_age(Duration::from_secs(60))
+            .clock(clock.clone()),
+        1,
+    );
+
+    dispatcher.dispatch_event(&0);
+    dispatcher.dispatch_event(&0);
+    clock.advance(Duration::from_secs(60));
+    dispatcher.dispatch_event(&0);
+
+    assert_eq!(*record.lock(), [0, 0, 103]);
+}
+
+#[test]
+fn sampled_forwards_every_n_th_event() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    dispatcher.add_listener(
+        Event::Damage(0),
+        Sampled::every_n(
+            RecordingListener {
+                record: Arc::clone(&record),
+            },
+            3,
+        )
+        .map_event(|Event::Damage(amount): &Event| *amount),
+        0,
+    );
+
+    for amount in 1..=7 {
+        dispatcher.dispatch_with(&Event::Damage(0), || Event::Damage(amount));
+    }
+
+    assert_eq!(*record.lock(), [3, 6]);
+}
+
+#[test]
+fn drop_audit_counts_dropped_events_per_key() {
+    let reasons = Arc::new(Mutex::new(Vec::new()));
+    let hook_reasons = Arc::clone(&reasons);
+    let audit: DropAudit<u32> = DropAudit::new(move |_: &u32, reason| {
+        hook_reasons.lock().push(reason);
+    });
+    let mut dispatcher = PriorityDispatcher::<u32, u32>::default();
+
+    for event_key in 0..2 {
+        dispatcher.add_listener(
+            event_key,
+            Throttled::new(
+                RecordingListener {
+                    record: Arc::new(Mutex::new(Vec::new())),
+                },
+                Duration::from_secs(3600),
+            )
+            .on_dropped(audit.clone()),
+            0,
+        );
+    }
+
+    dispatcher.add_listener(
+        2,
+        Sampled::every_n(
+            RecordingListener {
+                record: Arc::new(Mutex::new(Vec::new())),
+            },
+            3,
+        )
+        .on_dropped(audit.clone()),
+        0,
+    );
+
+    for event in &[0, 0, 0, 1, 2, 2, 2] {
+        dispatcher.dispatch_event(event);
+    }
+
+    assert_eq!(audit.dropped(&0), 2);
+    assert_eq!(audit.dropped(&1), 0);
+    assert_eq!(audit.dropped(&2), 2);
+    assert_eq!(
+        *reasons.lock(),
+        [
+            DropReason::Throttled,
+            DropReason::Throttled,
+            DropReason::Sampled,
+            DropReason::Sampled
+        ]
+    );
+}
+
+#[test]
+fn aggregator_forwards_full_batches() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, u32>::default();
+
+    let listener = RecordingListener {
+        record: Arc::clone(&record),
+    }
+    .map_event(|batch: &Vec<u32>| batch.iter().sum::<u32>());
+
+    dispatcher.add_listener(0, Aggregator::new(listener, 2), 0);
+
+    for amount in 1..=5 {
+        dispatcher.dispatch_with(&0, || amount);
+    }
+
+    assert_eq!(*record.lock(), [3, 7]);
+}
+
+#[test]
+fn shared_listener_receives_any_key() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, u32>::default();
+
+    let listener = RecordingListener {
+        record: Arc::clone(&record),
+    }
+    .shared();
+
+    dispatcher.add_listener(1, listener.clone(), 0);
+    dispatcher.add_listener(2, listener, 0);
+
+    for key in 1..=3 {
+        dispatcher.dispatch_event(&key);
+    }
+
+    assert_eq!(*record.lock(), [1, 2]);
+}
+
+#[test]
+fn keyed_shared_listener_receives_matched_key() {
+    struct ByTens;
+
+    impl KeyExtract<u32> for ByTens {
+        type Key = u32;
+
+        fn extract(event: &u32) -> Self::Key {
+            event / 10
+        }
+    }
+
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, u32, ByTens>::default();
+
+    let listener = RecordingListener {
+        record: Arc::clone(&record),
+    }
+    .map_event(|(key, event): &(u32, u32)| key * 100 + event);
+    let listener = Keyed::<_, ByTens>::with_key_extract(listener).shared();
+
+    dispatcher.add_listener(10, listener.clone(), 0);
+    dispatcher.add_listener(20, listener, 0);
+
+    for event in [12, 35, 27] {
+        dispatcher.dispatch_event(&event);
+    }
+
+    assert_eq!(*record.lock(), [112, 227]);
+}
+
+#[test]
+fn join_forwards_once_all_keys_arrived() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, u32>::default();
+
+    let listener = RecordingListener {
+        record: Arc::clone(&record),
+    }
+    .map_event(|joined: &Vec<u32>| joined.iter().fold(0, |sum, key| sum * 10 + key));
+    let listener = Join::new(listener, vec![1, 2, 3]);
+
+    for key in 1..=3 {
+        dispatcher.add_listener(key, listener.clone(), 0);
+    }
+
+    for key in [3, 1, 1, 2, 2, 1] {
+        dispatcher.dispatch_event(&key);
+    }
+
+    assert_eq!(*record.lock(), [123]);
+}
+
+#[test]
+fn correlate_forwards_paired_events() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, u32>::default();
+
+    let listener = Correlate::new(
+        RecordingListener {
+            record: Arc::clone(&record),
+        },
+        Duration::from_secs(3600),
+        |earlier: &u32, later: &u32| Some(*earlier).filter(|earlier| earlier + 10 == *later),
+    )
+    .shared();
+
+    for key in [1, 2, 11, 12] {
+        dispatcher.add_listener(key, listener.clone(), 0);
+    }
+
+    for key in [1, 2, 12, 12, 11] {
+        dispatcher.dispatch_event(&key);
+    }
+
+    assert_eq!(*record.lock(), [2, 1]);
+}
+
+#[test]
+fn lazy_creates_listener_on_first_event() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let times_created = Arc::new(Mutex::new(0));
+    let mut dispatcher = PriorityDispatcher::<u32, u32>::default();
+
+    let record_by_listener = Arc::clone(&record);
+    let times_created_by_factory = Arc::clone(&times_created);
+
+    dispatcher.add_listener(
+        1,
+        Lazy::new(move || {
+            *times_created_by_factory.lock() += 1;
+
+            RecordingListener {
+                record: Arc::clone(&record_by_listener),
+            }
+        })
+        .filter(|key: &u32| *key == 1),
+        0,
+    );
+
+    dispatcher.dispatch_event(&2);
+    assert_eq!(*times_created.lock(), 0);
+
+    dispatcher.dispatch_event(&1);
+    dispatcher.dispatch_event(&1);
+
+    assert_eq!(*times_created.lock(), 1);
+    assert_eq!(*record.lock(), [1, 1]);
+}
+
+#[test]
+fn pool_forwards_to_one_listener_per_event() {
+    let records: Vec<_> = (0..3).map(|_| Arc::new(Mutex::new(Vec::new()))).collect();
+    let mut dispatcher = PriorityDispatcher::<u32, u32>::default();
+
+    let listeners = records.iter().zip([2, 0, 1]).map(|(record, weight)| {
+        let listener = RecordingListener {
+            record: Arc::clone(record),
+        };
+
+        (listener, weight)
+    });
+
+    dispatcher.add_listener(0, Pool::weighted(listeners), 0);
+
+    for amount in 1..=6 {
+        dispatcher.dispatch_with(&0, || amount);
+    }
+
+    assert_eq!(*records[0].lock(), [1, 2, 4, 5]);
+    assert!(records[1].lock().is_empty());
+    assert_eq!(*records[2].lock(), [3, 6]);
+}
+
+#[test]
+fn sharded_forwards_equal_events_to_same_listener() {
+    let records: Vec<_> = (0..4).map(|_| Arc::new(Mutex::new(Vec::new()))).collect();
+    let mut dispatcher = PriorityDispatcher::<u32, Event, ByDiscriminant>::default();
+
+    let listeners = records.iter().map(|record| {
+        RecordingListener {
+            record: Arc::clone(record),
+        }
+        .map_event(|Event::Damage(amount): &Event| *amount)
+    });
+
+    dispatcher.add_listener(Event::Damage(0), Sharded::new(listeners), 0);
+
+    for amount in (0..64).chain(0..64) {
+        dispatcher.dispatch_event(&Event::Damage(amount));
+    }
+
+    let mut received = 0;
+
+    for record in &records {
+        let record = record.lock();
+
+        let mut amounts = record.clone();
+        amounts.sort_unstable();
+
+        // Both dispatches of an amount reached this listener.
+        for pair in amounts.chunks(2) {
+            assert_eq!(pair[0], pair[1]);
+        }
+
+        received += record.len();
+    }
+
+    assert_eq!(received, 128);
+    assert!(records.iter().all(|record| !record.lock().is_empty()));
+}
+
+#[test]
+fn wrapped_listeners_report_their_own_name() {
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    dispatcher.add_listener(
+        Event::Damage(0),
+        RecordingListener {
+            record: Arc::default(),
+        }
+        .map_event(|Event::Damage(amount): &Event| *amount)
+        .filter(|_event: &Event| true),
+        0,
+    );
+
+    let snapshot = dispatcher.snapshot();
+    assert!(snapshot.listeners[0].name.ends_with("RecordingListener"));
+}
+
+#[test]
+fn dead_wrapped_listeners_are_purged() {
+    let transient = Arc::new(());
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    dispatcher.add_listener(
+        Event::Damage(0),
+        weakly(&transient, |_, _event: &Event| {
+            None::<PriorityDispatcherResult>
+        })
+        .filter(|Event::Damage(amount): &Event| *amount > 0),
+        0,
+    );
+    dispatcher.add_fn(Event::Damage(0), |_event| None, 0);
+
+    assert_eq!(dispatcher.purge_dead(), 0);
+
+    drop(transient);
+
+    assert_eq!(dispatcher.purge_dead(), 1);
+    assert_eq!(dispatcher.listener_count(&Event::Damage(0)), 1);
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "arena")]
+
+use bumpalo::Bump;
+use hey_listen::{arena::ArenaDispatcher, static_dispatcher::StaticListener};
+use std::{cell::RefCell, rc::Rc};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Event {
+    Tick,
+    Quit,
+}
+
+struct Recorder {
+    name: &'static str,
+    record: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl StaticListener<Event> for Recorder {
+    fn on_event(&mut self, _event: &Event) {
+        self.record.borrow_mut().push(self.name);
+    }
+}
+
+#[test]
+fn listeners_are_allocated_in_the_arena() {
+    let arena = Bump::new();
+    let record = Rc::new(RefCell::new(Vec::new()));
+    let mut dispatcher: ArenaDispatcher<'_, Event> = ArenaDispatcher::new(&arena);
+
+    let allocated = arena.allocated_bytes();
+
+    dispatcher.add_listener(
+        Event::Tick,
+        Recorder {
+            name: "first",
+            record: Rc::clone(&record),
+        },
+    );
+    let closure_record = Rc::clone(&record);
+    dispatcher.add_fn(Event::Tick, move |_event| {
+        closure_record.borrow_mut().push("closure");
+    });
+    dispatcher.add_listener(
+        Event::Quit,
+        Recorder {
+            name: "quit",
+            record: Rc::clone(&record),
+        },
+    );
+
+    assert!(arena.allocated_bytes() > allocated);
+
+    dispatcher.dispatch_event(&Event::Tick);
+    assert_eq!(*record.borrow(), ["first", "closure"]);
+
+    assert_eq!(dispatcher.listener_count(&Event::Tick), 2);
+    assert_eq!(dispatcher.total_listeners(), 3);
+    assert_eq!(dispatcher.event_keys().len(), 2);
+
+    assert_eq!(dispatcher.remove_all_listeners(&Event::Tick), 2);
+    dispatcher.dispatch_event(&Event::Tick);
+    assert_eq!(record.borrow().len(), 2);
+
+    assert_eq!(dispatcher.remove_all_listeners(&Event::Quit), 1);
+    assert!(dispatcher.is_empty());
+}
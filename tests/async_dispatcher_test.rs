@@ -0,0 +1,210 @@
+#![cfg(feature = "async")]
+
+use async_trait::async_trait;
+use hey_listen::{
+    fns::weakly,
+    sync::{AsyncDispatchResult, AsyncDispatcher, AsyncListener},
+    Error,
+};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+enum Event {
+    Saved,
+}
+
+#[derive(Default)]
+struct Concurrency {
+    active: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+struct SharedConnection(Arc<Concurrency>);
+
+#[async_trait]
+impl AsyncListener<Event> for SharedConnection {
+    async fn on_event(&self, _event: &Event) -> Option<AsyncDispatchResult> {
+        let active = self.0.active.fetch_add(1, Ordering::SeqCst) + 1;
+        self.0.peak.fetch_max(active, Ordering::SeqCst);
+
+        tokio::task::yield_now().await;
+        self.0.active.fetch_sub(1, Ordering::SeqCst);
+
+        None
+    }
+}
+
+#[tokio::test]
+async fn exclusion_group_members_never_run_concurrently() {
+    let grouped = Arc::new(Concurrency::default());
+    let ungrouped = Arc::new(Concurrency::default());
+    let mut dispatcher = AsyncDispatcher::<Event>::new();
+
+    dispatcher.add_exclusion_group("database", 1);
+
+    for _ in 0..3 {
+        dispatcher
+            .add_listener_to_exclusion_group(
+                Event::Saved,
+                "database",
+                SharedConnection(Arc::clone(&grouped)),
+            )
+            .unwrap();
+        dispatcher.add_listener(Event::Saved, SharedConnection(Arc::clone(&ungrouped)));
+    }
+
+    assert!(matches!(
+        dispatcher.add_listener_to_exclusion_group(
+            Event::Saved,
+            "cache",
+            SharedConnection(Arc::clone(&grouped)),
+        ),
+        Err(Error::UnknownExclusionGroup(group)) if group == "cache"
+    ));
+
+    dispatcher.dispatch_event(&Event::Saved).await;
+
+    assert_eq!(grouped.peak.load(Ordering::SeqCst), 1);
+    assert_eq!(ungrouped.peak.load(Ordering::SeqCst), 3);
+}
+
+struct StopAfterCalls(Arc<AtomicUsize>);
+
+#[async_trait]
+impl AsyncListener<Event> for StopAfterCalls {
+    async fn on_event(&self, _event: &Event) -> Option<AsyncDispatchResult> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+
+        Some(AsyncDispatchResult::StopListening)
+    }
+}
+
+struct NeverDone;
+
+#[async_trait]
+impl AsyncListener<Event> for NeverDone {
+    async fn on_event(&self, _event: &Event) -> Option<AsyncDispatchResult> {
+        futures::future::pending().await
+    }
+}
+
+#[tokio::test]
+async fn cancelled_dispatch_still_removes_stopped_listeners() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut dispatcher = AsyncDispatcher::<Event>::new();
+
+    dispatcher.add_listener(Event::Saved, StopAfterCalls(Arc::clone(&calls)));
+    dispatcher.add_listener(Event::Saved, NeverDone);
+
+    tokio::select! {
+        biased;
+        _ = dispatcher.dispatch_event(&Event::Saved) => unreachable!(),
+        _ = tokio::task::yield_now() => {}
+    }
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(dispatcher.snapshot().listeners.len(), 1);
+
+    let (dispatch, handle) = dispatcher.dispatch_abortable(&Event::Saved);
+
+    handle.abort();
+
+    assert!(handle.is_aborted());
+    assert!(matches!(dispatch.await, Err(Error::DispatchAborted)));
+
+    let mut other_dispatcher = AsyncDispatcher::<Event>::new();
+
+    other_dispatcher.extend(dispatcher.drain());
+
+    assert!(dispatcher.is_empty());
+    assert_eq!(other_dispatcher.listener_count(&Event::Saved), 1);
+}
+
+#[tokio::test]
+async fn introspection_excludes_stopped_listeners() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut dispatcher = AsyncDispatcher::<Event>::new();
+
+    assert!(dispatcher.is_empty());
+
+    dispatcher.add_listener(Event::Saved, StopAfterCalls(Arc::clone(&calls)));
+    dispatcher.add_listener(Event::Saved, NeverDone);
+
+    assert_eq!(dispatcher.listener_count(&Event::Saved), 2);
+    assert_eq!(dispatcher.total_listeners(), 2);
+    assert!(dispatcher.event_keys().contains(&Event::Saved));
+
+    tokio::select! {
+        biased;
+        _ = dispatcher.dispatch_event(&Event::Saved) => unreachable!(),
+        _ = tokio::task::yield_now() => {}
+    }
+
+    assert_eq!(dispatcher.listener_count(&Event::Saved), 1);
+    assert_eq!(dispatcher.total_listeners(), 1);
+    assert!(!dispatcher.is_empty());
+
+    dispatcher.clear();
+
+    assert!(dispatcher.is_empty());
+    assert!(dispatcher.event_keys().is_empty());
+}
+
+#[tokio::test]
+async fn dead_exclusion_group_members_are_purged() {
+    let transient = Arc::new(());
+    let mut dispatcher = AsyncDispatcher::<Event>::new();
+
+    dispatcher.add_exclusion_group("database", 1);
+    dispatcher
+        .add_listener_to_exclusion_group(
+            Event::Saved,
+            "database",
+            weakly(&transient, |_, _event: &Event| None::<AsyncDispatchResult>),
+        )
+        .unwrap();
+
+    assert_eq!(dispatcher.purge_dead(), 0);
+
+    drop(transient);
+
+    assert_eq!(dispatcher.purge_dead(), 1);
+    assert_eq!(dispatcher.listener_count(&Event::Saved), 0);
+}
+
+#[tokio::test]
+async fn unsubscribed_listeners_are_not_counted_as_removed() {
+    let mut dispatcher = AsyncDispatcher::<Event>::new();
+
+    dispatcher.add_listener(Event::Saved, NeverDone);
+    let guard = dispatcher.subscribe_scoped(Event::Saved, NeverDone);
+
+    drop(guard);
+
+    assert_eq!(dispatcher.remove_all_listeners(&Event::Saved), 1);
+    assert_eq!(dispatcher.purge_dead(), 0);
+}
+
+#[tokio::test]
+async fn removing_by_id_applies_pending_stops_first() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut dispatcher = AsyncDispatcher::<Event>::new();
+
+    dispatcher.add_listener(Event::Saved, StopAfterCalls(Arc::clone(&calls)));
+    let never_done = dispatcher.add_listener(Event::Saved, NeverDone);
+    dispatcher.add_listener(Event::Saved, StopAfterCalls(Arc::clone(&calls)));
+
+    // The first listener's stop is pending until the dispatcher is mutated.
+    tokio::select! {
+        biased;
+        _ = dispatcher.dispatch_event(&Event::Saved) => unreachable!(),
+        _ = tokio::task::yield_now() => {}
+    }
+
+    assert!(dispatcher.remove_listener(never_done));
+    assert!(!dispatcher.remove_listener(never_done));
+    assert!(dispatcher.is_empty());
+}
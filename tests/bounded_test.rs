@@ -0,0 +1,71 @@
+use hey_listen::{
+    bounded::{BoundedDispatcher, CapacityError},
+    static_dispatcher::StaticListener,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Event {
+    Tick,
+    Reset,
+    Fault,
+}
+
+#[derive(Default)]
+struct Counter {
+    events: usize,
+}
+
+impl StaticListener<Event> for Counter {
+    fn on_event(&mut self, _event: &Event) {
+        self.events += 1;
+    }
+}
+
+#[test]
+fn bounded_dispatcher_rejects_listeners_beyond_capacity() {
+    let (mut first, mut second, mut third, mut fourth, mut fifth) = (
+        Counter::default(),
+        Counter::default(),
+        Counter::default(),
+        Counter::default(),
+        Counter::default(),
+    );
+
+    {
+        let mut dispatcher: BoundedDispatcher<'_, Event, 2, 2> = BoundedDispatcher::new();
+
+        assert_eq!(dispatcher.add_listener(Event::Tick, &mut first), Ok(()));
+        assert_eq!(dispatcher.add_listener(Event::Tick, &mut second), Ok(()));
+        assert_eq!(
+            dispatcher.add_listener(Event::Tick, &mut third),
+            Err(CapacityError::Listeners)
+        );
+        assert_eq!(dispatcher.add_listener(Event::Reset, &mut fourth), Ok(()));
+        assert_eq!(
+            dispatcher.add_listener(Event::Fault, &mut fifth),
+            Err(CapacityError::Keys)
+        );
+
+        assert_eq!(dispatcher.listener_count(&Event::Tick), 2);
+        assert_eq!(dispatcher.listener_count(&Event::Fault), 0);
+        assert_eq!(dispatcher.total_listeners(), 3);
+        assert!(dispatcher
+            .event_keys()
+            .eq([Event::Tick, Event::Reset].iter()));
+
+        dispatcher.dispatch_event(&Event::Tick);
+        dispatcher.dispatch_event(&Event::Fault);
+
+        assert!(dispatcher.remove_listeners(&Event::Reset));
+        assert!(!dispatcher.remove_listeners(&Event::Reset));
+        dispatcher.dispatch_event(&Event::Reset);
+
+        assert!(dispatcher.remove_listeners(&Event::Tick));
+        assert!(dispatcher.is_empty());
+    }
+
+    assert_eq!(first.events, 1);
+    assert_eq!(second.events, 1);
+    assert_eq!(third.events, 0);
+    assert_eq!(fourth.events, 0);
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "priority")]
+
+use hey_listen::{
+    sync::{DispatcherView, PriorityDispatcher, PriorityDispatcherResult, PriorityListener},
+    Error, Mutex,
+};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Event {
+    Frame,
+    Load,
+}
+
+type SharedDispatcher = Arc<Mutex<PriorityDispatcher<u32, Event>>>;
+
+fn count_dispatches(dispatcher: &SharedDispatcher, event: Event) -> Arc<AtomicUsize> {
+    let dispatches = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&dispatches);
+
+    dispatcher.lock().add_fn(
+        event,
+        move |_event| {
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            None
+        },
+        1,
+    );
+
+    dispatches
+}
+
+#[test]
+fn clones_dispatch_on_the_shared_dispatcher() {
+    let dispatcher: SharedDispatcher = Arc::default();
+    let frames = count_dispatches(&dispatcher, Event::Frame);
+    let renderer = DispatcherView::new(&dispatcher);
+    let physics = renderer.clone();
+
+    renderer.dispatch_event(&Event::Frame);
+    physics.dispatch_event(&Event::Frame);
+    physics.dispatch_event(&Event::Load);
+
+    assert_eq!(frames.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn try_dispatch_would_block_while_another_thread_dispatches() {
+    let dispatcher: SharedDispatcher = Arc::default();
+    let frames = count_dispatches(&dispatcher, Event::Frame);
+    let (entered_sender, entered) = mpsc::channel();
+    let (release, release_receiver) = mpsc::channel::<()>();
+    let release_receiver = Mutex::new(release_receiver);
+
+    // Holds the dispatcher's lock until released.
+    dispatcher.lock().add_fn(
+        Event::Load,
+        move |_event| {
+            entered_sender.send(()).unwrap();
+            release_receiver.lock().recv().unwrap();
+
+            None
+        },
+        1,
+    );
+
+    let loader = DispatcherView::new(&dispatcher);
+    let loading = thread::spawn(move || loader.dispatch_event(&Event::Load));
+    let renderer = DispatcherView::new(&dispatcher);
+
+    entered.recv().unwrap();
+
+    assert!(matches!(
+        renderer.try_dispatch_event(&Event::Frame),
+        Err(Error::WouldBlock)
+    ));
+    assert_eq!(frames.load(Ordering::SeqCst), 0);
+
+    release.send(()).unwrap();
+    loading.join().unwrap();
+
+    assert!(renderer.try_dispatch_event(&Event::Frame).is_ok());
+    assert_eq!(frames.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn try_dispatch_passes_reentrant_dispatches_through() {
+    struct Echo {
+        scene: DispatcherView<u32, Event>,
+        results: Arc<Mutex<Vec<Result<(), Error>>>>,
+    }
+
+    impl PriorityListener<Event> for Echo {
+        fn on_event(&self, event: &Event) -> Option<PriorityDispatcherResult> {
+            self.results
+                .lock()
+                .push(self.scene.try_dispatch_event(event));
+
+            None
+        }
+    }
+
+    let world: SharedDispatcher = Arc::default();
+    let scene: SharedDispatcher = Arc::default();
+    let scene_frames = count_dispatches(&scene, Event::Frame);
+    let results = Arc::new(Mutex::new(Vec::new()));
+
+    scene.lock().set_parent(&world);
+    world.lock().add_listener(
+        Event::Frame,
+        Echo {
+            scene: DispatcherView::new(&scene),
+            results: Arc::clone(&results),
+        },
+        1,
+    );
+
+    // Forwarding from the scene back to the dispatching world would
+    // deadlock, the view reports it instead.
+    world.lock().dispatch_event(&Event::Frame);
+
+    assert!(matches!(
+        results.lock().as_slice(),
+        [Err(Error::ReentrantDispatch)]
+    ));
+    assert_eq!(scene_frames.load(Ordering::SeqCst), 1);
+}
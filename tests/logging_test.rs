@@ -0,0 +1,150 @@
+#![cfg(all(feature = "priority", any(feature = "log", feature = "tracing")))]
+
+use hey_listen::sync::PriorityDispatcher;
+use std::sync::Mutex;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Event {
+    Damage(u32),
+    Heal(u32),
+}
+
+#[cfg(feature = "log")]
+mod log_listener {
+    use super::{Event, Mutex, PriorityDispatcher};
+    use hey_listen::logging::LogListener;
+    use log::{Level, LevelFilter, Log, Metadata, Record};
+    use std::sync::Arc;
+
+    /// Records every message logged by `hey_listen`.
+    struct RecordingLogger {
+        records: Arc<Mutex<Vec<(Level, String)>>>,
+    }
+
+    impl Log for RecordingLogger {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record<'_>) {
+            if record.target().starts_with("hey_listen") {
+                self.records
+                    .lock()
+                    .unwrap()
+                    .push((record.level(), record.args().to_string()));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn log_listener_logs_received_events() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let logger = RecordingLogger {
+            records: Arc::clone(&records),
+        };
+
+        log::set_logger(Box::leak(Box::new(logger))).expect("Failed to set logger");
+        log::set_max_level(LevelFilter::Trace);
+
+        let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+        dispatcher.add_listener(Event::Damage(3), LogListener::new(Level::Info), 0);
+        dispatcher.add_listener(Event::Heal(1), LogListener::default(), 0);
+
+        dispatcher.dispatch_event(&Event::Damage(3));
+        dispatcher.dispatch_event(&Event::Heal(1));
+        dispatcher.dispatch_event(&Event::Heal(2));
+
+        assert_eq!(
+            *records.lock().unwrap(),
+            [
+                (Level::Info, String::from("Damage(3)")),
+                (Level::Debug, String::from("Heal(1)")),
+            ]
+        );
+    }
+}
+
+#[cfg(feature = "tracing")]
+mod tracing_listener {
+    use super::{Event, Mutex, PriorityDispatcher};
+    use hey_listen::logging::TracingListener;
+    use std::{fmt::Debug, sync::Arc};
+    use tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+        Level, Metadata, Subscriber,
+    };
+
+    /// Records the level and `event`-field of every `tracing`-event.
+    #[derive(Clone, Default)]
+    struct RecordingSubscriber {
+        events: Arc<Mutex<Vec<(Level, String)>>>,
+    }
+
+    struct EventField(Option<String>);
+
+    impl Visit for EventField {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            if field.name() == "event" {
+                self.0 = Some(format!("{:?}", value));
+            }
+        }
+    }
+
+    impl Subscriber for RecordingSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _span: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            let mut field = EventField(None);
+            event.record(&mut field);
+
+            if let Some(field) = field.0 {
+                self.events
+                    .lock()
+                    .unwrap()
+                    .push((*event.metadata().level(), field));
+            }
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[test]
+    fn tracing_listener_emits_received_events() {
+        let subscriber = RecordingSubscriber::default();
+        let events = Arc::clone(&subscriber.events);
+        let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+        dispatcher.add_listener(Event::Damage(3), TracingListener::new(Level::WARN), 0);
+        dispatcher.add_listener(Event::Heal(1), TracingListener::default(), 0);
+
+        tracing::subscriber::with_default(subscriber, || {
+            dispatcher.dispatch_event(&Event::Damage(3));
+            dispatcher.dispatch_event(&Event::Heal(1));
+            dispatcher.dispatch_event(&Event::Heal(2));
+        });
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                (Level::WARN, String::from("Damage(3)")),
+                (Level::DEBUG, String::from("Heal(1)")),
+            ]
+        );
+    }
+}
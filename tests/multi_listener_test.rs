@@ -0,0 +1,137 @@
+#![cfg(feature = "priority")]
+
+use hey_listen::{
+    multi::Multi,
+    multi_listener,
+    sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener},
+    Mutex,
+};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Event {
+    Jump,
+    Land,
+    Damage(u32),
+    Quit,
+}
+
+multi_listener! {
+    /// Handles the player's events.
+    trait PlayerListener: PriorityListener<Event> as PriorityDispatcherResult {
+        fn on_movement => [Event::Jump | Event::Land],
+        fn on_lethal_damage => [Event::Damage(100..=u32::MAX)],
+        fn on_damage => [Event::Damage(_)],
+        fn on_quit => [Event::Quit],
+    }
+}
+
+/// Records which method received which event.
+#[derive(Default)]
+struct Player {
+    calls: Mutex<Vec<(&'static str, Event)>>,
+}
+
+impl Player {
+    fn record(&self, method: &'static str, event: &Event) {
+        self.calls.lock().push((method, event.clone()));
+    }
+}
+
+impl PlayerListener for Player {
+    fn on_movement(&self, event: &Event) -> Option<PriorityDispatcherResult> {
+        self.record("on_movement", event);
+
+        None
+    }
+
+    fn on_lethal_damage(&self, event: &Event) -> Option<PriorityDispatcherResult> {
+        self.record("on_lethal_damage", event);
+
+        Some(PriorityDispatcherResult::StopListening)
+    }
+
+    fn on_damage(&self, event: &Event) -> Option<PriorityDispatcherResult> {
+        self.record("on_damage", event);
+
+        None
+    }
+}
+
+fn dispatcher_with(player: &Multi<Player>) -> PriorityDispatcher<u32, Event> {
+    let mut dispatcher = PriorityDispatcher::default();
+
+    for event_key in &[Event::Jump, Event::Land, Event::Damage(0), Event::Quit] {
+        dispatcher.add_listener(event_key.clone(), player.clone(), 1);
+    }
+
+    dispatcher
+}
+
+#[test]
+fn routes_events_to_their_methods() {
+    let player = Multi::new(Player::default());
+    let mut dispatcher = dispatcher_with(&player);
+
+    dispatcher.dispatch_event(&Event::Jump);
+    dispatcher.dispatch_event(&Event::Damage(0));
+    dispatcher.dispatch_event(&Event::Land);
+
+    assert_eq!(
+        *player.listener().calls.lock(),
+        [
+            ("on_movement", Event::Jump),
+            ("on_damage", Event::Damage(0)),
+            ("on_movement", Event::Land),
+        ]
+    );
+}
+
+#[test]
+fn first_matching_pattern_wins() {
+    let player = Multi::new(Player::default());
+
+    assert!(matches!(
+        player.on_event(&Event::Damage(100)),
+        Some(PriorityDispatcherResult::StopListening)
+    ));
+    assert!(player.on_event(&Event::Damage(99)).is_none());
+
+    assert_eq!(
+        *player.listener().calls.lock(),
+        [
+            ("on_lethal_damage", Event::Damage(100)),
+            ("on_damage", Event::Damage(99)),
+        ]
+    );
+}
+
+#[test]
+fn unimplemented_methods_return_none() {
+    let player = Multi::new(Player::default());
+
+    assert!(player.on_event(&Event::Quit).is_none());
+    assert!(player.listener().calls.lock().is_empty());
+}
+
+#[test]
+fn requests_apply_to_the_registration_of_the_dispatched_key() {
+    let player = Multi::new(Player::default());
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    dispatcher.add_listener(Event::Damage(100), player.clone(), 1);
+    dispatcher.add_listener(Event::Jump, player.clone(), 1);
+
+    dispatcher.dispatch_event(&Event::Damage(100));
+    dispatcher.dispatch_event(&Event::Damage(100));
+    dispatcher.dispatch_event(&Event::Jump);
+
+    assert_eq!(dispatcher.listener_count(&Event::Damage(100)), 0);
+    assert_eq!(dispatcher.listener_count(&Event::Jump), 1);
+    assert_eq!(
+        *player.listener().calls.lock(),
+        [
+            ("on_lethal_damage", Event::Damage(100)),
+            ("on_movement", Event::Jump),
+        ]
+    );
+}
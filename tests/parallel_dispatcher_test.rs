@@ -1,8 +1,25 @@
+#![cfg(feature = "parallel")]
+
 use hey_listen::{
-    sync::{ParallelDispatchResult, ParallelDispatcher, ParallelListener},
+    fns::weakly,
+    key::ByDiscriminant,
+    sync::{
+        affinity::AffinityHost, DiscriminantParallelDispatcher, ParallelDispatchResult,
+        ParallelDispatcher, ParallelListener, Saturation,
+    },
     Mutex, RwLock,
 };
-use std::sync::Arc;
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Sender},
+        Arc,
+    },
+    thread::{self, ThreadId},
+    time::{Duration, Instant},
+};
 
 #[derive(Clone, Eq, Hash, PartialEq)]
 enum Event {
@@ -66,3 +83,935 @@ fn is_send_and_sync() {
     fn assert_send<T: Send + Sync>(_: &T) {}
     assert_send(&ParallelDispatcher::<Event>::new(0).unwrap());
 }
+
+#[test]
+fn stages_dispatch_in_order() {
+    struct RecordingListener {
+        name: &'static str,
+        record: Arc<Mutex<Vec<&'static str>>>,
+    }
+
+    impl ParallelListener<Event> for RecordingListener {
+        fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> {
+            self.record.lock().push(self.name);
+
+            None
+        }
+    }
+
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher =
+        ParallelDispatcher::<Event>::new(4).expect("Failed constructing threadpool");
+
+    dispatcher.add_stage("physics");
+    dispatcher.add_stage("ui");
+
+    for _ in 0..4 {
+        dispatcher
+            .add_listener_to_stage(
+                Event::VariantA,
+                "ui",
+                RecordingListener {
+                    name: "ui",
+                    record: Arc::clone(&record),
+                },
+            )
+            .unwrap();
+        dispatcher
+            .add_listener_to_stage(
+                Event::VariantA,
+                "physics",
+                RecordingListener {
+                    name: "physics",
+                    record: Arc::clone(&record),
+                },
+            )
+            .unwrap();
+    }
+
+    assert!(dispatcher
+        .add_listener_to_stage(
+            Event::VariantA,
+            "audio",
+            RecordingListener {
+                name: "audio",
+                record: Arc::clone(&record),
+            },
+        )
+        .is_err());
+
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    assert_eq!(
+        *record.lock(),
+        ["physics", "physics", "physics", "physics", "ui", "ui", "ui", "ui"]
+    );
+}
+
+#[test]
+fn drained_listeners_migrate_with_their_stages() {
+    #[derive(Clone)]
+    enum Payload {
+        Tick(u32),
+    }
+
+    struct RecordingListener {
+        name: &'static str,
+        record: Arc<Mutex<Vec<(&'static str, u32)>>>,
+    }
+
+    impl ParallelListener<Payload> for RecordingListener {
+        fn on_event(&self, Payload::Tick(tick): &Payload) -> Option<ParallelDispatchResult> {
+            self.record.lock().push((self.name, *tick));
+
+            None
+        }
+    }
+
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut old_world: DiscriminantParallelDispatcher<Payload> =
+        DiscriminantParallelDispatcher::new(1).expect("Failed constructing threadpool");
+    let mut new_world: DiscriminantParallelDispatcher<Payload> =
+        DiscriminantParallelDispatcher::new(1).expect("Failed constructing threadpool");
+
+    old_world.add_stage("ui");
+
+    for name in ["ui", "physics"] {
+        let listener = RecordingListener {
+            name,
+            record: Arc::clone(&record),
+        };
+
+        if name == "ui" {
+            old_world
+                .add_listener_to_stage(Payload::Tick(0), "ui", listener)
+                .unwrap();
+        } else {
+            old_world.add_listener(Payload::Tick(0), listener);
+        }
+    }
+
+    new_world.extend(old_world.drain());
+
+    old_world.dispatch_event(&Payload::Tick(1));
+    new_world.dispatch_event(&Payload::Tick(2));
+
+    // The missing stage has been added after the unnamed stage.
+    assert_eq!(*record.lock(), [("physics", 2), ("ui", 2)]);
+    assert_eq!(new_world.snapshot().listeners.len(), 2);
+}
+
+#[test]
+fn stop_listening_removes_all_requesting_listeners() {
+    struct StoppingListener {
+        times_dispatched: Arc<Mutex<usize>>,
+    }
+
+    impl ParallelListener<Event> for StoppingListener {
+        fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> {
+            *self.times_dispatched.lock() += 1;
+
+            Some(ParallelDispatchResult::StopListening)
+        }
+    }
+
+    let times_dispatched = Arc::new(Mutex::new(0));
+    let mut dispatcher =
+        ParallelDispatcher::<Event>::new(4).expect("Failed constructing threadpool");
+
+    for _ in 0..8 {
+        dispatcher.add_listener(
+            Event::VariantA,
+            StoppingListener {
+                times_dispatched: Arc::clone(&times_dispatched),
+            },
+        );
+    }
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    assert_eq!(*times_dispatched.lock(), 8);
+}
+
+#[test]
+fn dispatch_events_keeps_order_per_event_key() {
+    #[derive(Clone)]
+    enum Numbered {
+        A(usize),
+        B(usize),
+    }
+
+    struct Recorder(Arc<Mutex<Vec<usize>>>);
+
+    impl ParallelListener<Numbered> for Recorder {
+        fn on_event(&self, event: &Numbered) -> Option<ParallelDispatchResult> {
+            match event {
+                Numbered::A(number) | Numbered::B(number) => self.0.lock().push(*number),
+            }
+
+            None
+        }
+    }
+
+    let records_a = Arc::new(Mutex::new(Vec::new()));
+    let records_b = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher: ParallelDispatcher<Numbered, ByDiscriminant> =
+        ParallelDispatcher::new(2).expect("Failed constructing threadpool");
+
+    dispatcher.add_listener(Numbered::A(0), Recorder(Arc::clone(&records_a)));
+    dispatcher.add_listener(Numbered::B(0), Recorder(Arc::clone(&records_b)));
+
+    let events: Vec<_> = (0..16)
+        .map(|number| {
+            if number % 2 == 0 {
+                Numbered::A(number)
+            } else {
+                Numbered::B(number)
+            }
+        })
+        .collect();
+
+    dispatcher.dispatch_events(&events);
+
+    assert_eq!(*records_a.lock(), [0, 2, 4, 6, 8, 10, 12, 14]);
+    assert_eq!(*records_b.lock(), [1, 3, 5, 7, 9, 11, 13, 15]);
+}
+
+#[test]
+fn discriminant_dispatcher_ignores_fields() {
+    #[derive(Clone)]
+    enum Payload {
+        Damage(u32),
+        Heal(u32),
+    }
+
+    struct Recorder(Arc<Mutex<Vec<u32>>>);
+
+    impl ParallelListener<Payload> for Recorder {
+        fn on_event(&self, event: &Payload) -> Option<ParallelDispatchResult> {
+            match event {
+                Payload::Damage(amount) | Payload::Heal(amount) => self.0.lock().push(*amount),
+            }
+
+            None
+        }
+    }
+
+    let damage = Arc::new(Mutex::new(Vec::new()));
+    let heal = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher: DiscriminantParallelDispatcher<Payload> =
+        DiscriminantParallelDispatcher::new(1).expect("Failed constructing threadpool");
+
+    dispatcher.add_listener(Payload::Damage(0), Recorder(Arc::clone(&damage)));
+    dispatcher.add_listener(Payload::Heal(0), Recorder(Arc::clone(&heal)));
+
+    dispatcher.dispatch_event(&Payload::Damage(3));
+    dispatcher.dispatch_event(&Payload::Heal(5));
+    dispatcher.dispatch_event(&Payload::Damage(7));
+
+    assert_eq!(*damage.lock(), [3, 7]);
+    assert_eq!(*heal.lock(), [5]);
+}
+
+#[test]
+fn dispatch_with_threads_uses_at_most_given_threads() {
+    let threads = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher =
+        ParallelDispatcher::<Event>::new(4).expect("Failed constructing threadpool");
+
+    for _ in 0..16 {
+        let threads = Arc::clone(&threads);
+
+        dispatcher.add_fn(Event::VariantA, move |_event| {
+            threads.lock().push(thread::current().id());
+            thread::sleep(Duration::from_millis(1));
+
+            None
+        });
+    }
+
+    dispatcher.dispatch_event_with_threads(&Event::VariantA, 2);
+
+    let mut threads = threads.lock().clone();
+    assert_eq!(threads.len(), 16);
+
+    threads.sort_by_key(|id| format!("{:?}", id));
+    threads.dedup();
+    assert!(threads.len() <= 2);
+}
+
+#[test]
+fn affine_listener_runs_on_owning_thread() {
+    struct ThreadRecorder(Arc<Mutex<Vec<ThreadId>>>);
+
+    impl ParallelListener<Event> for ThreadRecorder {
+        fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> {
+            self.0.lock().push(thread::current().id());
+
+            None
+        }
+    }
+
+    let threads = Arc::new(Mutex::new(Vec::new()));
+    let host = AffinityHost::new(ThreadRecorder(Arc::clone(&threads)));
+    let mut dispatcher =
+        ParallelDispatcher::<Event>::new(4).expect("Failed constructing threadpool");
+
+    dispatcher.add_listener(Event::VariantA, host.listener());
+    dispatcher.add_listener(Event::VariantA, ThreadRecorder(Arc::clone(&threads)));
+
+    let dispatching = thread::spawn(move || {
+        dispatcher.dispatch_event(&Event::VariantA);
+        dispatcher.dispatch_event(&Event::VariantA);
+    });
+
+    host.run();
+    dispatching.join().unwrap();
+
+    let owner = thread::current().id();
+    let threads = threads.lock();
+    assert_eq!(threads.len(), 4);
+    assert_eq!(threads.iter().filter(|id| **id == owner).count(), 2);
+}
+
+#[test]
+fn main_thread_listeners_run_on_draining_thread() {
+    struct MainThreadRecorder(Rc<RefCell<Vec<ThreadId>>>);
+
+    impl ParallelListener<Event> for MainThreadRecorder {
+        fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> {
+            let mut calls = self.0.borrow_mut();
+            calls.push(thread::current().id());
+
+            if calls.len() == 2 {
+                Some(ParallelDispatchResult::StopListening)
+            } else {
+                None
+            }
+        }
+    }
+
+    let calls = Rc::new(RefCell::new(Vec::new()));
+    let mut dispatcher =
+        ParallelDispatcher::<Event>::new(4).expect("Failed constructing threadpool");
+    let mut main_thread = dispatcher.main_thread_queue();
+
+    main_thread.add_listener(Event::VariantA, MainThreadRecorder(Rc::clone(&calls)));
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_events(&[Event::VariantB, Event::VariantA]);
+    assert!(calls.borrow().is_empty());
+
+    main_thread.run_main_thread_work();
+    dispatcher.dispatch_event(&Event::VariantA);
+    main_thread.run_main_thread_work();
+
+    let owner = thread::current().id();
+    assert_eq!(*calls.borrow(), [owner, owner]);
+    assert!(main_thread.is_empty());
+}
+
+#[test]
+fn dropped_main_thread_queues_stop_receiving_events() {
+    struct Counter(Rc<RefCell<usize>>);
+
+    impl ParallelListener<Event> for Counter {
+        fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> {
+            *self.0.borrow_mut() += 1;
+
+            None
+        }
+    }
+
+    let mut dispatcher =
+        ParallelDispatcher::<Event>::new(1).expect("Failed constructing threadpool");
+    let mut kept = dispatcher.main_thread_queue();
+    let mut dropped = dispatcher.main_thread_queue();
+    let calls = Rc::new(RefCell::new(0));
+
+    let id = kept.add_listener(Event::VariantA, Counter(Rc::clone(&calls)));
+    dropped.add_listener(Event::VariantA, Counter(Rc::clone(&calls)));
+
+    drop(dropped);
+    dispatcher.dispatch_event(&Event::VariantA);
+    kept.run_main_thread_work();
+
+    assert_eq!(*calls.borrow(), 1);
+    assert_eq!(kept.listener_count(&Event::VariantA), 1);
+
+    assert!(kept.remove_listener(id));
+    assert!(kept.is_empty());
+}
+
+#[test]
+fn dead_main_thread_listeners_are_purged() {
+    struct Transient {
+        state: Weak<()>,
+        calls: Rc<RefCell<usize>>,
+    }
+
+    impl ParallelListener<Event> for Transient {
+        fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> {
+            *self.calls.borrow_mut() += 1;
+
+            None
+        }
+
+        fn is_alive(&self) -> bool {
+            self.state.strong_count() > 0
+        }
+    }
+
+    let mut dispatcher =
+        ParallelDispatcher::<Event>::new(1).expect("Failed constructing threadpool");
+    let mut queue = dispatcher.main_thread_queue();
+    let calls = Rc::new(RefCell::new(0));
+    let state = Rc::new(());
+
+    for event_key in &[Event::VariantA, Event::VariantB] {
+        for _ in 0..2 {
+            queue.add_listener(
+                event_key.clone(),
+                Transient {
+                    state: Rc::downgrade(&state),
+                    calls: Rc::clone(&calls),
+                },
+            );
+        }
+    }
+
+    drop(state);
+
+    // Adding to `VariantA` purges its dead listeners, running the queue
+    // skips and removes the dead ones of `VariantB`.
+    let state = Rc::new(());
+    queue.add_listener(
+        Event::VariantA,
+        Transient {
+            state: Rc::downgrade(&state),
+            calls: Rc::clone(&calls),
+        },
+    );
+    dispatcher.dispatch_event(&Event::VariantB);
+    queue.run_main_thread_work();
+
+    assert_eq!(*calls.borrow(), 0);
+    assert_eq!(queue.purge_dead(), 0);
+    assert_eq!(queue.listener_count(&Event::VariantA), 1);
+
+    drop(state);
+    assert_eq!(queue.purge_dead(), 1);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn inline_saturation_shares_listeners_with_dispatching_thread() {
+    let (sender, receiver) = mpsc::channel();
+    let sender = Arc::new(Mutex::new(sender));
+    let caller = thread::current().id();
+    let pooled_calls = Arc::new(AtomicUsize::new(0));
+    let mut dispatcher = ParallelDispatcher::<Event>::new(1).expect("Failed to build threadpool");
+
+    dispatcher.saturation(Saturation::Inline);
+
+    for _ in 0..4 {
+        let sender = Arc::clone(&sender);
+        let pooled_calls = Arc::clone(&pooled_calls);
+
+        dispatcher.add_fn(Event::VariantA, move |_event| {
+            let thread = thread::current().id();
+
+            // Keeps the dispatching thread busy, the thread-pool has to
+            // take over every other listener.
+            if thread == caller {
+                let deadline = Instant::now() + Duration::from_secs(5);
+
+                while pooled_calls.load(Ordering::SeqCst) < 3 && Instant::now() < deadline {
+                    thread::yield_now();
+                }
+            } else {
+                pooled_calls.fetch_add(1, Ordering::SeqCst);
+            }
+
+            sender.lock().send(thread).unwrap();
+
+            None
+        });
+    }
+
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    let calls: Vec<_> = receiver.try_iter().collect();
+    assert_eq!(calls.len(), 4);
+    assert!(calls.iter().filter(|id| **id == caller).count() <= 1);
+    assert!(pooled_calls.load(Ordering::SeqCst) >= 3);
+}
+
+#[test]
+fn pinned_threads_keep_pinning_across_rebuilds() {
+    let pinned = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = ParallelDispatcher::<Event>::new(2).unwrap();
+
+    let record = Arc::clone(&pinned);
+    dispatcher
+        .pin_threads(move |index| record.lock().push(index))
+        .unwrap();
+
+    let mut indices = pinned.lock().clone();
+    indices.sort_unstable();
+    assert_eq!(indices, [0, 1]);
+
+    pinned.lock().clear();
+    dispatcher.num_threads(3).unwrap();
+    dispatcher.warm_up();
+
+    let mut indices = pinned.lock().clone();
+    indices.sort_unstable();
+    assert_eq!(indices, [0, 1, 2]);
+}
+
+#[test]
+fn emitted_events_dispatch_after_current_dispatch() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = ParallelDispatcher::<Event>::new(2).expect("Failed to build threadpool");
+
+    for name in &["a1", "a2"] {
+        let (emitter, record) = (dispatcher.emitter(), Arc::clone(&record));
+
+        dispatcher.add_fn(Event::VariantA, move |_event| {
+            record.lock().push(*name);
+            emitter.emit(Event::VariantB);
+
+            None
+        });
+    }
+
+    let b_record = Arc::clone(&record);
+    dispatcher.add_fn(Event::VariantB, move |_event| {
+        b_record.lock().push("b");
+
+        None
+    });
+
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    let record = record.lock();
+    assert_eq!(record.len(), 4);
+    assert!(record[..2].contains(&"a1") && record[..2].contains(&"a2"));
+    assert_eq!(record[2..], ["b", "b"]);
+}
+
+#[test]
+fn prioritised_emitted_events_dispatch_by_event_priority() {
+    use hey_listen::sync::EventPriority;
+
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    enum Job {
+        Render(u8),
+        Input(u8),
+    }
+
+    impl EventPriority<u32> for Job {
+        fn priority(&self) -> u32 {
+            match self {
+                Job::Input(_) => 0,
+                Job::Render(_) => 1,
+            }
+        }
+    }
+
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = ParallelDispatcher::<Job>::new(1).expect("Failed to build threadpool");
+    dispatcher.prioritise_emitted::<u32>();
+
+    for job in &[Job::Render(0), Job::Input(0), Job::Render(1), Job::Input(1)] {
+        let record = Arc::clone(&record);
+
+        dispatcher.add_fn(job.clone(), move |event| {
+            record.lock().push(event.clone());
+
+            None
+        });
+    }
+
+    let emitter = dispatcher.emitter();
+    emitter.emit(Job::Render(0));
+    emitter.emit(Job::Input(0));
+    emitter.emit(Job::Render(1));
+    emitter.emit(Job::Input(1));
+    dispatcher.dispatch_emitted();
+
+    assert_eq!(
+        *record.lock(),
+        [Job::Input(0), Job::Input(1), Job::Render(0), Job::Render(1)]
+    );
+}
+
+#[test]
+fn tracked_emits_complete_with_dispatch_summary() {
+    let mut dispatcher = ParallelDispatcher::<Event>::new(2).expect("Failed to build threadpool");
+    let emitter = dispatcher.emitter();
+
+    dispatcher.add_fn(Event::VariantA, |_event| None);
+    dispatcher.add_fn(Event::VariantA, |_event| {
+        Some(ParallelDispatchResult::StopListening)
+    });
+
+    let first = emitter.emit_tracked(Event::VariantA);
+    let second = emitter.emit_tracked(Event::VariantA);
+    let unheard = emitter.emit_tracked(Event::VariantB);
+
+    let producer = thread::spawn(move || (first.wait(), second.wait(), unheard.wait()));
+    dispatcher.dispatch_emitted();
+
+    let (first, second, unheard) = producer.join().unwrap();
+    let first = first.unwrap();
+    let second = second.unwrap();
+
+    assert_eq!((first.listeners, first.stopped_listening), (2, 1));
+    assert_eq!((second.listeners, second.stopped_listening), (1, 0));
+    assert_eq!(unheard.unwrap().listeners, 0);
+
+    let dropped = emitter.emit_tracked(Event::VariantA);
+    drop((dispatcher, emitter));
+    assert_eq!(dropped.wait(), None);
+}
+
+#[test]
+fn single_listener_stops_listening() {
+    let mut dispatcher = ParallelDispatcher::<Event>::new(2).expect("Failed to build threadpool");
+
+    dispatcher.add_fn(Event::VariantA, |_event| {
+        Some(ParallelDispatchResult::StopListening)
+    });
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert!(dispatcher.snapshot().listeners.is_empty());
+}
+
+#[test]
+fn weakly_stops_listening_once_shared_state_drops() {
+    let counter = Arc::new(Mutex::new(0));
+    let mut dispatcher = ParallelDispatcher::<Event>::new(1).expect("Failed to build threadpool");
+
+    dispatcher.add_listener(
+        Event::VariantA,
+        weakly(&counter, |counter, _event| {
+            *counter.lock() += 1;
+
+            None
+        }),
+    );
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(*counter.lock(), 1);
+
+    drop(counter);
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert!(dispatcher.snapshot().listeners.is_empty());
+}
+
+#[test]
+fn dead_weakly_listeners_are_purged_without_dispatching() {
+    let transient = Arc::new(());
+    let mut dispatcher = ParallelDispatcher::<Event>::new(1).expect("Failed to build threadpool");
+
+    for _ in 0..2 {
+        dispatcher.add_listener(Event::VariantA, weakly(&transient, |_, _event| None));
+        dispatcher.add_listener(Event::VariantB, weakly(&transient, |_, _event| None));
+    }
+
+    drop(transient);
+
+    // Adding to `VariantA` purges its dead listeners only, the dead ones of
+    // `VariantB` are merely no longer counted.
+    dispatcher.add_fn(Event::VariantA, |_event| None);
+    assert_eq!(dispatcher.total_listeners(), 1);
+
+    assert_eq!(dispatcher.purge_dead(), 2);
+    assert_eq!(dispatcher.purge_dead(), 0);
+    assert_eq!(dispatcher.snapshot().listeners.len(), 1);
+}
+
+#[test]
+fn unsubscribed_listeners_are_not_counted_as_removed() {
+    struct Idle;
+
+    impl ParallelListener<Event> for Idle {
+        fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> {
+            None
+        }
+    }
+
+    let mut dispatcher = ParallelDispatcher::<Event>::new(1).expect("Failed to build threadpool");
+
+    dispatcher.add_listener(Event::VariantA, Idle);
+    let guard = dispatcher.subscribe_scoped(Event::VariantA, Idle);
+
+    drop(guard);
+
+    assert_eq!(dispatcher.remove_all_listeners(&Event::VariantA), 1);
+    assert_eq!(dispatcher.purge_dead(), 0);
+}
+
+#[test]
+#[cfg(feature = "priority")]
+fn priority_listeners_migrate_to_parallel_dispatch() {
+    use hey_listen::sync::{PriorityDispatcher, PriorityDispatcherResult};
+
+    let calls = Arc::new(Mutex::new(0));
+    let mut sequential = PriorityDispatcher::<u32, Event>::default();
+
+    for _ in 0..2 {
+        let calls = Arc::clone(&calls);
+
+        sequential.add_fn(
+            Event::VariantA,
+            move |_event| {
+                *calls.lock() += 1;
+
+                Some(PriorityDispatcherResult::StopPropagation)
+            },
+            1,
+        );
+    }
+
+    sequential.add_fn(
+        Event::VariantA,
+        |_event| Some(PriorityDispatcherResult::StopListeningAndPropagation),
+        2,
+    );
+
+    let mut parallel = ParallelDispatcher::<Event>::new(2).expect("Failed to build threadpool");
+    parallel.extend_from_priority(sequential);
+    parallel.dispatch_event(&Event::VariantA);
+
+    assert_eq!(*calls.lock(), 2);
+    assert_eq!(parallel.snapshot().listeners.len(), 2);
+}
+
+#[test]
+fn follow_up_events_inherit_correlation() {
+    use hey_listen::sync::correlation::Correlation;
+
+    let correlations = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = ParallelDispatcher::<Event>::new(2).expect("Failed to build threadpool");
+    let emitter = dispatcher.emitter();
+
+    for _ in 0..2 {
+        let correlations = Arc::clone(&correlations);
+        let emitter = emitter.clone();
+
+        dispatcher.add_fn(Event::VariantA, move |_event| {
+            correlations
+                .lock()
+                .push((Event::VariantA, Correlation::current().unwrap()));
+            emitter.emit(Event::VariantB);
+
+            None
+        });
+    }
+
+    let recorded = Arc::clone(&correlations);
+    dispatcher.add_fn(Event::VariantB, move |_event| {
+        recorded
+            .lock()
+            .push((Event::VariantB, Correlation::current().unwrap()));
+
+        None
+    });
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    assert!(Correlation::current().is_none());
+
+    let correlations = correlations.lock();
+    let causes: Vec<_> = correlations
+        .iter()
+        .filter(|(event, _)| *event == Event::VariantA)
+        .map(|(_, correlation)| *correlation)
+        .collect();
+    let follow_ups: Vec<_> = correlations
+        .iter()
+        .filter(|(event, _)| *event == Event::VariantB)
+        .map(|(_, correlation)| *correlation)
+        .collect();
+
+    assert_eq!(follow_ups.len(), 4);
+    assert_ne!(causes[0].correlation_id(), causes[3].correlation_id());
+    assert!(causes.iter().all(|cause| cause.causation_id().is_none()));
+
+    for follow_up in follow_ups {
+        let cause = causes
+            .iter()
+            .find(|cause| Some(cause.event_id()) == follow_up.causation_id())
+            .expect("Follow-up without cause");
+
+        assert_eq!(follow_up.correlation_id(), cause.correlation_id());
+        assert_ne!(follow_up.event_id(), cause.event_id());
+    }
+}
+
+#[test]
+fn remove_all_listeners_wipes_all_stages() {
+    struct Listener;
+
+    impl ParallelListener<Event> for Listener {
+        fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> {
+            None
+        }
+    }
+
+    let mut dispatcher: ParallelDispatcher<Event> =
+        ParallelDispatcher::new(1).expect("Failed to build threadpool");
+
+    dispatcher.add_stage("late");
+    dispatcher.add_fn(Event::VariantA, |_event| None);
+    dispatcher
+        .add_listener_to_stage(Event::VariantA, "late", Listener)
+        .expect("Stage does not exist");
+    dispatcher.add_fn(Event::VariantB, |_event| None);
+
+    assert_eq!(dispatcher.remove_all_listeners(&Event::VariantA), 2);
+    assert_eq!(dispatcher.remove_all_listeners(&Event::VariantA), 0);
+    assert_eq!(dispatcher.snapshot().listeners.len(), 1);
+
+    dispatcher.clear();
+    assert!(dispatcher.snapshot().listeners.is_empty());
+}
+
+#[test]
+fn introspection_counts_listeners_of_all_stages() {
+    struct Listener;
+
+    impl ParallelListener<Event> for Listener {
+        fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> {
+            None
+        }
+    }
+
+    let mut dispatcher: ParallelDispatcher<Event> =
+        ParallelDispatcher::new(1).expect("Failed to build threadpool");
+
+    assert!(dispatcher.is_empty());
+
+    dispatcher.add_stage("late");
+    dispatcher.add_fn(Event::VariantA, |_event| None);
+    dispatcher
+        .add_listener_to_stage(Event::VariantA, "late", Listener)
+        .expect("Stage does not exist");
+    dispatcher.add_fn(Event::VariantB, |_event| None);
+
+    assert_eq!(dispatcher.listener_count(&Event::VariantA), 2);
+    assert_eq!(dispatcher.listener_count(&Event::VariantB), 1);
+    assert_eq!(dispatcher.total_listeners(), 3);
+    assert_eq!(dispatcher.event_keys().len(), 2);
+    assert!(dispatcher.event_keys().contains(&Event::VariantB));
+    assert!(!dispatcher.is_empty());
+
+    assert_eq!(dispatcher.remove_all_listeners(&Event::VariantA), 2);
+    assert!(!dispatcher.is_empty());
+    assert_eq!(dispatcher.event_keys().len(), 1);
+
+    dispatcher.clear();
+    assert!(dispatcher.is_empty());
+    assert!(dispatcher.event_keys().is_empty());
+}
+
+#[test]
+fn dispatch_reduce_combines_outputs_by_type() {
+    let mut dispatcher: ParallelDispatcher<Event> =
+        ParallelDispatcher::new(4).expect("Failed to build threadpool");
+
+    for vote in 0..100_u32 {
+        dispatcher.add_output_fn(Event::VariantA, move |_event| vote % 2 == 0);
+        dispatcher.add_output_fn(Event::VariantA, move |_event| u64::from(vote));
+    }
+
+    let yes_votes = dispatcher.dispatch_reduce(&Event::VariantA, || 0_usize, |a, b| a + b);
+    let total = dispatcher.dispatch_reduce(&Event::VariantA, || 0_u64, |a, b| a + b);
+    let is_unanimous = dispatcher.dispatch_reduce(&Event::VariantA, || true, |a, b| a && b);
+
+    assert_eq!(yes_votes, 0, "No listener returns `usize`");
+    assert_eq!(total, 4950);
+    assert!(!is_unanimous);
+    assert_eq!(
+        dispatcher.dispatch_reduce(&Event::VariantB, || 7_u64, |a, b| a + b),
+        7
+    );
+
+    dispatcher.clear();
+    assert_eq!(
+        dispatcher.dispatch_reduce(&Event::VariantA, || 0_u64, |a, b| a + b),
+        0
+    );
+}
+
+#[test]
+fn output_listeners_are_counted_and_removed() {
+    let mut dispatcher: ParallelDispatcher<Event> =
+        ParallelDispatcher::new(1).expect("Failed to build threadpool");
+
+    dispatcher.add_output_fn(Event::VariantA, |_event| 1_u32);
+    dispatcher.add_output_fn(Event::VariantA, |_event| 2_u64);
+    dispatcher.add_output_fn(Event::VariantB, |_event| 3_u32);
+    dispatcher.add_fn(Event::VariantB, |_event| None);
+
+    assert_eq!(dispatcher.listener_count(&Event::VariantA), 2);
+    assert_eq!(dispatcher.total_listeners(), 4);
+    assert_eq!(dispatcher.event_keys().len(), 2);
+    assert_eq!(dispatcher.snapshot().listeners.len(), 4);
+
+    // Output-listeners are removed, but only `ParallelListener`s returned.
+    assert_eq!(dispatcher.take_listeners(&Event::VariantB).len(), 1);
+    assert_eq!(dispatcher.listener_count(&Event::VariantB), 0);
+    assert_eq!(
+        dispatcher.dispatch_reduce(&Event::VariantB, || 0_u32, |a, b| a + b),
+        0
+    );
+
+    assert_eq!(dispatcher.remove_all_listeners(&Event::VariantA), 2);
+    assert!(dispatcher.is_empty());
+
+    dispatcher.add_output_fn(Event::VariantA, |_event| 1_u32);
+    assert!(dispatcher.drain().is_empty());
+    assert!(dispatcher.is_empty());
+}
+
+#[test]
+fn listeners_are_removed_by_id_from_any_stage() {
+    struct Named(&'static str, Arc<Mutex<Sender<&'static str>>>);
+
+    impl ParallelListener<Event> for Named {
+        fn on_event(&self, _event: &Event) -> Option<ParallelDispatchResult> {
+            self.1.lock().send(self.0).unwrap();
+
+            None
+        }
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let sender = Arc::new(Mutex::new(sender));
+    let named = |name| Named(name, Arc::clone(&sender));
+    let mut dispatcher = ParallelDispatcher::<Event>::new(2).expect("Failed to build threadpool");
+
+    dispatcher.add_stage("late");
+
+    let early = dispatcher.add_listener(Event::VariantA, named("early"));
+    let late = dispatcher
+        .add_listener_to_stage(Event::VariantA, "late", named("late"))
+        .unwrap();
+    dispatcher.add_listener(Event::VariantA, named("kept"));
+
+    for id in [early, late] {
+        assert!(dispatcher.remove_listener(id));
+        assert!(!dispatcher.remove_listener(id));
+    }
+
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    assert_eq!(receiver.try_iter().collect::<Vec<_>>(), ["kept"]);
+    assert_eq!(dispatcher.listener_count(&Event::VariantA), 1);
+}
@@ -1,10 +1,26 @@
+#![cfg(feature = "priority")]
+
 use hey_listen::{
-    sync::{PriorityDispatcher, PriorityDispatcherResult, PriorityListener},
-    RwLock,
+    audit::DropAudit,
+    fns::bind,
+    key::{EnumKey, Interned, Interner, KeyExtract},
+    panic_policy::PanicPolicy,
+    snapshot::{ListenerEntry, Snapshot},
+    sync::{
+        DiscriminantPriorityDispatcher, ListenerRegistry, PriorityDispatcher,
+        PriorityDispatcherResult, PriorityListener,
+    },
+    Error, Mutex, RwLock,
+};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
 };
-use std::sync::Arc;
 
-#[derive(Clone, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 enum Event {
     EventType,
 }
@@ -110,6 +126,36 @@ fn stop_listening() {
     assert_eq!(receiver.try_write().unwrap().times_dispatched, 1);
 }
 
+#[test]
+fn stop_listening_keeps_order_within_priority() {
+    struct Leaving;
+
+    impl PriorityListener<Event> for Leaving {
+        fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> {
+            Some(PriorityDispatcherResult::StopListening)
+        }
+    }
+
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    dispatcher.add_listener(Event::EventType, Leaving, 1);
+
+    for name in &["a", "b", "c"] {
+        let listener = Arc::new(RwLock::new(EventListener {
+            name: (*name).to_string(),
+            name_record: Arc::clone(&names_record),
+        }));
+
+        dispatcher.add_listener(Event::EventType, listener, 1);
+    }
+
+    dispatcher.dispatch_event(&Event::EventType);
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.read(), ["a", "b", "c", "a", "b", "c"]);
+}
+
 #[test]
 fn stop_propagation() {
     #[derive(Default)]
@@ -168,7 +214,1450 @@ fn stop_listening_and_propagation() {
 }
 
 #[test]
-fn is_send_and_sync() {
-    fn assert_send<T: Send + Sync>(_: &T) {}
-    assert_send(&PriorityDispatcher::<u32, Event>::default());
+fn dispatch_with_builds_event_only_for_listeners() {
+    #[derive(Default)]
+    struct EventListener {
+        times_dispatched: usize,
+    }
+
+    impl PriorityListener<Event> for Arc<RwLock<EventListener>> {
+        fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> {
+            self.write().times_dispatched += 1;
+
+            Some(PriorityDispatcherResult::StopListening)
+        }
+    }
+
+    let receiver = Arc::new(RwLock::new(EventListener::default()));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    let mut times_built = 0;
+
+    dispatcher.add_listener(Event::EventType, Arc::clone(&receiver), 0);
+
+    dispatcher.dispatch_with(&Event::EventType, || {
+        times_built += 1;
+
+        Event::EventType
+    });
+    dispatcher.dispatch_with(&Event::EventType, || {
+        times_built += 1;
+
+        Event::EventType
+    });
+
+    assert_eq!(times_built, 1);
+    assert_eq!(receiver.try_write().unwrap().times_dispatched, 1);
+}
+
+#[test]
+fn unstopped_events_propagate_to_parent() {
+    struct EventListener {
+        times_dispatched: Arc<RwLock<usize>>,
+        request: fn() -> Option<PriorityDispatcherResult>,
+    }
+
+    impl PriorityListener<Event> for EventListener {
+        fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> {
+            *self.times_dispatched.write() += 1;
+
+            (self.request)()
+        }
+    }
+
+    let parent_dispatched = Arc::new(RwLock::new(0));
+    let child_dispatched = Arc::new(RwLock::new(0));
+    let parent = Arc::new(Mutex::new(PriorityDispatcher::<u32, Event>::default()));
+    let mut child = PriorityDispatcher::<u32, Event>::default();
+
+    parent.lock().add_listener(
+        Event::EventType,
+        EventListener {
+            times_dispatched: Arc::clone(&parent_dispatched),
+            request: || None,
+        },
+        0,
+    );
+    child.set_parent(&parent);
+
+    // Without listeners, the child still forwards to its parent.
+    child.dispatch_event(&Event::EventType);
+    assert_eq!(*parent_dispatched.read(), 1);
+
+    child.add_listener(
+        Event::EventType,
+        EventListener {
+            times_dispatched: Arc::clone(&child_dispatched),
+            request: || Some(PriorityDispatcherResult::StopListeningAndPropagation),
+        },
+        0,
+    );
+
+    child.dispatch_event(&Event::EventType);
+    assert_eq!(*child_dispatched.read(), 1);
+    assert_eq!(*parent_dispatched.read(), 1);
+
+    child.dispatch_event(&Event::EventType);
+    assert_eq!(*child_dispatched.read(), 1);
+    assert_eq!(*parent_dispatched.read(), 2);
+
+    drop(parent);
+    child.dispatch_event(&Event::EventType);
+    assert_eq!(*parent_dispatched.read(), 2);
+}
+
+#[test]
+fn self_reentrant_dispatch_fails_instead_of_deadlocking() {
+    struct Echo {
+        dispatcher: Arc<Mutex<PriorityDispatcher<u32, Event>>>,
+        results: Arc<RwLock<Vec<Result<(), Error>>>>,
+    }
+
+    impl PriorityListener<Event> for Echo {
+        fn on_event(&self, event: &Event) -> Option<PriorityDispatcherResult> {
+            let result = PriorityDispatcher::try_dispatch_locked(&self.dispatcher, event);
+            self.results.write().push(result);
+
+            None
+        }
+    }
+
+    let results = Arc::new(RwLock::new(Vec::new()));
+    let dispatcher = Arc::new(Mutex::new(PriorityDispatcher::<u32, Event>::default()));
+
+    dispatcher.lock().add_listener(
+        Event::EventType,
+        Echo {
+            dispatcher: Arc::clone(&dispatcher),
+            results: Arc::clone(&results),
+        },
+        0,
+    );
+
+    PriorityDispatcher::try_dispatch_locked(&dispatcher, &Event::EventType).unwrap();
+    dispatcher.lock().dispatch_event(&Event::EventType);
+
+    assert!(matches!(
+        results.read().as_slice(),
+        [Err(Error::ReentrantDispatch), Err(Error::ReentrantDispatch)]
+    ));
+
+    // Once the dispatch has finished, dispatching succeeds again.
+    dispatcher.lock().clear();
+    assert!(PriorityDispatcher::try_dispatch_locked(&dispatcher, &Event::EventType).is_ok());
+}
+
+#[test]
+#[cfg(feature = "parallel")]
+fn self_reentrant_dispatch_from_parallel_tiers_fails_instead_of_deadlocking() {
+    struct Echo {
+        dispatcher: Arc<Mutex<PriorityDispatcher<u32, Event>>>,
+        results: Arc<RwLock<Vec<Result<(), Error>>>>,
+    }
+
+    impl PriorityListener<Event> for Echo {
+        fn on_event(&self, event: &Event) -> Option<PriorityDispatcherResult> {
+            let result = PriorityDispatcher::try_dispatch_locked(&self.dispatcher, event);
+            self.results.write().push(result);
+
+            None
+        }
+    }
+
+    let results = Arc::new(RwLock::new(Vec::new()));
+    let dispatcher = Arc::new(Mutex::new(
+        PriorityDispatcher::<u32, Event>::default().parallel_tiers(2),
+    ));
+
+    for _ in 0..4 {
+        dispatcher.lock().add_listener(
+            Event::EventType,
+            Echo {
+                dispatcher: Arc::clone(&dispatcher),
+                results: Arc::clone(&results),
+            },
+            0,
+        );
+    }
+
+    PriorityDispatcher::try_dispatch_locked(&dispatcher, &Event::EventType).unwrap();
+
+    let results = results.read();
+    assert_eq!(results.len(), 4);
+    assert!(results
+        .iter()
+        .all(|result| matches!(result, Err(Error::ReentrantDispatch))));
+}
+
+#[test]
+fn inherited_priority_skips_earlier_listeners() {
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    for priority in 1..=3 {
+        let listener = Arc::new(RwLock::new(EventListener {
+            name: priority.to_string(),
+            name_record: Arc::clone(&names_record),
+        }));
+
+        dispatcher.add_listener(Event::EventType, listener, priority);
+    }
+
+    dispatcher.dispatch_inherited(&Event::EventType, Some(&2));
+    assert_eq!(*names_record.read(), ["2", "3"]);
+
+    names_record.write().clear();
+    dispatcher.dispatch_inherited(&Event::EventType, None);
+    assert_eq!(*names_record.read(), ["1", "2", "3"]);
+
+    names_record.write().clear();
+    dispatcher.dispatch_inherited(&Event::EventType, Some(&4));
+    assert!(names_record.read().is_empty());
+}
+
+#[test]
+fn dispatch_between_skips_listeners_outside_band() {
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    for priority in 1..=4 {
+        let listener = Arc::new(RwLock::new(EventListener {
+            name: priority.to_string(),
+            name_record: Arc::clone(&names_record),
+        }));
+
+        dispatcher.add_listener(Event::EventType, listener, priority);
+    }
+
+    dispatcher.dispatch_event_between(&Event::EventType, 2..=3);
+    assert_eq!(*names_record.read(), ["2", "3"]);
+
+    names_record.write().clear();
+    dispatcher.dispatch_event_between(&Event::EventType, ..2);
+    assert_eq!(*names_record.read(), ["1"]);
+
+    names_record.write().clear();
+    dispatcher.dispatch_event_between(&Event::EventType, 5..);
+    assert!(names_record.read().is_empty());
+}
+
+#[test]
+fn named_stages_dispatch_in_defined_order() {
+    struct StageListener {
+        stage: &'static str,
+        record: Arc<RwLock<Vec<&'static str>>>,
+    }
+
+    impl PriorityListener<Event> for StageListener {
+        fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> {
+            self.record.write().push(self.stage);
+
+            None
+        }
+    }
+
+    let record = Arc::new(RwLock::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<usize, Event>::default();
+
+    dispatcher.define_stages(["input", "logic"]);
+    dispatcher.define_stages(["render", "input"]);
+
+    for stage in &["render", "input", "logic"] {
+        let listener = StageListener {
+            stage,
+            record: Arc::clone(&record),
+        };
+
+        dispatcher
+            .add_listener_to_stage(Event::EventType, stage, listener)
+            .unwrap();
+    }
+
+    let listener = StageListener {
+        stage: "audio",
+        record: Arc::clone(&record),
+    };
+
+    assert!(matches!(
+        dispatcher.add_listener_to_stage(Event::EventType, "audio", listener),
+        Err(Error::UnknownStage(stage)) if stage == "audio"
+    ));
+
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*record.read(), ["input", "logic", "render"]);
+}
+
+#[test]
+fn guard_skips_and_counts_dispatches() {
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let is_enabled = Arc::new(AtomicUsize::new(0));
+    let guard_enabled = Arc::clone(&is_enabled);
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    let listener = Arc::new(RwLock::new(EventListener {
+        name: "1".to_string(),
+        name_record: Arc::clone(&names_record),
+    }));
+
+    dispatcher.add_listener(Event::EventType, listener, 1);
+    dispatcher.set_guard(
+        Event::EventType,
+        Box::new(move |_| guard_enabled.load(Ordering::SeqCst) > 0),
+    );
+
+    let audit: DropAudit<Event> = DropAudit::new(());
+    dispatcher.set_drop_hook(audit.clone());
+
+    dispatcher.dispatch_event(&Event::EventType);
+    dispatcher.dispatch_event(&Event::EventType);
+    assert!(names_record.read().is_empty());
+    assert_eq!(dispatcher.skipped_dispatches(&Event::EventType), 2);
+    assert_eq!(audit.dropped(&Event::EventType), 2);
+
+    is_enabled.store(1, Ordering::SeqCst);
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(*names_record.read(), ["1"]);
+
+    is_enabled.store(0, Ordering::SeqCst);
+    assert!(dispatcher.remove_guard(&Event::EventType).is_some());
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(*names_record.read(), ["1", "1"]);
+    assert_eq!(dispatcher.skipped_dispatches(&Event::EventType), 2);
+}
+
+#[test]
+fn bound_method_receives_events() {
+    #[derive(Default)]
+    struct Counter {
+        count: usize,
+    }
+
+    impl Counter {
+        fn count(&mut self, _event: &Event) -> Option<PriorityDispatcherResult> {
+            self.count += 1;
+
+            (self.count == 2).then(|| PriorityDispatcherResult::StopListening)
+        }
+    }
+
+    let counter = Arc::new(Mutex::new(Counter::default()));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    dispatcher.add_listener(Event::EventType, bind(&counter, Counter::count), 1);
+
+    for _ in 0..3 {
+        dispatcher.dispatch_event(&Event::EventType);
+    }
+
+    assert_eq!(counter.lock().count, 2);
+}
+
+#[test]
+#[cfg(feature = "parallel")]
+fn parallel_tiers_dispatch_whole_tier_before_stopping() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default().parallel_tiers(3);
+
+    for index in 0..3 {
+        let calls = Arc::clone(&calls);
+
+        dispatcher.add_fn(
+            Event::EventType,
+            move |_event| {
+                calls.fetch_add(1, Ordering::SeqCst);
+
+                match index {
+                    0 => Some(PriorityDispatcherResult::StopListeningAndPropagation),
+                    _ => None,
+                }
+            },
+            1,
+        );
+    }
+
+    let later_calls = Arc::new(AtomicUsize::new(0));
+    let later = Arc::clone(&later_calls);
+    dispatcher.add_fn(
+        Event::EventType,
+        move |_event| {
+            later.fetch_add(1, Ordering::SeqCst);
+
+            None
+        },
+        2,
+    );
+
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    assert_eq!(later_calls.load(Ordering::SeqCst), 0);
+
+    // Two listeners remain, the tier is dispatched to sequentially again.
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(calls.load(Ordering::SeqCst), 5);
+    assert_eq!(later_calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+#[cfg(feature = "parallel")]
+fn ordered_listeners_keep_parallel_tiers_sequential() {
+    use hey_listen::order::Ordered;
+
+    struct Gate;
+
+    impl PriorityListener<Event> for Gate {
+        fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> {
+            Some(PriorityDispatcherResult::StopPropagation)
+        }
+    }
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default().parallel_tiers(2);
+
+    dispatcher.add_listener(Event::EventType, Ordered(Gate), 1);
+
+    for _ in 0..3 {
+        let calls = Arc::clone(&calls);
+
+        dispatcher.add_fn(
+            Event::EventType,
+            move |_event| {
+                calls.fetch_add(1, Ordering::SeqCst);
+
+                None
+            },
+            1,
+        );
+    }
+
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn key_extract_groups_events_by_derived_key() {
+    #[derive(Clone)]
+    enum Payload {
+        Damage(u32),
+        Heal,
+    }
+
+    struct IsDamage;
+
+    impl KeyExtract<Payload> for IsDamage {
+        type Key = bool;
+
+        fn extract(event: &Payload) -> Self::Key {
+            matches!(event, Payload::Damage(_))
+        }
+    }
+
+    struct DamageListener {
+        record: Arc<RwLock<Vec<u32>>>,
+    }
+
+    impl PriorityListener<Payload> for DamageListener {
+        fn on_event(&self, event: &Payload) -> Option<PriorityDispatcherResult> {
+            if let Payload::Damage(amount) = event {
+                self.record.write().push(*amount);
+            }
+
+            None
+        }
+    }
+
+    let record = Arc::new(RwLock::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, Payload, IsDamage>::default();
+
+    dispatcher.add_listener(
+        Payload::Damage(0),
+        DamageListener {
+            record: Arc::clone(&record),
+        },
+        0,
+    );
+
+    dispatcher.dispatch_event(&Payload::Damage(3));
+    dispatcher.dispatch_event(&Payload::Heal);
+    dispatcher.dispatch_event(&Payload::Damage(7));
+
+    assert_eq!(*record.read(), [3, 7]);
+    assert!(dispatcher.snapshot().listeners[0].event_key);
+}
+
+#[test]
+fn discriminant_dispatcher_ignores_fields() {
+    #[derive(Clone)]
+    enum Payload {
+        Damage(u32),
+        Heal(u32),
+    }
+
+    struct DamageListener {
+        record: Arc<RwLock<Vec<u32>>>,
+    }
+
+    impl PriorityListener<Payload> for DamageListener {
+        fn on_event(&self, event: &Payload) -> Option<PriorityDispatcherResult> {
+            match event {
+                Payload::Damage(amount) => self.record.write().push(*amount),
+                Payload::Heal(amount) => panic!("Healed a damage-listener by {}", amount),
+            }
+
+            None
+        }
+    }
+
+    let record = Arc::new(RwLock::new(Vec::new()));
+    let mut dispatcher: DiscriminantPriorityDispatcher<u32, Payload> =
+        DiscriminantPriorityDispatcher::default();
+
+    dispatcher.add_listener(
+        Payload::Damage(0),
+        DamageListener {
+            record: Arc::clone(&record),
+        },
+        0,
+    );
+
+    dispatcher.dispatch_event(&Payload::Damage(3));
+    dispatcher.dispatch_event(&Payload::Heal(5));
+    dispatcher.dispatch_event(&Payload::Damage(7));
+
+    assert_eq!(*record.read(), [3, 7]);
+}
+
+#[test]
+fn interned_names_dispatch_by_id() {
+    let mut interner = Interner::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut dispatcher = PriorityDispatcher::<u32, _>::default();
+
+    let jump = interner.intern("player.jump");
+    let land = interner.intern("player.land");
+
+    assert_eq!(interner.intern("player.jump"), jump);
+    assert_ne!(jump, land);
+    assert_eq!(interner.id("player.land"), Some(land));
+    assert_eq!(interner.id("player.duck"), None);
+    assert_eq!(interner.resolve(land), Some("player.land"));
+
+    let counter = Arc::clone(&calls);
+    dispatcher.add_fn(
+        jump,
+        move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            None
+        },
+        0,
+    );
+
+    dispatcher.dispatch_event(&jump);
+    dispatcher.dispatch_event(&land);
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn interned_keys_look_listeners_up_by_id() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut dispatcher = PriorityDispatcher::<u32, String, Interned>::default();
+
+    let counter = Arc::clone(&calls);
+    dispatcher.add_fn(
+        String::from("interned.jump"),
+        move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            None
+        },
+        0,
+    );
+
+    let jump = Interned::id("interned.jump").expect("Name is interned at registration");
+    assert_eq!(Interned::resolve(jump).as_deref(), Some("interned.jump"));
+    assert_eq!(Interned::id("interned.never-registered"), None);
+
+    // The event's own name is never consulted, only the id selects listeners.
+    dispatcher.dispatch_by_id(jump, &String::from("interned.land"));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    dispatcher.dispatch_event(&String::from("interned.jump"));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    dispatcher.dispatch_event(&String::from("interned.land"));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_ne!(Interned::id("interned.land"), Some(jump));
+}
+
+#[test]
+fn typed_dispatch_combines_outputs_per_key() {
+    use hey_listen::typed::EventResult;
+
+    #[derive(Clone, Eq, Hash, PartialEq)]
+    enum Modifier {
+        Damage,
+        Armour,
+    }
+
+    impl EventResult for Modifier {
+        type Output = i32;
+
+        fn combine(total: &mut i32, modifier: i32) {
+            *total += modifier;
+        }
+    }
+
+    let mut dispatcher = PriorityDispatcher::<u32, Modifier>::default();
+
+    dispatcher.add_typed_fn(Modifier::Damage, |_| 10);
+    dispatcher.add_typed_fn(Modifier::Damage, |_| -3);
+    dispatcher.add_typed_fn(Modifier::Armour, |_| 5);
+    dispatcher.add_fn(Modifier::Damage, |_| None, 1);
+
+    assert_eq!(dispatcher.dispatch_typed(&Modifier::Damage), 7);
+    assert_eq!(dispatcher.dispatch_typed(&Modifier::Armour), 5);
+    assert_eq!(dispatcher.listener_count(&Modifier::Damage), 3);
+    assert_eq!(dispatcher.total_listeners(), 4);
+
+    assert_eq!(dispatcher.remove_all_listeners(&Modifier::Damage), 3);
+    assert_eq!(dispatcher.dispatch_typed(&Modifier::Damage), 0);
+    assert_eq!(dispatcher.event_keys().len(), 1);
+
+    dispatcher.clear();
+    assert!(dispatcher.is_empty());
+}
+
+#[test]
+fn snapshot_diff_reports_registration_changes() {
+    struct NamedListener;
+
+    impl PriorityListener<Event> for NamedListener {
+        fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> {
+            Some(PriorityDispatcherResult::StopListening)
+        }
+
+        fn name(&self) -> &str {
+            "named"
+        }
+    }
+
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    let empty = dispatcher.snapshot();
+
+    dispatcher.add_listener(Event::EventType, NamedListener, 2);
+    let registered = dispatcher.snapshot();
+
+    let diff = empty.diff(&registered);
+    assert!(diff.removed.is_empty());
+    assert_eq!(diff.added.len(), 1);
+    assert_eq!(diff.added[0].name, "named");
+    assert_eq!(diff.added[0].priority, 2);
+
+    dispatcher.dispatch_event(&Event::EventType);
+
+    let diff = registered.diff(&dispatcher.snapshot());
+    assert!(diff.added.is_empty());
+    assert_eq!(diff.removed.len(), 1);
+    assert!(empty.diff(&dispatcher.snapshot()).is_empty());
+}
+
+#[test]
+fn snapshot_reports_unhandled_keys() {
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    enum Input {
+        Jump,
+        Land,
+    }
+
+    impl EnumKey for Input {
+        fn keys() -> Vec<Self> {
+            vec![Input::Jump, Input::Land]
+        }
+    }
+
+    struct Animation;
+
+    impl PriorityListener<Input> for Animation {
+        fn on_event(&self, _event: &Input) -> Option<PriorityDispatcherResult> {
+            None
+        }
+    }
+
+    let mut dispatcher = PriorityDispatcher::<u32, Input>::default();
+
+    assert_eq!(
+        dispatcher.snapshot().unhandled_keys(),
+        [Input::Jump, Input::Land]
+    );
+
+    dispatcher.add_listener(Input::Land, Animation, 1);
+    assert_eq!(dispatcher.snapshot().unhandled_keys(), [Input::Jump]);
+
+    dispatcher.add_listener(Input::Jump, Animation, 1);
+    assert!(dispatcher.snapshot().unhandled_keys().is_empty());
+}
+
+#[test]
+fn health_report_finds_duplicates_and_crowded_keys() {
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let listener = Arc::new(RwLock::new(EventListener {
+        name: "1".to_string(),
+        name_record: names_record,
+    }));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    dispatcher.add_listener(Event::EventType, Arc::clone(&listener), 1);
+    assert!(dispatcher.snapshot().health(1).is_healthy());
+
+    dispatcher.add_listener(Event::EventType, Arc::clone(&listener), 1);
+    dispatcher.add_listener(Event::EventType, listener, 2);
+
+    let report = dispatcher.snapshot().health(2);
+    assert_eq!(report.duplicates.len(), 1);
+    assert_eq!(report.duplicates[0].0.priority, 1);
+    assert_eq!(report.duplicates[0].1, 2);
+    assert_eq!(report.crowded_keys, [(Event::EventType, 3)]);
+}
+
+#[test]
+fn registry_wires_listeners_by_name() {
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let mut registry = ListenerRegistry::new();
+
+    for name in ["first", "second"] {
+        let names_record = Arc::clone(&names_record);
+
+        registry.register(name, move || {
+            Arc::new(RwLock::new(EventListener {
+                name: name.to_string(),
+                name_record: Arc::clone(&names_record),
+            }))
+        });
+    }
+
+    let entry = |name: &str, priority| ListenerEntry {
+        event_key: Event::EventType,
+        priority,
+        name: name.to_string(),
+    };
+    let wiring = Snapshot {
+        listeners: vec![entry("second", 2), entry("first", 1)],
+    };
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    registry.wire(&wiring, &mut dispatcher).unwrap();
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.read(), ["first", "second"]);
+    assert!(wiring.diff(&dispatcher.snapshot()).is_empty());
+
+    let unknown = Snapshot {
+        listeners: vec![entry("first", 1), entry("third", 3)],
+    };
+
+    assert!(matches!(
+        registry.wire(&unknown, &mut dispatcher),
+        Err(Error::UnknownListener(name)) if name == "third"
+    ));
+    assert_eq!(dispatcher.snapshot().listeners.len(), 2);
+}
+
+#[test]
+fn panic_policy_catches_panicking_listeners() {
+    struct PanickingListener;
+
+    impl PriorityListener<Event> for PanickingListener {
+        fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> {
+            panic!("Listener failed");
+        }
+
+        fn name(&self) -> &str {
+            "panicking"
+        }
+    }
+
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let panicked = Arc::new(RwLock::new(Vec::new()));
+    let panicked_by_hook = Arc::clone(&panicked);
+
+    let mut hooked = PriorityDispatcher::<u32, Event>::default().panic_policy(PanicPolicy::hook(
+        move |listener, _payload| panicked_by_hook.write().push(listener.to_string()),
+    ));
+    let mut removing =
+        PriorityDispatcher::<u32, Event>::default().panic_policy(PanicPolicy::RemoveListener);
+
+    for dispatcher in [&mut hooked, &mut removing] {
+        let listener = Arc::new(RwLock::new(EventListener {
+            name: "after".to_string(),
+            name_record: Arc::clone(&names_record),
+        }));
+
+        dispatcher.add_listener(Event::EventType, PanickingListener, 0);
+        dispatcher.add_listener(Event::EventType, listener, 1);
+    }
+
+    hooked.dispatch_event(&Event::EventType);
+    hooked.dispatch_event(&Event::EventType);
+    removing.dispatch_event(&Event::EventType);
+    removing.dispatch_event(&Event::EventType);
+
+    assert_eq!(*panicked.read(), ["panicking", "panicking"]);
+    assert_eq!(names_record.read().len(), 4);
+    assert_eq!(hooked.snapshot().listeners.len(), 2);
+    assert_eq!(removing.snapshot().listeners.len(), 1);
+}
+
+#[test]
+fn drained_listeners_migrate_to_another_dispatcher() {
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let mut old_world = PriorityDispatcher::<u32, Event>::default();
+    let mut new_world = PriorityDispatcher::<u32, Event>::default();
+
+    for (name, priority) in &[("late", 2), ("early", 1)] {
+        let listener = Arc::new(RwLock::new(EventListener {
+            name: (*name).to_string(),
+            name_record: Arc::clone(&names_record),
+        }));
+
+        old_world.add_listener(Event::EventType, listener, *priority);
+    }
+
+    for (event_key, priority, listener) in old_world.drain() {
+        new_world.add_listener(event_key, listener, priority);
+    }
+
+    old_world.dispatch_event(&Event::EventType);
+    assert!(names_record.try_read().unwrap().is_empty());
+
+    new_world.dispatch_event(&Event::EventType);
+    assert_eq!(*names_record.try_read().unwrap(), ["early", "late"]);
+}
+
+#[test]
+fn drained_listeners_migrate_by_derived_key() {
+    #[derive(Clone)]
+    enum Payload {
+        Damage(u32),
+        Heal(u32),
+    }
+
+    struct Recorder {
+        name: &'static str,
+        record: Arc<RwLock<Vec<(&'static str, u32)>>>,
+    }
+
+    impl PriorityListener<Payload> for Recorder {
+        fn on_event(&self, event: &Payload) -> Option<PriorityDispatcherResult> {
+            match event {
+                Payload::Damage(amount) | Payload::Heal(amount) => {
+                    self.record.write().push((self.name, *amount));
+                }
+            }
+
+            None
+        }
+    }
+
+    let record = Arc::new(RwLock::new(Vec::new()));
+    let mut old_world: DiscriminantPriorityDispatcher<u32, Payload> =
+        DiscriminantPriorityDispatcher::default();
+    let mut new_world: DiscriminantPriorityDispatcher<u32, Payload> =
+        DiscriminantPriorityDispatcher::default();
+
+    for (name, event_key, priority) in [
+        ("late", Payload::Damage(0), 2),
+        ("early", Payload::Damage(0), 1),
+        ("healer", Payload::Heal(0), 0),
+    ] {
+        let listener = Recorder {
+            name,
+            record: Arc::clone(&record),
+        };
+
+        old_world.add_listener(event_key, listener, priority);
+    }
+
+    new_world.extend(old_world.drain());
+
+    old_world.dispatch_event(&Payload::Damage(1));
+    assert!(record.read().is_empty());
+
+    new_world.dispatch_event(&Payload::Damage(3));
+    new_world.dispatch_event(&Payload::Heal(5));
+
+    assert_eq!(*record.read(), [("early", 3), ("late", 3), ("healer", 5)]);
+}
+
+#[test]
+fn frozen_dispatcher_dispatches_from_many_threads() {
+    struct Counter(AtomicUsize);
+
+    impl PriorityListener<Event> for Arc<Counter> {
+        fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+
+            Some(PriorityDispatcherResult::StopListening)
+        }
+    }
+
+    let counter = Arc::new(Counter(AtomicUsize::new(0)));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    dispatcher.add_listener(Event::EventType, Arc::clone(&counter), 1);
+
+    let frozen = dispatcher.freeze();
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            let frozen = frozen.clone();
+
+            thread::spawn(move || frozen.dispatch_event(&Event::EventType))
+        })
+        .collect();
+
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    // Frozen dispatchers ignore requests to stop listening.
+    assert_eq!(counter.0.load(Ordering::SeqCst), 4);
+    assert_eq!(frozen.listener_count(&Event::EventType), 1);
+    assert_eq!(frozen.total_listeners(), 1);
+    assert!(frozen.event_keys().contains(&Event::EventType));
+    assert!(!frozen.is_empty());
+}
+
+#[cfg(feature = "arc-swap")]
+#[test]
+fn cow_dispatcher_registers_while_shared() {
+    use hey_listen::sync::CowDispatcher;
+
+    struct Once(Arc<RwLock<Vec<String>>>, &'static str);
+
+    impl PriorityListener<Event> for Once {
+        fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> {
+            self.0.try_write().unwrap().push(self.1.to_string());
+
+            Some(PriorityDispatcherResult::StopListening)
+        }
+    }
+
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let dispatcher = Arc::new(CowDispatcher::<u32, Event>::new());
+    let shared = Arc::clone(&dispatcher);
+    let record = Arc::clone(&names_record);
+
+    thread::spawn(move || {
+        shared.add_listener(Event::EventType, Once(Arc::clone(&record), "late"), 2);
+        shared.add_listener(Event::EventType, Once(record, "early"), 1);
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(dispatcher.listener_count(&Event::EventType), 2);
+    assert_eq!(dispatcher.total_listeners(), 2);
+    assert!(dispatcher.event_keys().contains(&Event::EventType));
+
+    dispatcher.dispatch_event(&Event::EventType);
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.try_read().unwrap(), ["early", "late"]);
+    assert!(dispatcher.is_empty());
+    assert!(dispatcher.event_keys().is_empty());
+}
+
+#[test]
+fn is_send_and_sync() {
+    fn assert_send<T: Send + Sync>(_: &T) {}
+    assert_send(&PriorityDispatcher::<u32, Event>::default());
+    assert_send(&PriorityDispatcher::<u32, Event>::default().freeze());
+}
+
+#[cfg(feature = "dot")]
+#[test]
+fn topology_exports_dot() {
+    use hey_listen::dot::Topology;
+
+    struct Physics;
+
+    impl PriorityListener<Event> for Physics {
+        fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> {
+            None
+        }
+
+        fn name(&self) -> &str {
+            "physics"
+        }
+    }
+
+    let world = PriorityDispatcher::<u32, Event>::default();
+    let mut scene = PriorityDispatcher::<u32, Event>::default();
+
+    scene.add_listener(Event::EventType, Physics, 1);
+
+    let dot = Topology::new()
+        .dispatcher("world", &world.snapshot())
+        .dispatcher("scene", &scene.snapshot())
+        .bridge("scene", "world")
+        .export_dot();
+
+    assert_eq!(
+        dot,
+        "digraph events {\n    \
+             \"world\" [label = \"world\", shape = box3d];\n    \
+             \"scene\" [label = \"scene\", shape = box3d];\n    \
+             \"scene/key/EventType\" [label = \"EventType\", shape = box];\n    \
+             \"scene\" -> \"scene/key/EventType\";\n    \
+             \"scene/listener/0\" [label = \"physics\"];\n    \
+             \"scene/key/EventType\" -> \"scene/listener/0\" [label = \"1\"];\n    \
+             \"scene\" -> \"world\" [style = dashed, label = \"forwards\"];\n\
+         }\n"
+    );
+}
+
+#[test]
+fn hub_dispatches_each_event_type_to_its_own_dispatcher() {
+    use hey_listen::sync::Hub;
+
+    #[derive(Clone, Eq, Hash, PartialEq)]
+    struct Tick;
+
+    let ticks = Arc::new(AtomicUsize::new(0));
+    let mut hub: Hub<u32> = Hub::new();
+
+    let counter = Arc::clone(&ticks);
+    hub.add_fn(
+        Tick,
+        move |_event| {
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            None
+        },
+        1,
+    );
+    hub.add_fn(
+        Event::EventType,
+        |_event| Some(PriorityDispatcherResult::StopListening),
+        1,
+    );
+
+    hub.dispatch_event(&Tick);
+    hub.dispatch_event(&Event::EventType);
+    hub.dispatch_event(&0_u8);
+
+    assert_eq!(ticks.load(Ordering::SeqCst), 1);
+    assert!(hub.dispatcher::<u8>().is_none());
+    assert_eq!(
+        hub.dispatcher::<Event>()
+            .unwrap()
+            .snapshot()
+            .listeners
+            .len(),
+        0
+    );
+    assert_eq!(hub.dispatcher_mut::<Tick>().snapshot().listeners.len(), 1);
+}
+
+#[test]
+fn channel_dispatcher_dispatches_events_of_worker_threads() {
+    use hey_listen::sync::channel::channel_dispatcher;
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let (emitter, mut consumer) = channel_dispatcher::<u32, Event>();
+
+    let counter = Arc::clone(&calls);
+    consumer.dispatcher_mut().add_fn(
+        Event::EventType,
+        move |_event| {
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            None
+        },
+        1,
+    );
+
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let emitter = emitter.clone();
+
+            thread::spawn(move || emitter.emit(Event::EventType))
+        })
+        .collect();
+
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    assert_eq!(consumer.poll_dispatch(), 4);
+    assert_eq!(consumer.poll_dispatch(), 0);
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn hub_removes_listeners_of_owner_across_event_types() {
+    use hey_listen::{
+        order::Ordered,
+        sync::{hub::Owned, Hub, Owner},
+    };
+
+    #[derive(Clone, Eq, Hash, PartialEq)]
+    struct Tick;
+
+    struct Plugin;
+
+    impl PriorityListener<Tick> for Plugin {
+        fn on_event(&self, _event: &Tick) -> Option<PriorityDispatcherResult> {
+            None
+        }
+    }
+
+    impl PriorityListener<Event> for Plugin {
+        fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> {
+            None
+        }
+    }
+
+    let (plugin, other_plugin) = (Owner::new(1), Owner::new(2));
+    let mut hub: Hub<u32> = Hub::new();
+
+    hub.add_owned_listener(plugin, Tick, Plugin, 1);
+    hub.add_owned_listener(plugin, Event::EventType, Plugin, 1);
+    hub.add_listener(Event::EventType, Ordered(Owned::new(plugin, Plugin)), 2);
+    hub.add_owned_listener(other_plugin, Tick, Plugin, 1);
+    hub.add_fn(Tick, |_event| None, 2);
+
+    assert_eq!(hub.remove_owner(plugin), 3);
+    assert_eq!(hub.remove_owner(plugin), 0);
+    assert!(hub
+        .dispatcher::<Event>()
+        .unwrap()
+        .snapshot()
+        .listeners
+        .is_empty());
+    assert_eq!(
+        hub.dispatcher::<Tick>().unwrap().snapshot().listeners.len(),
+        2
+    );
+}
+
+#[test]
+fn listener_hooks_surround_every_listener() {
+    struct Named(&'static str, bool);
+
+    impl PriorityListener<Event> for Named {
+        fn on_event(&self, _event: &Event) -> Option<PriorityDispatcherResult> {
+            if self.1 {
+                Some(PriorityDispatcherResult::StopListening)
+            } else {
+                None
+            }
+        }
+
+        fn name(&self) -> &str {
+            self.0
+        }
+    }
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+
+    let before = Arc::clone(&calls);
+    let after = Arc::clone(&calls);
+    dispatcher.set_listener_hooks(
+        move |_event, listener| before.lock().push(format!("before {}", listener.name)),
+        move |_event, listener, request| {
+            after
+                .lock()
+                .push(format!("after {} {:?}", listener.name, request));
+        },
+    );
+    dispatcher.add_listener(Event::EventType, Named("physics", false), 1);
+    dispatcher.add_listener(Event::EventType, Named("audio", true), 2);
+
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(
+        *calls.lock(),
+        [
+            "before physics",
+            "after physics None",
+            "before audio",
+            "after audio Some(StopListening)",
+        ]
+    );
+}
+
+#[test]
+fn disabled_key_skips_dispatches_keeping_listeners() {
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    let listener = Arc::new(RwLock::new(EventListener {
+        name: "1".to_string(),
+        name_record: Arc::clone(&names_record),
+    }));
+
+    dispatcher.add_listener(Event::EventType, listener, 1);
+    dispatcher.disable_key(Event::EventType);
+
+    dispatcher.dispatch_event(&Event::EventType);
+    dispatcher.dispatch_event(&Event::EventType);
+    assert!(names_record.read().is_empty());
+    assert_eq!(dispatcher.skipped_dispatches(&Event::EventType), 2);
+    assert_eq!(dispatcher.snapshot().listeners.len(), 1);
+
+    assert!(dispatcher.enable_key(&Event::EventType));
+    assert!(!dispatcher.enable_key(&Event::EventType));
+    assert!(dispatcher.is_key_enabled(&Event::EventType));
+
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(*names_record.read(), ["1"]);
+    assert_eq!(dispatcher.skipped_dispatches(&Event::EventType), 2);
+}
+
+#[test]
+fn listener_removed_via_its_id() {
+    use hey_listen::{order::Ordered, sync::Hub};
+
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    let ids: Vec<_> = ["1", "2", "3"]
+        .iter()
+        .map(|name| {
+            let listener = Arc::new(RwLock::new(EventListener {
+                name: (*name).to_string(),
+                name_record: Arc::clone(&names_record),
+            }));
+
+            dispatcher.add_listener(Event::EventType, Ordered(listener), 1)
+        })
+        .collect();
+
+    assert!(dispatcher.remove_listener(ids[1]));
+    assert!(!dispatcher.remove_listener(ids[1]));
+
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(*names_record.read(), ["1", "3"]);
+
+    let mut hub: Hub<u32> = Hub::new();
+    let id = hub.add_fn(Event::EventType, |_event| None, 1);
+
+    assert!(!hub.remove_listener(ids[0]));
+    assert!(hub.remove_listener(id));
+    assert!(hub
+        .dispatcher::<Event>()
+        .unwrap()
+        .snapshot()
+        .listeners
+        .is_empty());
+}
+
+#[test]
+fn scoped_subscriptions_end_with_their_guard() {
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    let mut guards: Vec<_> = ["scoped", "detached"]
+        .iter()
+        .map(|name| {
+            let listener = Arc::new(RwLock::new(EventListener {
+                name: (*name).to_string(),
+                name_record: Arc::clone(&names_record),
+            }));
+
+            dispatcher.subscribe_scoped(Event::EventType, listener, 1)
+        })
+        .collect();
+
+    guards.pop().expect("Missing guard").detach();
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(*names_record.read(), ["scoped", "detached"]);
+
+    drop(guards);
+    assert_eq!(dispatcher.purge_dead(), 1);
+
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(*names_record.read(), ["scoped", "detached", "detached"]);
+}
+
+#[test]
+fn introspection_counts_listeners_across_priorities() {
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    assert!(dispatcher.is_empty());
+    assert_eq!(dispatcher.listener_count(&Event::EventType), 0);
+
+    for priority in &[1, 2, 2] {
+        dispatcher.add_fn(Event::EventType, |_event| None, *priority);
+    }
+
+    assert_eq!(dispatcher.listener_count(&Event::EventType), 3);
+    assert_eq!(dispatcher.total_listeners(), 3);
+    assert!(dispatcher.event_keys().contains(&Event::EventType));
+    assert!(!dispatcher.is_empty());
+
+    assert_eq!(dispatcher.remove_all_listeners(&Event::EventType), 3);
+    assert!(dispatcher.event_keys().is_empty());
+    assert!(dispatcher.is_empty());
+}
+
+#[test]
+fn unsubscribed_listeners_are_not_counted_before_being_purged() {
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    let listener = Arc::new(RwLock::new(EventListener {
+        name: String::from("scoped"),
+        name_record: Arc::default(),
+    }));
+    let guard = dispatcher.subscribe_scoped(Event::EventType, listener, 1);
+
+    assert_eq!(dispatcher.listener_count(&Event::EventType), 1);
+
+    drop(guard);
+
+    assert_eq!(dispatcher.listener_count(&Event::EventType), 0);
+    assert_eq!(dispatcher.total_listeners(), 0);
+    assert!(dispatcher.event_keys().is_empty());
+    assert!(dispatcher.is_empty());
+    assert!(dispatcher.snapshot().listeners.is_empty());
+    assert_eq!(dispatcher.purge_dead(), 1);
+}
+
+#[test]
+fn unsubscribed_listeners_are_neither_listed_as_tiers_nor_removed() {
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    let listener = || {
+        Arc::new(RwLock::new(EventListener {
+            name: String::from("scoped"),
+            name_record: Arc::default(),
+        }))
+    };
+
+    dispatcher.add_listener(Event::EventType, listener(), 2);
+    let first = dispatcher.subscribe_scoped(Event::EventType, listener(), 1);
+    let second = dispatcher.subscribe_scoped(Event::EventType, listener(), 2);
+
+    assert_eq!(
+        dispatcher.tiers(&Event::EventType).collect::<Vec<_>>(),
+        [(&1, 1), (&2, 2)]
+    );
+
+    drop(first);
+    drop(second);
+
+    assert_eq!(
+        dispatcher.tiers(&Event::EventType).collect::<Vec<_>>(),
+        [(&2, 1)]
+    );
+    assert_eq!(dispatcher.remove_all_listeners(&Event::EventType), 1);
+    assert_eq!(dispatcher.purge_dead(), 0);
+}
+
+#[test]
+fn budgeted_dispatch_resumes_across_listener_changes() {
+    use std::time::Duration;
+
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    let add_listener = |dispatcher: &mut PriorityDispatcher<u32, Event>, name: &str, priority| {
+        let listener = Arc::new(RwLock::new(EventListener {
+            name: name.to_string(),
+            name_record: Arc::clone(&names_record),
+        }));
+
+        dispatcher.add_listener(Event::EventType, listener, priority)
+    };
+
+    add_listener(&mut dispatcher, "1a", 1);
+    let removed = add_listener(&mut dispatcher, "1b", 1);
+    add_listener(&mut dispatcher, "2", 2);
+
+    let cursor = dispatcher
+        .dispatch_event_budgeted(&Event::EventType, Duration::from_secs(0))
+        .expect("Listeners are left");
+    assert_eq!(*names_record.read(), ["1a"]);
+
+    dispatcher.remove_listener(removed);
+    add_listener(&mut dispatcher, "1c", 1);
+
+    let cursor = dispatcher
+        .resume_dispatch(cursor, Duration::from_secs(0))
+        .expect("Listeners are left");
+    assert_eq!(*names_record.read(), ["1a", "1c"]);
+
+    assert!(dispatcher
+        .resume_dispatch(cursor, Duration::from_secs(60))
+        .is_none());
+    assert_eq!(*names_record.read(), ["1a", "1c", "2"]);
+}
+
+#[test]
+fn cursor_skips_listeners_added_to_passed_tiers() {
+    use hey_listen::cursor::{Budget, DispatchCursor};
+
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    let add_listener = |dispatcher: &mut PriorityDispatcher<u32, Event>, name: &str, priority| {
+        let listener = Arc::new(RwLock::new(EventListener {
+            name: name.to_string(),
+            name_record: Arc::clone(&names_record),
+        }));
+
+        dispatcher.add_listener(Event::EventType, listener, priority);
+    };
+
+    add_listener(&mut dispatcher, "1", 1);
+    add_listener(&mut dispatcher, "2a", 2);
+    add_listener(&mut dispatcher, "2b", 2);
+
+    let cursor = DispatchCursor::new(Event::EventType)
+        .resume(&mut dispatcher, Budget::Listeners(2))
+        .expect("Listeners are left");
+    assert_eq!(*names_record.read(), ["1", "2a"]);
+
+    add_listener(&mut dispatcher, "1 late", 1);
+    add_listener(&mut dispatcher, "2 late", 2);
+
+    assert!(cursor
+        .resume(&mut dispatcher, Budget::Listeners(10))
+        .is_none());
+    assert_eq!(*names_record.read(), ["1", "2a", "2b", "2 late"]);
+}
+
+#[test]
+fn cursor_resumes_by_listener_count_and_respects_disabled_keys() {
+    use hey_listen::cursor::{Budget, DispatchCursor};
+
+    let dispatched = Arc::new(AtomicUsize::new(0));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    for priority in 0..5 {
+        let dispatched = Arc::clone(&dispatched);
+
+        dispatcher.add_fn(
+            Event::EventType,
+            move |_event| {
+                dispatched.fetch_add(1, Ordering::SeqCst);
+
+                None
+            },
+            priority,
+        );
+    }
+
+    let cursor = DispatchCursor::new(Event::EventType)
+        .resume(&mut dispatcher, Budget::Listeners(3))
+        .expect("Listeners are left");
+    assert_eq!(dispatched.load(Ordering::SeqCst), 3);
+
+    assert!(cursor
+        .resume(&mut dispatcher, Budget::Listeners(3))
+        .is_none());
+    assert_eq!(dispatched.load(Ordering::SeqCst), 5);
+
+    dispatcher.disable_key(Event::EventType);
+
+    assert!(DispatchCursor::new(Event::EventType)
+        .resume(&mut dispatcher, Budget::Listeners(3))
+        .is_none());
+    assert_eq!(dispatched.load(Ordering::SeqCst), 5);
+    assert_eq!(dispatcher.skipped_dispatches(&Event::EventType), 1);
 }
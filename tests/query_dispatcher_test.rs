@@ -0,0 +1,89 @@
+#![cfg(feature = "blocking")]
+
+use hey_listen::rc::{QueryDispatcher, QueryHandler};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Query {
+    Health,
+    Ammo,
+}
+
+struct Stat(Option<u32>);
+
+impl QueryHandler<Query, u32> for Stat {
+    fn handle(&mut self, _query: &Query) -> Option<u32> {
+        self.0
+    }
+}
+
+struct Counter(u32);
+
+impl QueryHandler<Query, u32> for Counter {
+    fn handle(&mut self, _query: &Query) -> Option<u32> {
+        self.0 += 1;
+
+        Some(self.0)
+    }
+}
+
+#[test]
+fn query_without_handlers() {
+    let mut dispatcher: QueryDispatcher<Query, u32> = QueryDispatcher::new();
+
+    assert_eq!(dispatcher.query(&Query::Health), None);
+    assert!(dispatcher.query_all(&Query::Health).is_empty());
+}
+
+#[test]
+fn query_returns_first_response() {
+    let mut dispatcher: QueryDispatcher<Query, u32> = QueryDispatcher::default();
+
+    dispatcher.add_handler(Query::Health, Stat(None));
+    dispatcher.add_handler(Query::Health, Stat(Some(50)));
+    dispatcher.add_handler(Query::Health, Stat(Some(100)));
+    dispatcher.add_handler(Query::Ammo, Stat(Some(12)));
+
+    assert_eq!(dispatcher.query(&Query::Health), Some(50));
+    assert_eq!(dispatcher.query(&Query::Ammo), Some(12));
+}
+
+#[test]
+fn query_stops_asking_after_first_response() {
+    let mut dispatcher: QueryDispatcher<Query, u32> = QueryDispatcher::new();
+
+    dispatcher.add_handler(Query::Health, Counter(0));
+    dispatcher.add_handler(Query::Health, Counter(10));
+
+    assert_eq!(dispatcher.query(&Query::Health), Some(1));
+    assert_eq!(dispatcher.query(&Query::Health), Some(2));
+    assert_eq!(dispatcher.query_all(&Query::Health), [3, 11]);
+}
+
+#[test]
+fn query_all_skips_handlers_without_response() {
+    let mut dispatcher: QueryDispatcher<Query, u32> = QueryDispatcher::new();
+
+    dispatcher.add_handler(Query::Ammo, Stat(Some(12)));
+    dispatcher.add_handler(Query::Ammo, Stat(None));
+    dispatcher.add_handler(Query::Ammo, Stat(Some(30)));
+
+    assert_eq!(dispatcher.query_all(&Query::Ammo), [12, 30]);
+    assert!(dispatcher.query_all(&Query::Health).is_empty());
+}
+
+#[test]
+fn introspection_counts_handlers() {
+    let mut dispatcher: QueryDispatcher<Query, u32> = QueryDispatcher::new();
+
+    assert!(dispatcher.is_empty());
+
+    dispatcher.add_handler(Query::Health, Stat(None));
+    dispatcher.add_handler(Query::Health, Counter(0));
+    dispatcher.add_handler(Query::Ammo, Stat(Some(12)));
+
+    assert_eq!(dispatcher.listener_count(&Query::Health), 2);
+    assert_eq!(dispatcher.listener_count(&Query::Ammo), 1);
+    assert_eq!(dispatcher.total_listeners(), 3);
+    assert_eq!(dispatcher.event_keys().len(), 2);
+    assert!(!dispatcher.is_empty());
+}
@@ -0,0 +1,268 @@
+#![cfg(feature = "blocking")]
+
+use hey_listen::{
+    cursor::{Budget, DispatchCursor},
+    fns::weakly,
+    rc::{DiscriminantDispatcher, DispatcherRequest, Listener},
+    typed::EventResult,
+};
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+#[derive(Clone, Debug, PartialEq)]
+enum Payload {
+    Damage(u32),
+    Heal(u32),
+    Quit,
+}
+
+impl EventResult for Payload {
+    type Output = u32;
+
+    fn combine(total: &mut u32, amount: u32) {
+        *total += amount;
+    }
+}
+
+struct Recorder {
+    record: Rc<RefCell<Vec<Payload>>>,
+    request: Option<fn() -> DispatcherRequest>,
+}
+
+impl Recorder {
+    fn new(record: &Rc<RefCell<Vec<Payload>>>) -> Self {
+        Self {
+            record: Rc::clone(record),
+            request: None,
+        }
+    }
+}
+
+impl Listener<Payload> for Recorder {
+    fn on_event(&self, event: &Payload) -> Option<DispatcherRequest> {
+        self.record.borrow_mut().push(event.clone());
+
+        self.request.map(|request| request())
+    }
+}
+
+#[test]
+fn discriminant_dispatcher_ignores_fields() {
+    let damage = Rc::new(RefCell::new(Vec::new()));
+    let heal = Rc::new(RefCell::new(Vec::new()));
+    let mut dispatcher: DiscriminantDispatcher<Payload> = DiscriminantDispatcher::new();
+
+    // The fields of the registration-event do not matter.
+    dispatcher.add_listener(Payload::Damage(0), Recorder::new(&damage));
+    dispatcher.add_listener(Payload::Heal(99), Recorder::new(&heal));
+
+    dispatcher.dispatch_event(&Payload::Damage(3));
+    dispatcher.dispatch_event(&Payload::Heal(1));
+    dispatcher.dispatch_event(&Payload::Quit);
+    dispatcher.dispatch_event(&Payload::Damage(4));
+
+    assert_eq!(*damage.borrow(), [Payload::Damage(3), Payload::Damage(4)]);
+    assert_eq!(*heal.borrow(), [Payload::Heal(1)]);
+}
+
+#[test]
+fn discriminant_dispatcher_stops_listening_per_variant() {
+    let record = Rc::new(RefCell::new(Vec::new()));
+    let mut dispatcher: DiscriminantDispatcher<Payload> = DiscriminantDispatcher::default();
+
+    dispatcher.add_listener(
+        Payload::Damage(0),
+        Recorder {
+            record: Rc::clone(&record),
+            request: Some(|| DispatcherRequest::StopListening),
+        },
+    );
+    dispatcher.add_listener(Payload::Heal(0), Recorder::new(&record));
+
+    dispatcher.dispatch_event(&Payload::Damage(1));
+    dispatcher.dispatch_event(&Payload::Damage(2));
+    dispatcher.dispatch_event(&Payload::Heal(3));
+
+    assert_eq!(*record.borrow(), [Payload::Damage(1), Payload::Heal(3)]);
+}
+
+#[test]
+fn drained_listeners_migrate_by_derived_key() {
+    let damage = Rc::new(RefCell::new(Vec::new()));
+    let heal = Rc::new(RefCell::new(Vec::new()));
+    let mut old_world: DiscriminantDispatcher<Payload> = DiscriminantDispatcher::new();
+    let mut new_world: DiscriminantDispatcher<Payload> = DiscriminantDispatcher::new();
+
+    old_world.add_listener(Payload::Damage(0), Recorder::new(&damage));
+    old_world.add_listener(Payload::Heal(0), Recorder::new(&heal));
+
+    new_world.extend(old_world.drain());
+
+    old_world.dispatch_event(&Payload::Damage(1));
+    new_world.dispatch_event(&Payload::Damage(2));
+    new_world.dispatch_event(&Payload::Heal(3));
+
+    assert!(old_world.is_empty());
+    assert_eq!(*damage.borrow(), [Payload::Damage(2)]);
+    assert_eq!(*heal.borrow(), [Payload::Heal(3)]);
+}
+
+#[test]
+fn cold_listeners_receive_events_only_while_awake() {
+    let record = Rc::new(RefCell::new(Vec::new()));
+    let mut dispatcher: DiscriminantDispatcher<Payload> = DiscriminantDispatcher::new();
+
+    dispatcher.add_cold_listener(Payload::Damage(0), Recorder::new(&record));
+
+    dispatcher.dispatch_event(&Payload::Damage(1));
+    assert!(record.borrow().is_empty());
+    assert!(!dispatcher.are_cold_listeners_awake(&Payload::Damage(0)));
+
+    dispatcher.wake_cold_listeners(Payload::Damage(0));
+    dispatcher.dispatch_event(&Payload::Damage(2));
+    dispatcher.dispatch_event(&Payload::Heal(3));
+    assert!(dispatcher.are_cold_listeners_awake(&Payload::Damage(7)));
+
+    assert!(dispatcher.sleep_cold_listeners(&Payload::Damage(0)));
+    assert!(!dispatcher.sleep_cold_listeners(&Payload::Damage(0)));
+    dispatcher.dispatch_event(&Payload::Damage(4));
+
+    assert_eq!(*record.borrow(), [Payload::Damage(2)]);
+}
+
+#[test]
+fn warm_listeners_stopping_propagation_skip_cold_listeners() {
+    let record = Rc::new(RefCell::new(Vec::new()));
+    let cold = Rc::new(RefCell::new(Vec::new()));
+    let mut dispatcher: DiscriminantDispatcher<Payload> = DiscriminantDispatcher::new();
+
+    dispatcher.add_cold_listener(Payload::Damage(0), Recorder::new(&cold));
+    dispatcher.add_listener(
+        Payload::Damage(0),
+        Recorder {
+            record: Rc::clone(&record),
+            request: Some(|| DispatcherRequest::StopPropagation),
+        },
+    );
+    dispatcher.wake_cold_listeners(Payload::Damage(0));
+
+    dispatcher.dispatch_event(&Payload::Damage(1));
+
+    assert_eq!(*record.borrow(), [Payload::Damage(1)]);
+    assert!(cold.borrow().is_empty());
+}
+
+#[test]
+fn cursor_skips_warm_listeners_added_once_it_reached_cold_ones() {
+    let warm = Rc::new(RefCell::new(Vec::new()));
+    let cold = Rc::new(RefCell::new(Vec::new()));
+    let late = Rc::new(RefCell::new(Vec::new()));
+    let mut dispatcher: DiscriminantDispatcher<Payload> = DiscriminantDispatcher::new();
+
+    dispatcher.add_listener(Payload::Damage(0), Recorder::new(&warm));
+    dispatcher.add_cold_listener(Payload::Damage(0), Recorder::new(&cold));
+    dispatcher.wake_cold_listeners(Payload::Damage(0));
+
+    let cursor = DispatchCursor::new(Payload::Damage(1))
+        .resume(&mut dispatcher, Budget::Listeners(1))
+        .expect("The cold listener is left");
+
+    dispatcher.add_listener(Payload::Damage(0), Recorder::new(&late));
+
+    assert!(cursor
+        .resume(&mut dispatcher, Budget::Listeners(10))
+        .is_none());
+    assert_eq!(*warm.borrow(), [Payload::Damage(1)]);
+    assert_eq!(*cold.borrow(), [Payload::Damage(1)]);
+    assert!(late.borrow().is_empty());
+}
+
+#[test]
+fn introspection_counts_warm_and_cold_listeners() {
+    let record = Rc::new(RefCell::new(Vec::new()));
+    let mut dispatcher: DiscriminantDispatcher<Payload> = DiscriminantDispatcher::new();
+
+    assert!(dispatcher.is_empty());
+
+    dispatcher.add_listener(Payload::Damage(0), Recorder::new(&record));
+    dispatcher.add_listener(Payload::Damage(0), Recorder::new(&record));
+    // Cold listeners count even while asleep.
+    dispatcher.add_cold_listener(Payload::Heal(0), Recorder::new(&record));
+
+    assert_eq!(dispatcher.listener_count(&Payload::Damage(7)), 2);
+    assert_eq!(dispatcher.listener_count(&Payload::Heal(7)), 1);
+    assert_eq!(dispatcher.listener_count(&Payload::Quit), 0);
+    assert_eq!(dispatcher.total_listeners(), 3);
+    assert_eq!(dispatcher.event_keys().len(), 2);
+    assert!(!dispatcher.is_empty());
+
+    assert_eq!(dispatcher.remove_all_listeners(&Payload::Heal(0)), 1);
+    assert_eq!(dispatcher.event_keys().len(), 1);
+
+    dispatcher.clear();
+    assert!(dispatcher.is_empty());
+    assert_eq!(dispatcher.total_listeners(), 0);
+}
+
+#[test]
+fn unsubscribed_listeners_are_not_counted_as_removed() {
+    let record = Rc::new(RefCell::new(Vec::new()));
+    let mut dispatcher: DiscriminantDispatcher<Payload> = DiscriminantDispatcher::new();
+
+    dispatcher.add_listener(Payload::Damage(0), Recorder::new(&record));
+    let guard = dispatcher.subscribe_scoped(Payload::Damage(0), Recorder::new(&record));
+
+    drop(guard);
+
+    assert_eq!(dispatcher.remove_all_listeners(&Payload::Damage(0)), 1);
+    assert_eq!(dispatcher.purge_dead(), 0);
+}
+
+#[test]
+fn typed_dispatch_combines_outputs_per_key() {
+    let record = Rc::new(RefCell::new(Vec::new()));
+    let mut dispatcher: DiscriminantDispatcher<Payload> = DiscriminantDispatcher::new();
+
+    dispatcher.add_typed_fn(Payload::Damage(0), |payload| match payload {
+        Payload::Damage(amount) => *amount,
+        _ => 0,
+    });
+    dispatcher.add_typed_fn(Payload::Damage(0), |_| 1);
+    dispatcher.add_listener(Payload::Damage(0), Recorder::new(&record));
+
+    assert_eq!(dispatcher.dispatch_typed(&Payload::Damage(4)), 5);
+    assert_eq!(dispatcher.dispatch_typed(&Payload::Heal(4)), 0);
+    // Typed dispatches do not reach other listeners.
+    assert!(record.borrow().is_empty());
+
+    assert_eq!(dispatcher.listener_count(&Payload::Damage(0)), 3);
+    assert_eq!(dispatcher.snapshot().listeners.len(), 3);
+    assert_eq!(dispatcher.take_listeners(&Payload::Damage(0)).len(), 1);
+    assert!(dispatcher.is_empty());
+}
+
+#[test]
+fn cold_listeners_are_removed_like_warm_ones() {
+    let record = Rc::new(RefCell::new(Vec::new()));
+    let transient = Arc::new(());
+    let mut dispatcher: DiscriminantDispatcher<Payload> = DiscriminantDispatcher::new();
+
+    let removed = dispatcher.add_cold_listener(Payload::Damage(0), Recorder::new(&record));
+    dispatcher.add_cold_listener(Payload::Damage(0), Recorder::new(&record));
+    dispatcher.add_cold_listener(Payload::Heal(0), Recorder::new(&record));
+    dispatcher.add_cold_listener(
+        Payload::Heal(0),
+        weakly(&transient, |_, _event: &Payload| None::<DispatcherRequest>),
+    );
+    dispatcher.add_listener(Payload::Heal(0), Recorder::new(&record));
+
+    assert_eq!(dispatcher.listener_count(&Payload::Damage(0)), 2);
+    assert!(dispatcher.remove_listener(removed));
+    assert_eq!(dispatcher.listener_count(&Payload::Damage(0)), 1);
+
+    drop(transient);
+    assert_eq!(dispatcher.purge_dead(), 1);
+
+    assert_eq!(dispatcher.take_listeners(&Payload::Heal(0)).len(), 2);
+    assert_eq!(dispatcher.take_listeners(&Payload::Damage(0)).len(), 1);
+    assert!(dispatcher.is_empty());
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "remote")]
+
+use async_trait::async_trait;
+use hey_listen::{
+    remote::{RemoteEmitter, RemoteReceiver},
+    sync::{AsyncDispatchResult, AsyncDispatcher, AsyncListener},
+    Error, Mutex,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::{io::AsyncWriteExt, net::TcpListener};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+enum Event {
+    Ping,
+    Pong,
+}
+
+struct RecordingListener {
+    record: Arc<Mutex<Vec<Event>>>,
+}
+
+#[async_trait]
+impl AsyncListener<Event> for RecordingListener {
+    async fn on_event(&self, event: &Event) -> Option<AsyncDispatchResult> {
+        self.record.lock().push(event.clone());
+
+        None
+    }
+}
+
+#[tokio::test]
+async fn received_events_are_dispatched_in_order() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = AsyncDispatcher::<Event>::new();
+
+    for event in [Event::Ping, Event::Pong] {
+        dispatcher.add_listener(
+            event,
+            RecordingListener {
+                record: Arc::clone(&record),
+            },
+        );
+    }
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let mut emitter = RemoteEmitter::connect(listener.local_addr().unwrap())
+        .await
+        .unwrap();
+
+    emitter.emit(&Event::Ping).await.unwrap();
+    emitter.emit(&Event::Pong).await.unwrap();
+    emitter.emit(&Event::Ping).await.unwrap();
+    drop(emitter);
+
+    let (stream, _) = listener.accept().await.unwrap();
+    RemoteReceiver::new(stream)
+        .run(&mut dispatcher)
+        .await
+        .unwrap();
+
+    assert_eq!(*record.lock(), [Event::Ping, Event::Pong, Event::Ping]);
+}
+
+#[tokio::test]
+async fn malformed_events_fail_to_dispatch() {
+    let (mut writer, reader) = tokio::io::duplex(64);
+    let mut dispatcher = AsyncDispatcher::<Event>::new();
+    let mut receiver = RemoteReceiver::new(reader);
+
+    writer.write_all(b"\"Unknown\"\n").await.unwrap();
+    drop(writer);
+
+    assert!(matches!(
+        receiver.dispatch_next(&mut dispatcher).await,
+        Err(Error::Serialisation(_))
+    ));
+    assert!(!receiver.dispatch_next(&mut dispatcher).await.unwrap());
+}
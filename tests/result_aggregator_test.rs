@@ -0,0 +1,40 @@
+use hey_listen::typed::{EventResult, ResultAggregator};
+
+struct Damage(u32);
+
+impl EventResult for Damage {
+    type Output = u32;
+
+    fn combine(total: &mut u32, modifier: u32) {
+        *total += modifier;
+    }
+}
+
+struct Save;
+
+impl EventResult for Save {
+    type Output = Vec<&'static str>;
+
+    fn combine(saved: &mut Vec<&'static str>, mut systems: Vec<&'static str>) {
+        saved.append(&mut systems);
+    }
+}
+
+#[test]
+fn dispatch_combines_outputs_per_event_type() {
+    let mut aggregator = ResultAggregator::new();
+
+    assert_eq!(aggregator.dispatch_event(&Damage(3)), 0);
+
+    aggregator.add_fn(|damage: &Damage| damage.0);
+    aggregator.add_fn(|damage: &Damage| damage.0 * 2);
+    aggregator.add_fn(|_: &Save| vec!["physics"]);
+    aggregator.add_fn(|_: &Save| vec!["audio", "input"]);
+
+    assert_eq!(aggregator.listener_count::<Damage>(), 2);
+    assert_eq!(aggregator.dispatch_event(&Damage(3)), 9);
+    assert_eq!(
+        aggregator.dispatch_event(&Save),
+        ["physics", "audio", "input"]
+    );
+}
@@ -0,0 +1,106 @@
+use hey_listen::{static_dispatcher, static_dispatcher::StaticListener};
+use std::{cell::RefCell, rc::Rc};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Event {
+    Tick,
+    Collision { force: u32 },
+    Quit,
+}
+
+#[derive(Default)]
+struct Recorder {
+    events: Vec<Event>,
+}
+
+impl StaticListener<Event> for Recorder {
+    fn on_event(&mut self, event: &Event) {
+        self.events.push(event.clone());
+    }
+}
+
+/// Records the order listeners are called in.
+struct Ordered {
+    name: &'static str,
+    order: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl StaticListener<Event> for Ordered {
+    fn on_event(&mut self, _event: &Event) {
+        self.order.borrow_mut().push(self.name);
+    }
+}
+
+static_dispatcher! {
+    struct GameDispatcher for Event {
+        physics: Recorder => [Event::Tick],
+        audio: Recorder => [Event::Collision { .. } | Event::Quit],
+        heavy_hits: Recorder => [Event::Collision { force: 10..=u32::MAX }],
+    }
+}
+
+static_dispatcher! {
+    struct EmptyDispatcher for Event {}
+}
+
+#[test]
+fn dispatches_matching_patterns_only() {
+    let mut dispatcher = GameDispatcher {
+        physics: Recorder::default(),
+        audio: Recorder::default(),
+        heavy_hits: Recorder::default(),
+    };
+
+    dispatcher.dispatch_event(&Event::Tick);
+    dispatcher.dispatch_event(&Event::Collision { force: 3 });
+    dispatcher.dispatch_event(&Event::Collision { force: 12 });
+    dispatcher.dispatch_event(&Event::Quit);
+
+    assert_eq!(dispatcher.physics.events, [Event::Tick]);
+    assert_eq!(
+        dispatcher.audio.events,
+        [
+            Event::Collision { force: 3 },
+            Event::Collision { force: 12 },
+            Event::Quit,
+        ]
+    );
+    assert_eq!(
+        dispatcher.heavy_hits.events,
+        [Event::Collision { force: 12 }]
+    );
+}
+
+#[test]
+fn dispatches_in_declaration_order() {
+    static_dispatcher! {
+        struct OrderDispatcher for Event {
+            second: Ordered => [Event::Tick],
+            first: Ordered => [Event::Tick | Event::Quit],
+        }
+    }
+
+    let order = Rc::new(RefCell::new(Vec::new()));
+    let mut dispatcher = OrderDispatcher {
+        second: Ordered {
+            name: "second",
+            order: Rc::clone(&order),
+        },
+        first: Ordered {
+            name: "first",
+            order: Rc::clone(&order),
+        },
+    };
+
+    dispatcher.dispatch_event(&Event::Tick);
+    dispatcher.dispatch_event(&Event::Quit);
+
+    assert_eq!(*order.borrow(), ["second", "first", "first"]);
+}
+
+#[test]
+fn dispatcher_without_listeners() {
+    let mut dispatcher = EmptyDispatcher {};
+
+    dispatcher.dispatch_event(&Event::Tick);
+}
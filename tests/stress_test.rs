@@ -0,0 +1,23 @@
+#![cfg(all(feature = "stress", feature = "priority"))]
+
+use hey_listen::{stress::Workload, sync::PriorityDispatcher};
+
+#[test]
+fn workload_reports_deliveries_and_stopped_listeners() {
+    let workload = Workload::new().keys(4).listeners_per_key(3).events(100);
+
+    let mut dispatcher = PriorityDispatcher::<u32, usize>::default();
+    let report = workload.clone().run(&mut dispatcher);
+
+    assert_eq!(report.events, 100);
+    assert_eq!(report.deliveries, 300);
+    assert_eq!(report.stopped_listening, 0);
+    assert!(report.latency_percentile(0.5) <= report.latency_percentile(1.0));
+
+    // Every listener stops listening upon its first event.
+    let mut dispatcher = PriorityDispatcher::<u32, usize>::default();
+    let report = workload.stop_listening(1.0).run(&mut dispatcher);
+
+    assert_eq!(report.deliveries, 12);
+    assert_eq!(report.stopped_listening, 12);
+}